@@ -45,10 +45,12 @@ pub mod pipeline {
 	pub type BindlessMeshGraphicsPipeline<T> =
 		rust_gpu_bindless_core::pipeline::BindlessMeshGraphicsPipeline<crate::P, T>;
 	pub type RecordingError = rust_gpu_bindless_core::pipeline::RecordingError<crate::P>;
+	pub type PipelineCreationError = rust_gpu_bindless_core::pipeline::PipelineCreationError<crate::P>;
 	pub type Recording<'a> = rust_gpu_bindless_core::pipeline::Recording<'a, crate::P>;
 	pub type Rendering<'a, 'b> = rust_gpu_bindless_core::pipeline::Rendering<'a, 'b, crate::P>;
-	pub type RenderingAttachment<'a, 'b, A> =
-		rust_gpu_bindless_core::pipeline::RenderingAttachment<'a, 'b, crate::P, A>;
+	pub type RenderingAttachment<'a, 'b, A, T = rust_gpu_bindless_core::descriptor::Image2d> =
+		rust_gpu_bindless_core::pipeline::RenderingAttachment<'a, 'b, crate::P, A, T>;
+	pub type ResolveAttachment<'a, 'b, A> = rust_gpu_bindless_core::pipeline::ResolveAttachment<'a, 'b, crate::P, A>;
 
 	pub use rust_gpu_bindless_core::pipeline::*;
 }
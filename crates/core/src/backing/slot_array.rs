@@ -1,38 +1,129 @@
 use rust_gpu_bindless_shaders::descriptor::DescriptorIndex;
-use std::ops::{Index, IndexMut};
+use std::ops::Index;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering::Relaxed;
 
-pub struct SlotArray<T>(pub Box<[T]>);
+/// An array of slots that can grow up to some max capacity, without ever moving already existing slots.
+///
+/// The slots are stored in chunks: The first chunk contains the initial capacity, and every following chunk doubles the
+/// capacity of the array, with the last chunk being cut off at the max capacity.
+pub struct SlotArray<T> {
+	chunks: Box<[OnceLock<Box<[T]>>]>,
+	/// size of the first chunk, at least 1
+	base: u32,
+	capacity: AtomicU32,
+	max_capacity: u32,
+	generator: fn() -> T,
+}
 
 impl<T: Default> SlotArray<T> {
-	pub fn new(count: u32) -> Self {
-		Self::new_generator(count, |_| T::default())
+	pub fn new(capacity: u32, max_capacity: u32) -> Self {
+		Self::new_generator(capacity, max_capacity, T::default)
 	}
 }
 
 impl<T> SlotArray<T> {
-	pub fn new_generator(count: u32, f: impl FnMut(u32) -> T) -> Self {
-		Self((0..count).map(f).collect::<Vec<_>>().into_boxed_slice())
+	pub fn new_generator(capacity: u32, max_capacity: u32, generator: fn() -> T) -> Self {
+		let capacity = capacity.min(max_capacity);
+		let base = capacity.max(1);
+		let chunk_count = if max_capacity == 0 {
+			0
+		} else {
+			Self::locate(base, max_capacity - 1).0 + 1
+		};
+		let array = Self {
+			chunks: (0..chunk_count).map(|_| OnceLock::new()).collect(),
+			base,
+			capacity: AtomicU32::new(0),
+			max_capacity,
+			generator,
+		};
+		if capacity > 0 {
+			array.grow_to_fit(capacity - 1);
+		}
+		array
+	}
+
+	/// The amount of slots that are currently allocated
+	#[inline]
+	pub fn capacity(&self) -> u32 {
+		self.capacity.load(Relaxed)
+	}
+
+	/// The amount of slots this array can grow to
+	#[inline]
+	pub fn max_capacity(&self) -> u32 {
+		self.max_capacity
 	}
 
-	pub fn len(&self) -> usize {
-		self.0.len()
+	/// Ensures the slot at `index` is allocated by allocating all missing chunks up to and including the one containing
+	/// `index`. Returns the capacity before and after growing, if this call allocated any chunks. The capacity before
+	/// growing may be larger than the current capacity, if another thread is concurrently growing the array.
+	///
+	/// # Panics
+	/// if `index` is not below the max capacity
+	pub fn grow_to_fit(&self, index: u32) -> Option<(u32, u32)> {
+		assert!(
+			index < self.max_capacity,
+			"index {} out of max capacity {}",
+			index,
+			self.max_capacity
+		);
+		let (chunk, _) = Self::locate(self.base, index);
+		let mut grown = None;
+		for i in 0..=chunk {
+			let mut initialized = false;
+			self.chunks[i].get_or_init(|| {
+				initialized = true;
+				let (start, end) = self.chunk_range(i);
+				(start..end).map(|_| (self.generator)()).collect()
+			});
+			if initialized {
+				let (start, end) = self.chunk_range(i);
+				self.capacity.fetch_max(end, Relaxed);
+				grown = Some((grown.map_or(start, |(old, _)| old), end));
+			}
+		}
+		grown
 	}
 
-	pub fn is_empty(&self) -> bool {
-		self.0.is_empty()
+	/// Returns the index of the chunk containing `index` and the offset within that chunk
+	#[inline]
+	fn locate(base: u32, index: u32) -> (usize, usize) {
+		if index < base {
+			(0, index as usize)
+		} else {
+			let chunk = (index / base).ilog2() + 1;
+			let start = (base as u64) << (chunk - 1);
+			(chunk as usize, (index as u64 - start) as usize)
+		}
+	}
+
+	/// Returns the range of indices contained in the chunk
+	fn chunk_range(&self, chunk: usize) -> (u32, u32) {
+		let start = match chunk {
+			0 => 0,
+			_ => (self.base as u64) << (chunk - 1),
+		};
+		let end = ((self.base as u64) << chunk).min(self.max_capacity as u64);
+		(start as u32, end as u32)
 	}
 }
 
 impl<T> Index<DescriptorIndex> for SlotArray<T> {
 	type Output = T;
 
+	#[inline]
 	fn index(&self, index: DescriptorIndex) -> &Self::Output {
-		self.0.index(index.to_usize())
-	}
-}
-
-impl<T> IndexMut<DescriptorIndex> for SlotArray<T> {
-	fn index_mut(&mut self, index: DescriptorIndex) -> &mut Self::Output {
-		self.0.index_mut(index.to_usize())
+		let (chunk, offset) = Self::locate(self.base, index.to_u32());
+		match self.chunks.get(chunk).and_then(OnceLock::get) {
+			Some(chunk) => &chunk[offset],
+			None => panic!(
+				"index {} out of capacity {} of SlotArray",
+				index.to_u32(),
+				self.capacity()
+			),
+		}
 	}
 }
@@ -1,11 +1,10 @@
-use crate::backing::ab::{AB, ABArray};
 use crate::backing::range_set::{DescriptorIndexIterator, DescriptorIndexRangeSet};
 use crate::backing::slot_array::SlotArray;
 use crossbeam_queue::SegQueue;
 use crossbeam_utils::CachePadded;
 use parking_lot::{Mutex, MutexGuard, RwLock};
 use rust_gpu_bindless_shaders::descriptor::{
-	DescriptorId, DescriptorIndex, DescriptorType, DescriptorVersion, ID_TYPE_BITS,
+	DescriptorId, DescriptorIndex, DescriptorType, DescriptorVersion, ID_INDEX_BITS, ID_TYPE_BITS,
 };
 use static_assertions::const_assert_eq;
 use std::any::Any;
@@ -25,16 +24,23 @@ pub trait TableInterface: Sized + 'static {
 }
 
 pub const TABLE_COUNT: u32 = 1 << ID_TYPE_BITS;
+pub const MAX_SLOTS_CAPACITY: u32 = 1 << ID_INDEX_BITS;
+/// The default amount of gc epochs, see [`TableSync::with_epochs`]
+pub const DEFAULT_EPOCHS: u32 = 4;
+
+type GrowthListener = Box<dyn Fn(TableGrowthEvent) + Send + Sync>;
 
 #[repr(C)]
 pub struct TableSync {
 	// TODO I hate this RwLock
 	tables: [RwLock<Option<Arc<dyn AbstractTable>>>; TABLE_COUNT as usize],
-	frame_mutex: CachePadded<Mutex<ABArray<u32>>>,
+	epochs: CachePadded<Mutex<Epochs>>,
 	table_next_free: CachePadded<AtomicU32>,
-	write_queue_ab: CachePadded<AtomicU32>,
+	/// The reaper queue dropped slots are pushed into: `current epoch % epoch count`
+	write_queue: CachePadded<AtomicU32>,
 	/// Mutex for both flushing ang gc. Ensures multiple flushes do not race and prevents gc-ing while flushing.
 	flush_and_gc_mutex: CachePadded<Mutex<()>>,
+	growth_listeners: RwLock<Vec<GrowthListener>>,
 }
 
 unsafe impl Send for TableSync {}
@@ -47,44 +53,98 @@ pub struct Table<I: TableInterface> {
 	slot_counters: SlotArray<SlotCounter>,
 	slots: SlotArray<UnsafeCell<MaybeUninit<I::Slot>>>,
 	flush_queue: SegQueue<RcTableSlot>,
-	reaper_queue: ABArray<SegQueue<DescriptorIndex>>,
+	/// one reaper queue per epoch, indexed by `epoch % epoch count`
+	reaper_queue: Box<[SegQueue<DescriptorIndex>]>,
 	dead_queue: SegQueue<DescriptorIndex>,
 	next_free: CachePadded<AtomicU32>,
+	live_slots: AtomicU32,
+	/// slots whose version is exhausted and that will never be reused
+	retired_slots: AtomicU32,
 	interface: I,
 }
 
 unsafe impl<I: TableInterface> Send for Table<I> {}
 unsafe impl<I: TableInterface> Sync for Table<I> {}
 
+/// Slots are garbage collected using epochs: Every [`FrameGuard`] is assigned to an epoch, and every dropped slot is
+/// queued in the epoch that was current when it was dropped. Slots of an epoch are dropped once all frames of that
+/// and all previous epochs have ended. Epochs are stored in a ring buffer, starting a new frame advances the current
+/// epoch unless the ring is full, in which case the new frame shares the current epoch.
+struct Epochs {
+	/// the epoch new frames are assigned to and dropped slots are queued in
+	current: u64,
+	/// the oldest epoch that has not been collected yet
+	oldest: u64,
+	/// the amount of alive frames per epoch, indexed by `epoch % epoch count`
+	frames: Box<[u32]>,
+}
+
+impl Epochs {
+	#[inline]
+	fn queue(&self, epoch: u64) -> usize {
+		(epoch % self.frames.len() as u64) as usize
+	}
+}
+
 impl TableSync {
 	pub fn new() -> Arc<Self> {
+		Self::with_epochs(DEFAULT_EPOCHS)
+	}
+
+	/// Creates a new TableSync with `epochs` gc epochs. More epochs allow more frames in flight to be collected
+	/// independently, while with fewer epochs multiple frames may have to share an epoch, delaying collection.
+	pub fn with_epochs(epochs: u32) -> Arc<Self> {
+		assert!(epochs >= 2, "at least 2 epochs are required, got {}", epochs);
 		Arc::new(TableSync {
 			tables: core::array::from_fn(|_| RwLock::new(None)),
 			table_next_free: CachePadded::new(AtomicU32::new(0)),
-			frame_mutex: CachePadded::new(Mutex::new(ABArray::new(|| 0))),
-			write_queue_ab: CachePadded::new(AtomicU32::new(AB::B.to_u32())),
+			epochs: CachePadded::new(Mutex::new(Epochs {
+				current: 0,
+				oldest: 0,
+				frames: (0..epochs).map(|_| 0).collect(),
+			})),
+			write_queue: CachePadded::new(AtomicU32::new(0)),
 			flush_and_gc_mutex: CachePadded::new(Mutex::new(())),
+			growth_listeners: RwLock::new(Vec::new()),
 		})
 	}
 
+	/// Register a new [`Table`] with a fixed capacity of `slots_capacity`.
 	pub fn register<I: TableInterface>(
 		self: &Arc<Self>,
 		slots_capacity: u32,
 		interface: I,
+	) -> Result<Arc<Table<I>>, TableRegisterError> {
+		self.register_growable(slots_capacity, slots_capacity, interface)
+	}
+
+	/// Register a new [`Table`] with an initial capacity of `slots_capacity`, that grows on demand up to
+	/// `max_slots_capacity`. Growing never invalidates existing slots or their ids. `max_slots_capacity` is clamped to
+	/// [`MAX_SLOTS_CAPACITY`].
+	pub fn register_growable<I: TableInterface>(
+		self: &Arc<Self>,
+		slots_capacity: u32,
+		max_slots_capacity: u32,
+		interface: I,
 	) -> Result<Arc<Table<I>>, TableRegisterError> {
 		let table_id = self.table_next_free.fetch_add(1, Relaxed);
 		if table_id < TABLE_COUNT {
+			let max_slots_capacity = max_slots_capacity.min(MAX_SLOTS_CAPACITY);
 			let mut guard = self.tables[table_id as usize].write();
 			let table = Arc::new(Table {
 				table_sync: Arc::downgrade(self),
 				table_id: unsafe { DescriptorType::new(table_id).unwrap() },
 				interface,
-				slot_counters: SlotArray::new(slots_capacity),
-				slots: SlotArray::new_generator(slots_capacity, |_| UnsafeCell::new(MaybeUninit::uninit())),
+				slot_counters: SlotArray::new(slots_capacity, max_slots_capacity),
+				slots: SlotArray::new_generator(slots_capacity, max_slots_capacity, || {
+					UnsafeCell::new(MaybeUninit::uninit())
+				}),
 				flush_queue: SegQueue::new(),
-				reaper_queue: ABArray::new(SegQueue::new),
+				reaper_queue: (0..self.epoch_count()).map(|_| SegQueue::new()).collect(),
 				dead_queue: SegQueue::new(),
 				next_free: CachePadded::new(AtomicU32::new(0)),
+				live_slots: AtomicU32::new(0),
+				retired_slots: AtomicU32::new(0),
 			});
 			let old_table = guard.replace(table.clone() as Arc<dyn AbstractTable>);
 			assert!(old_table.is_none());
@@ -94,74 +154,107 @@ impl TableSync {
 		}
 	}
 
+	/// Add a listener that is called whenever any table grows its capacity. Listeners are called on the thread
+	/// allocating the slot that caused the growth, and must not register further listeners.
+	pub fn add_growth_listener(&self, listener: impl Fn(TableGrowthEvent) + Send + Sync + 'static) {
+		self.growth_listeners.write().push(Box::new(listener));
+	}
+
+	fn notify_growth(&self, event: TableGrowthEvent) {
+		for listener in self.growth_listeners.read().iter() {
+			listener(event);
+		}
+	}
+
+	/// The amount of gc epochs
 	#[inline]
-	fn write_queue_ab(&self) -> AB {
-		AB::from_u32(self.write_queue_ab.load(Relaxed)).unwrap()
+	pub fn epoch_count(&self) -> u32 {
+		self.epochs.lock().frames.len() as u32
 	}
 
 	#[inline]
-	fn frame_ab(&self) -> AB {
-		!self.write_queue_ab()
+	fn write_queue(&self) -> usize {
+		self.write_queue.load(Relaxed) as usize
 	}
 
 	pub fn frame(self: &Arc<Self>) -> FrameGuard {
-		let frame_ab;
+		let epoch;
 		{
-			let mut guard = self.frame_mutex.lock();
-			frame_ab = self.frame_ab();
-			guard[frame_ab] += 1;
-
-			// if we ran dry of frames (like we are at startup), switch frame ab after first frame
-			if guard[!frame_ab] == 0 {
-				self.gc_queue(guard, !frame_ab);
-			}
+			let mut epochs = self.epochs.lock();
+			// slots dropped before this frame started can't be referenced by it, so we try to separate them
+			self.try_advance_epoch(&mut epochs);
+			epoch = epochs.current;
+			let queue = epochs.queue(epoch);
+			epochs.frames[queue] += 1;
 		}
 
 		FrameGuard {
 			table_manager: self.clone(),
-			frame_ab,
+			epoch,
 		}
 	}
 
-	fn frame_drop(self: &Arc<Self>, dropped_frame_ab: AB) {
-		let mut guard = self.frame_mutex.lock();
-		let frame_cnt = &mut guard[dropped_frame_ab];
+	fn frame_drop(self: &Arc<Self>, epoch: u64) {
+		let mut epochs = self.epochs.lock();
+		let queue = epochs.queue(epoch);
+		let frame_cnt = &mut epochs.frames[queue];
 		match *frame_cnt {
 			0 => panic!("frame ref counting underflow"),
 			1 => {
 				*frame_cnt = 0;
-				let frame_ab = self.frame_ab();
-				if frame_ab != dropped_frame_ab {
-					self.gc_queue(guard, dropped_frame_ab);
-				}
+				self.gc(epochs);
 			}
 			_ => *frame_cnt -= 1,
 		}
 	}
 
-	#[cold]
-	#[inline(never)]
-	fn gc_queue(&self, guard: MutexGuard<ABArray<u32>>, dropped_frame_ab: AB) {
-		let _guard2 = self.flush_and_gc_mutex.lock();
-		let table_gc_indices;
-		{
-			let gc_queue = !dropped_frame_ab;
-			table_gc_indices = self
-				.tables
-				.iter()
-				.map(|table_lock| {
-					let table = table_lock.read();
-					table.as_ref().map(|table| table.gc_collect(gc_queue))
-				})
-				.collect::<Vec<_>>();
+	/// Advances to a new epoch and drops all slots that are no longer referenced and were dropped in epochs without any
+	/// alive frames. Slots dropped in epochs of frames still alive are kept until these frames end. Slots in the flush
+	/// queue are referenced by it and must be flushed first to be collected.
+	pub fn collect_now(&self) {
+		let mut epochs = self.epochs.lock();
+		self.try_advance_epoch(&mut epochs);
+		self.gc(epochs);
+	}
 
+	/// Advance the current epoch, unless the ring of epochs is full
+	fn try_advance_epoch(&self, epochs: &mut Epochs) {
+		let next = epochs.current + 1;
+		if next - epochs.oldest < epochs.frames.len() as u64 {
+			epochs.current = next;
 			// Release may seem a bit defensive here, as we don't actually need to flush any memory.
 			// But it ensures that when creating a new FrameGuard afterward and sending it to another thread via
 			// Rel/Acq, this write is visible. Which is important as it could otherwise write to be gc'ed objects to the
 			// wrong queue.
-			self.write_queue_ab.store(gc_queue.to_u32(), Release);
-			drop(guard);
+			self.write_queue.store(epochs.queue(next) as u32, Release);
+		}
+	}
+
+	/// Collect all epochs before the current one that have no alive frames, starting from the oldest
+	fn gc(&self, mut epochs: MutexGuard<Epochs>) {
+		let mut gc_queues = Vec::new();
+		while epochs.oldest < epochs.current && epochs.frames[epochs.queue(epochs.oldest)] == 0 {
+			gc_queues.push(epochs.queue(epochs.oldest));
+			epochs.oldest += 1;
+		}
+		if !gc_queues.is_empty() {
+			self.gc_queues(epochs, &gc_queues);
 		}
+	}
+
+	#[cold]
+	#[inline(never)]
+	fn gc_queues(&self, epochs: MutexGuard<Epochs>, gc_queues: &[usize]) {
+		let _guard2 = self.flush_and_gc_mutex.lock();
+		let table_gc_indices = self
+			.tables
+			.iter()
+			.map(|table_lock| {
+				let table = table_lock.read();
+				table.as_ref().map(|table| table.gc_collect(gc_queues))
+			})
+			.collect::<Vec<_>>();
+		drop(epochs);
 
 		for (table, gc_indices) in self.tables.iter().zip(table_gc_indices) {
 			if let Some(gc_indices) = gc_indices {
@@ -181,7 +274,7 @@ impl TableSync {
 		let table = self.tables[id.desc_type().to_usize()].read();
 		if let Some(table) = table.as_ref() {
 			table
-				.try_recover(id, self.write_queue_ab())
+				.try_recover(id, self.write_queue())
 				.then(|| unsafe { RcTableSlot::new(Arc::as_ptr(self), id) })
 		} else {
 			None
@@ -218,9 +311,21 @@ impl FlushGuard<'_> {
 }
 
 impl<I: TableInterface> Table<I> {
+	#[inline]
+	pub fn table_id(&self) -> DescriptorType {
+		self.table_id
+	}
+
+	/// The amount of slots currently allocated
 	#[inline]
 	pub fn slots_capacity(&self) -> u32 {
-		self.slot_counters.len() as u32
+		self.slots.capacity()
+	}
+
+	/// The amount of slots this table may grow to
+	#[inline]
+	pub fn max_slots_capacity(&self) -> u32 {
+		self.slots.max_capacity()
 	}
 
 	pub fn alloc_slot(self: &Arc<Self>, slot: I::Slot) -> Result<RcTableSlot, SlotAllocationError> {
@@ -228,13 +333,15 @@ impl<I: TableInterface> Table<I> {
 			Ok(index)
 		} else {
 			let index = self.next_free.fetch_add(1, Relaxed);
-			if index < self.slots_capacity() {
+			if index < self.max_slots_capacity() {
+				self.grow_to_fit(index);
 				// Safety: atomic ensures it's unique
 				unsafe { Ok(DescriptorIndex::new(index).unwrap()) }
 			} else {
-				Err(SlotAllocationError::NoMoreCapacity(self.slots_capacity()))
+				Err(SlotAllocationError::NoMoreCapacity(self.max_slots_capacity()))
 			}
 		}?;
+		self.live_slots.fetch_add(1, Relaxed);
 
 		// Safety: we just allocated index, we have exclusive access to slot, which is currently uninitialized
 		unsafe { (*self.slots[index].get()).write(slot) };
@@ -250,6 +357,29 @@ impl<I: TableInterface> Table<I> {
 		}
 	}
 
+	#[cold]
+	#[inline(never)]
+	fn grow_to_fit_slow(&self, index: u32) {
+		self.slot_counters.grow_to_fit(index);
+		if let Some((old_capacity, new_capacity)) = self.slots.grow_to_fit(index) {
+			if let Some(table_sync) = self.table_sync.upgrade() {
+				table_sync.notify_growth(TableGrowthEvent {
+					table_id: self.table_id,
+					old_capacity,
+					new_capacity,
+					max_capacity: self.max_slots_capacity(),
+				});
+			}
+		}
+	}
+
+	#[inline]
+	fn grow_to_fit(&self, index: u32) {
+		if index >= self.slots_capacity() {
+			self.grow_to_fit_slow(index);
+		}
+	}
+
 	/// Get the contents of the slot unchecked
 	///
 	/// # Safety
@@ -258,6 +388,64 @@ impl<I: TableInterface> Table<I> {
 		unsafe { (*self.slots[index].get()).assume_init_ref() }
 	}
 
+	/// Returns new references to all slots that are still referenced elsewhere, meant for debugging purposes like
+	/// reporting leaked slots. Slots still in the flush queue are referenced by it.
+	pub fn referenced_slots(&self) -> Vec<RcTableSlot> {
+		let Some(table_sync) = self.table_sync.upgrade() else {
+			return Vec::new();
+		};
+		let end = self.next_free.load(Relaxed).min(self.max_slots_capacity());
+		(0..end)
+			.filter_map(|i| {
+				// Safety: i is below capacity
+				let index = unsafe { DescriptorIndex::new(i).unwrap() };
+				// Safety: try_ref_inc transfers the ref_count inc to the RcTableSlot
+				self.try_ref_inc(index)
+					.map(|id| unsafe { RcTableSlot::new(Arc::as_ptr(&table_sync), id) })
+			})
+			.collect()
+	}
+
+	/// Increments the ref count of the slot at `index` and returns its id, unless the slot has been deallocated or was
+	/// never allocated.
+	fn try_ref_inc(&self, index: DescriptorIndex) -> Option<DescriptorId> {
+		// slots capacity is only increased after slot counters have been allocated
+		if index.to_u32() >= self.slots_capacity() {
+			return None;
+		}
+		let counters = &self.slot_counters[index];
+		let mut old = counters.ref_count.load(Acquire);
+		loop {
+			if old == 0 {
+				// slot has been deallocated
+				break None;
+			}
+
+			match counters.ref_count.compare_exchange_weak(old, old + 1, Acquire, Relaxed) {
+				// Safety: we inc ref count so this slot must be alive and we can read this
+				Ok(_) => break Some(unsafe { DescriptorId::new(self.table_id, index, counters.read_version()) }),
+				Err(o) => old = o,
+			}
+		}
+	}
+
+	/// Collect statistics about this table. As other threads may concurrently modify the table, the individual values
+	/// may not be consistent with each other.
+	pub fn stats(&self) -> TableStats {
+		let max_capacity = self.max_slots_capacity();
+		TableStats {
+			table_id: self.table_id,
+			live_slots: self.live_slots.load(Relaxed),
+			capacity: self.slots_capacity(),
+			max_capacity,
+			unused_slots: max_capacity.saturating_sub(self.next_free.load(Relaxed)),
+			retired_slots: self.retired_slots.load(Relaxed),
+			dead_queue_len: self.dead_queue.len(),
+			reaper_queue_len: self.reaper_queue.iter().map(SegQueue::len).sum(),
+			flush_queue_len: self.flush_queue.len(),
+		}
+	}
+
 	#[inline]
 	pub fn drain_flush_queue(&self) -> DrainFlushQueue<'_, I> {
 		DrainFlushQueue(self)
@@ -276,11 +464,11 @@ impl<I: TableInterface> Deref for Table<I> {
 trait AbstractTable: Any + Send + Sync + 'static {
 	fn as_any(&self) -> &dyn Any;
 	fn ref_inc(&self, id: DescriptorId);
-	fn ref_dec(&self, id: DescriptorId, write_queue_ab: AB) -> bool;
-	fn gc_collect(&self, gc_queue: AB) -> DescriptorIndexRangeSet<'static, ()>;
+	fn ref_dec(&self, id: DescriptorId, write_queue: usize) -> bool;
+	fn gc_collect(&self, gc_queues: &[usize]) -> DescriptorIndexRangeSet<'static, ()>;
 	fn gc_drop(&self, gc_indices: DescriptorIndexRangeSet<'static, ()>);
 	fn flush(&self);
-	fn try_recover(&self, id: DescriptorId, write_queue_ab: AB) -> bool;
+	fn try_recover(&self, id: DescriptorId, write_queue: usize) -> bool;
 }
 
 impl<I: TableInterface> AbstractTable for Table<I> {
@@ -294,21 +482,24 @@ impl<I: TableInterface> AbstractTable for Table<I> {
 	}
 
 	#[inline]
-	fn ref_dec(&self, id: DescriptorId, write_queue_ab: AB) -> bool {
+	fn ref_dec(&self, id: DescriptorId, write_queue: usize) -> bool {
 		match self.slot_counters[id.index()].ref_count.fetch_sub(1, Relaxed) {
 			0 => panic!("TableSlot ref_count underflow!"),
 			1 => {
 				fence(Acquire);
-				self.reaper_queue[write_queue_ab].push(id.index());
+				self.reaper_queue[write_queue].push(id.index());
 				true
 			}
 			_ => false,
 		}
 	}
 
-	fn gc_collect(&self, gc_queue: AB) -> DescriptorIndexRangeSet<'static, ()> {
-		let reaper_queue = &self.reaper_queue[gc_queue];
-		unsafe { DescriptorIndexRangeSet::from(&(), (0..).map_while(|_| reaper_queue.pop())) }
+	fn gc_collect(&self, gc_queues: &[usize]) -> DescriptorIndexRangeSet<'static, ()> {
+		let indices = gc_queues.iter().flat_map(|&queue| {
+			let reaper_queue = &self.reaper_queue[queue];
+			(0..).map_while(|_| reaper_queue.pop())
+		});
+		unsafe { DescriptorIndexRangeSet::from(&(), indices) }
 	}
 
 	fn gc_drop(&self, gc_indices: DescriptorIndexRangeSet<'static, ()>) {
@@ -316,7 +507,10 @@ impl<I: TableInterface> AbstractTable for Table<I> {
 
 		self.interface.drop_slots(&gc_indices);
 
+		let mut dropped = 0;
+		let mut retired = 0;
 		for (i, _) in gc_indices.iter() {
+			dropped += 1;
 			// Safety: we have exclusive access to the previously initialized slot
 			let valid_version = unsafe {
 				(*self.slots.index(i).get()).assume_init_drop();
@@ -328,36 +522,30 @@ impl<I: TableInterface> AbstractTable for Table<I> {
 			// we send / share the slot to the dead_queue
 			if valid_version {
 				self.dead_queue.push(i);
+			} else {
+				// the version would wrap around, retire the slot so stale ids can never alias a new allocation
+				retired += 1;
 			}
 		}
+		self.live_slots.fetch_sub(dropped, Relaxed);
+		if retired > 0 {
+			self.retired_slots.fetch_add(retired, Relaxed);
+		}
 	}
 
 	fn flush(&self) {
 		self.interface.flush(&mut self.drain_flush_queue())
 	}
 
-	fn try_recover(&self, id: DescriptorId, write_queue_ab: AB) -> bool {
-		let counters = &self.slot_counters[id.index()];
-		let mut old = counters.ref_count.load(Acquire);
-		loop {
-			if old == 0 {
-				// slot has been deallocated
-				break false;
-			}
-
-			match counters.ref_count.compare_exchange_weak(old, old + 1, Acquire, Relaxed) {
-				Ok(_) => {
-					// Safety: we inc ref count so this slot must be alive and we can read this
-					let version = unsafe { *counters.version.get() };
-					if id.version().to_u32() == version {
-						break true;
-					} else {
-						// slot has been reused
-						self.ref_dec(id, write_queue_ab);
-						break false;
-					}
-				}
-				Err(o) => old = o,
+	fn try_recover(&self, id: DescriptorId, write_queue: usize) -> bool {
+		match self.try_ref_inc(id.index()) {
+			// slot has been deallocated
+			None => false,
+			Some(current) if current == id => true,
+			Some(current) => {
+				// slot has been reused
+				self.ref_dec(current, write_queue);
+				false
 			}
 		}
 	}
@@ -366,9 +554,8 @@ impl<I: TableInterface> AbstractTable for Table<I> {
 impl<I: TableInterface> Drop for Table<I> {
 	fn drop(&mut self) {
 		self.flush();
-		for ab in AB::VALUES {
-			self.gc_drop(self.gc_collect(ab))
-		}
+		let gc_queues = (0..self.reaper_queue.len()).collect::<Vec<_>>();
+		self.gc_drop(self.gc_collect(&gc_queues))
 	}
 }
 
@@ -468,8 +655,8 @@ impl Clone for RcTableSlot {
 impl Drop for RcTableSlot {
 	#[inline]
 	fn drop(&mut self) {
-		let write_queue_ab = self.table_sync().write_queue_ab();
-		if self.table(|t| t.ref_dec(self.id, write_queue_ab)) {
+		let write_queue = self.table_sync().write_queue();
+		if self.table(|t| t.ref_dec(self.id, write_queue)) {
 			// Safety: slot ref count hit 0, so decrement ref count of `TableManager` which was incremented in
 			// `alloc_slot()` when this slot was created
 			unsafe { drop(Arc::from_raw(self.table)) };
@@ -479,7 +666,7 @@ impl Drop for RcTableSlot {
 
 pub struct FrameGuard {
 	table_manager: Arc<TableSync>,
-	frame_ab: AB,
+	epoch: u64,
 }
 
 impl FrameGuard {
@@ -487,14 +674,15 @@ impl FrameGuard {
 		&self.table_manager
 	}
 
-	pub fn ab(&self) -> AB {
-		self.frame_ab
+	/// The gc epoch this frame is assigned to
+	pub fn epoch(&self) -> u64 {
+		self.epoch
 	}
 }
 
 impl Drop for FrameGuard {
 	fn drop(&mut self) {
-		self.table_manager.frame_drop(self.frame_ab);
+		self.table_manager.frame_drop(self.epoch);
 	}
 }
 
@@ -520,6 +708,38 @@ impl<I: TableInterface> Iterator for DrainFlushQueue<'_, I> {
 	}
 }
 
+/// Statistics about a [`Table`], see [`Table::stats`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TableStats {
+	pub table_id: DescriptorType,
+	/// Slots that are allocated, including slots that are no longer referenced but have yet to be dropped by gc
+	pub live_slots: u32,
+	/// The amount of slots currently allocated, see [`Table::slots_capacity`]
+	pub capacity: u32,
+	/// The amount of slots this table may grow to, see [`Table::max_slots_capacity`]
+	pub max_capacity: u32,
+	/// Slots that have never been allocated
+	pub unused_slots: u32,
+	/// Slots that have been reused so often that their [`DescriptorVersion`] is exhausted. They are never reused
+	/// again, to prevent stale ids from aliasing newer allocations of the same slot.
+	pub retired_slots: u32,
+	/// Slots that have been dropped and are ready to be reused
+	pub dead_queue_len: usize,
+	/// Slots that are no longer referenced and are waiting for gc to drop them
+	pub reaper_queue_len: usize,
+	/// Newly allocated slots that have yet to be flushed
+	pub flush_queue_len: usize,
+}
+
+/// Emitted whenever a [`Table`] grows its capacity, see [`TableSync::add_growth_listener`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TableGrowthEvent {
+	pub table_id: DescriptorType,
+	pub old_capacity: u32,
+	pub new_capacity: u32,
+	pub max_capacity: u32,
+}
+
 #[derive(Debug)]
 pub enum TableRegisterError {
 	OutOfTables,
@@ -559,8 +779,9 @@ impl Display for SlotAllocationError {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::backing::ab::AB::*;
 	use rangemap::RangeSet;
+	use rust_gpu_bindless_shaders::descriptor::ID_VERSION_BITS;
+	use std::collections::VecDeque;
 	use std::mem::take;
 
 	struct DummyInterface;
@@ -646,6 +867,51 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_alloc_slot_growable() -> anyhow::Result<()> {
+		const N: u32 = 4;
+		const MAX: u32 = 20;
+
+		let tm = TableSync::new();
+		let events = Arc::new(Mutex::new(Vec::new()));
+		{
+			let events = events.clone();
+			tm.add_growth_listener(move |event| events.lock().push(event));
+		}
+		let table = tm.register_growable(N, MAX, DummyInterface)?;
+		assert_eq!(table.slots_capacity(), N);
+		assert_eq!(table.max_slots_capacity(), MAX);
+
+		{
+			let slots = (0..MAX)
+				.map(|i| {
+					let slot = table.alloc_slot(Arc::new(42 + i)).unwrap();
+					assert_eq!(slot.id.index().to_u32(), i);
+					assert_eq!(slot.id.version().to_u32(), 0);
+					slot
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(table.slots_capacity(), MAX);
+
+			table.alloc_slot(Arc::new(69)).expect_err("we should be out of slots");
+
+			// growing must not move existing slots
+			for (i, slot) in slots.iter().enumerate() {
+				assert_eq!(**slot.try_deref::<DummyInterface>().unwrap(), 42 + i as u32);
+			}
+		}
+		tm.flush();
+
+		let growth = |old_capacity, new_capacity| TableGrowthEvent {
+			table_id: table.table_id(),
+			old_capacity,
+			new_capacity,
+			max_capacity: MAX,
+		};
+		assert_eq!(*events.lock(), [growth(4, 8), growth(8, 16), growth(16, 20)]);
+		Ok(())
+	}
+
 	#[test]
 	fn test_slot_reuse() -> anyhow::Result<()> {
 		let tm = TableSync::new();
@@ -699,102 +965,107 @@ mod tests {
 		let tm = TableSync::new();
 		tm.register(128, DummyInterface)?;
 
-		let frame = |exp: AB| {
+		for i in 0..5 {
 			let f = tm.frame();
-			assert_eq!(f.frame_ab, exp);
+			assert_eq!(f.epoch(), i + 1);
 			drop(f);
-		};
-
-		for _ in 0..5 {
-			frame(A);
 		}
 
 		Ok(())
 	}
 
 	#[test]
-	fn test_frames_dry_out() -> anyhow::Result<()> {
-		let tm = TableSync::new();
+	fn test_frames_ring_full() -> anyhow::Result<()> {
+		let tm = TableSync::with_epochs(2);
 		tm.register(128, DummyInterface)?;
 
 		for i in 0..5 {
-			println!("iter {}", i);
-			let flip = |ab: AB| if i % 2 == 0 { ab } else { !ab };
+			let a = tm.frame();
+			assert_eq!(a.epoch(), i + 1);
+			// the ring is full, so b has to share the epoch with a
+			let b = tm.frame();
+			assert_eq!(b.epoch(), i + 1);
+			drop((a, b));
+		}
+		Ok(())
+	}
 
-			assert_eq!(tm.frame_ab(), flip(A));
-			let a1 = tm.frame();
-			assert_eq!(a1.frame_ab, flip(A));
+	#[test]
+	fn test_frames_in_flight() -> anyhow::Result<()> {
+		let tm = TableSync::with_epochs(4);
+		tm.register(128, DummyInterface)?;
 
-			assert_eq!(tm.frame_ab(), flip(B));
-			let b1 = tm.frame();
-			assert_eq!(b1.frame_ab, flip(B));
+		let f1 = tm.frame();
+		let f2 = tm.frame();
+		let f3 = tm.frame();
+		let f4 = tm.frame();
+		assert_eq!([f1.epoch(), f2.epoch(), f3.epoch(), f4.epoch()], [1, 2, 3, 3]);
+
+		drop(f1);
+		let f5 = tm.frame();
+		assert_eq!(f5.epoch(), 4);
 
-			assert_eq!(tm.frame_ab(), flip(B));
-			drop(a1);
-			assert_eq!(tm.frame_ab(), flip(A));
-			drop(b1);
-			assert_eq!(tm.frame_ab(), flip(B));
-		}
 		Ok(())
 	}
 
 	#[test]
 	fn test_frames_interleaved() -> anyhow::Result<()> {
-		let tm = TableSync::new();
+		let tm = TableSync::with_epochs(4);
 		tm.register(128, DummyInterface)?;
 
 		let a1 = tm.frame();
-		assert_eq!(a1.frame_ab, A);
+		assert_eq!(a1.epoch(), 1);
 
 		let b1 = tm.frame();
-		assert_eq!(b1.frame_ab, B);
+		assert_eq!(b1.epoch(), 2);
 		let b2 = tm.frame();
-		assert_eq!(b2.frame_ab, B);
+		assert_eq!(b2.epoch(), 3);
 
 		drop(a1);
 		let a2 = tm.frame();
-		assert_eq!(a2.frame_ab, A);
+		assert_eq!(a2.epoch(), 4);
 		let a3 = tm.frame();
-		assert_eq!(a3.frame_ab, A);
+		assert_eq!(a3.epoch(), 5);
 
 		drop((b1, b2));
 		let b3 = tm.frame();
-		assert_eq!(b3.frame_ab, B);
+		assert_eq!(b3.epoch(), 6);
 
-		// no switch!
+		// a2 blocks epoch 4 from being collected, so the ring is full after b4
 		drop(b3);
 		let b4 = tm.frame();
-		assert_eq!(b4.frame_ab, B);
+		assert_eq!(b4.epoch(), 7);
+		let b5 = tm.frame();
+		assert_eq!(b5.epoch(), 7);
 
 		Ok(())
 	}
 
-	struct FrameSwitch {
+	/// Keeps a fixed amount of frames in flight, like a renderer would
+	struct FramesInFlight {
 		tm: Arc<TableSync>,
-		frame: ABArray<Option<FrameGuard>>,
-		ab: AB,
+		frames: VecDeque<FrameGuard>,
+		in_flight: usize,
 	}
 
-	impl FrameSwitch {
-		pub fn new(tm: Arc<TableSync>) -> Self {
-			let mut switch = Self {
+	impl FramesInFlight {
+		pub fn new(tm: Arc<TableSync>, in_flight: usize) -> Self {
+			let mut frames = Self {
 				tm,
-				frame: ABArray::new(|| None),
-				ab: A,
+				frames: VecDeque::new(),
+				in_flight,
 			};
-			for _ in 0..3 {
-				switch.switch();
+			for _ in 0..=in_flight {
+				frames.switch();
 			}
-			switch
+			frames
 		}
 
 		pub fn switch(&mut self) {
-			let slot = &mut self.frame[self.ab];
-			drop(slot.take());
-			let frame = self.tm.frame();
-			assert_eq!(frame.frame_ab, self.ab);
-			*slot = Some(frame);
-			self.ab = !self.ab;
+			if self.frames.len() == self.in_flight {
+				drop(self.frames.pop_front());
+			}
+			self.frames.push_back(self.tm.frame());
 		}
 	}
 
@@ -802,7 +1073,7 @@ mod tests {
 	fn test_gc() -> anyhow::Result<()> {
 		let tm = TableSync::new();
 		let table = tm.register(128, SimpleInterface::new())?;
-		let mut switch = FrameSwitch::new(tm.clone());
+		let mut switch = FramesInFlight::new(tm.clone(), 2);
 		let ti = &table.interface;
 		ti.take();
 
@@ -838,9 +1109,7 @@ mod tests {
 		let ti = &table.interface;
 
 		let a1 = tm.frame();
-		assert_eq!(a1.frame_ab, A);
 		let long_frame_b = tm.frame();
-		assert_eq!(long_frame_b.frame_ab, B);
 		drop(a1);
 
 		drop(table.alloc_slot(Arc::new(42))?);
@@ -850,23 +1119,72 @@ mod tests {
 		// doesn't matter how many frames, it never gets dropped until long_frame_b is done
 		for _ in 0..5 {
 			let a = tm.frame();
-			assert_eq!(a.frame_ab, A);
+			assert!(a.epoch() > long_frame_b.epoch());
 			drop(a);
 			// no cleanup happened
 			assert_eq!(ti.take(), &[&[]; 0]);
 		}
 
-		// gc of nothing
+		// all later frames are done, so it is dropped immediately
 		drop(long_frame_b);
-		assert_eq!(ti.take(), simple_empty());
+		assert_eq!(ti.take(), &[&[0][..]]);
 
-		// 2nd gc should drop 0
 		drop(tm.frame());
+		assert_eq!(ti.take(), simple_empty());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_collect_now() -> anyhow::Result<()> {
+		let tm = TableSync::new();
+		let table = tm.register(128, SimpleInterface::new())?;
+		let ti = &table.interface;
+
+		drop(table.alloc_slot(Arc::new(42))?);
+		tm.flush();
+		assert_eq!(ti.take(), simple_empty());
+		tm.collect_now();
+		assert_eq!(ti.take(), &[&[0][..]]);
+
+		// can't collect slots that may be used by an alive frame
+		let frame = tm.frame();
+		drop(table.alloc_slot(Arc::new(69))?);
+		tm.flush();
+		tm.collect_now();
+		assert_eq!(ti.take(), simple_empty());
+		drop(frame);
 		assert_eq!(ti.take(), &[&[0][..]]);
 
 		Ok(())
 	}
 
+	#[test]
+	fn test_version_retire() -> anyhow::Result<()> {
+		let tm = TableSync::new();
+		let table = tm.register(1, DummyInterface)?;
+
+		let mut last = None;
+		for version in 0..1 << ID_VERSION_BITS {
+			let slot = table.alloc_slot(Arc::new(version))?;
+			assert_eq!(slot.id.index().to_u32(), 0);
+			assert_eq!(slot.id.version().to_u32(), version);
+			last = Some(slot.id);
+			drop(slot);
+			tm.flush();
+			tm.collect_now();
+		}
+
+		// the slot's version is exhausted, so it must not be reused
+		assert_eq!(table.stats().retired_slots, 1);
+		assert_eq!(table.stats().dead_queue_len, 0);
+		assert!(!table.try_recover(last.unwrap(), 0));
+		table
+			.alloc_slot(Arc::new(42))
+			.expect_err("retired slot should not be reused");
+		Ok(())
+	}
+
 	#[test]
 	fn test_gc_dry_out() -> anyhow::Result<()> {
 		let tm = TableSync::new();
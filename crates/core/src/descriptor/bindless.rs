@@ -1,11 +1,16 @@
-use crate::backing::table::{FrameGuard, TableSync};
+use crate::backing::table::{FrameGuard, MAX_SLOTS_CAPACITY, TableStats, TableSync};
 use crate::descriptor::buffer_table::{BufferTable, BufferTableAccess};
+use crate::descriptor::custom_table::{
+	AnyCustomTable, CustomDescContent, CustomTable, CustomTableAccess, CustomTableCreateInfo,
+};
 use crate::descriptor::descriptor_counts::DescriptorCounts;
 use crate::descriptor::image_table::{ImageTable, ImageTableAccess};
+use crate::descriptor::leak_report::{LeakPolicy, LeakReport};
 use crate::descriptor::sampler_table::{SamplerTable, SamplerTableAccess};
 use crate::platform::BindlessPlatform;
+use parking_lot::Mutex;
 use rust_gpu_bindless_shaders::buffer_content::Metadata;
-use rust_gpu_bindless_shaders::descriptor::TransientAccess;
+use rust_gpu_bindless_shaders::descriptor::{BINDING_CUSTOM_BASE, TransientAccess};
 use std::ops::Deref;
 use std::sync::{Arc, Weak};
 
@@ -55,6 +60,8 @@ pub struct BindlessInner<P: BindlessPlatform> {
 	pub(super) buffer: BufferTable<P>,
 	pub(super) image: ImageTable<P>,
 	pub(super) sampler: SamplerTable<P>,
+	pub(super) custom: Vec<Box<dyn AnyCustomTable<P>>>,
+	leak_policy: Mutex<LeakPolicy>,
 }
 
 impl<P: BindlessPlatform> Deref for BindlessInner<P> {
@@ -82,6 +89,7 @@ impl<P: BindlessPlatform> Drop for BindlessInstance<P> {
 		unsafe {
 			self.bindless_shutdown(&self.0);
 		}
+		self.report_leaks();
 	}
 }
 
@@ -92,19 +100,98 @@ impl<P: BindlessPlatform> BindlessInstance<P> {
 	/// * There must only be one global Bindless instance for each [`Device`].
 	/// * The [general bindless safety requirements](crate#safety) apply
 	pub unsafe fn new(ci: P::PlatformCreateInfo, counts: DescriptorCounts) -> Self {
+		unsafe { Self::new_growable(ci, counts, counts) }
+	}
+
+	/// Creates a new Descriptors instance with which to allocate descriptors. The tables start out with a capacity of
+	/// `counts` and grow on demand up to `max_counts`, which is clamped to [`DescriptorCounts::limits`] of the
+	/// platform. Growing never invalidates existing [`DescriptorId`]s and can be observed using
+	/// [`TableSync::add_growth_listener`].
+	///
+	/// The platform's descriptor set is sized for `max_counts` upfront, as recreating it would invalidate all
+	/// pipeline layouts created with it. Consider using moderate `max_counts` if descriptor memory is a concern.
+	///
+	/// # Safety
+	/// * There must only be one global Bindless instance for each [`Device`].
+	/// * The [general bindless safety requirements](crate#safety) apply
+	///
+	/// [`DescriptorId`]: rust_gpu_bindless_shaders::descriptor::DescriptorId
+	pub unsafe fn new_growable(
+		ci: P::PlatformCreateInfo,
+		counts: DescriptorCounts,
+		max_counts: DescriptorCounts,
+	) -> Self {
+		unsafe { Self::new_with_custom_tables(ci, counts, max_counts, Vec::new()) }
+	}
+
+	/// Creates a new Descriptors instance like [`Self::new_growable`], with additional `custom_tables` registered in
+	/// the global descriptor set. Access them with [`Bindless::custom`]. Every custom table uses up one
+	/// [`DescriptorType`], of which only [`TABLE_COUNT`] - 3 are available to custom tables.
+	///
+	/// # Safety
+	/// * There must only be one global Bindless instance for each [`Device`].
+	/// * The [general bindless safety requirements](crate#safety) apply
+	///
+	/// [`DescriptorType`]: rust_gpu_bindless_shaders::descriptor::DescriptorType
+	/// [`TABLE_COUNT`]: crate::backing::table::TABLE_COUNT
+	pub unsafe fn new_with_custom_tables(
+		ci: P::PlatformCreateInfo,
+		counts: DescriptorCounts,
+		max_counts: DescriptorCounts,
+		custom_tables: Vec<CustomTableCreateInfo<P>>,
+	) -> Self {
+		for (i, custom) in custom_tables.iter().enumerate() {
+			let binding = custom.binding().binding;
+			assert!(
+				binding >= BINDING_CUSTOM_BASE,
+				"custom table binding {} must be at least {}",
+				binding,
+				BINDING_CUSTOM_BASE
+			);
+			assert!(
+				custom_tables[..i]
+					.iter()
+					.all(|other| other.binding().binding != binding),
+				"custom table binding {} is used multiple times",
+				binding
+			);
+		}
+
 		unsafe {
 			let bindless = Bindless(Arc::new_cyclic(|weak| {
 				let weak = WeakBindless(weak.clone());
 				// TODO propagate error
 				let platform = P::create_platform(ci, &weak).unwrap();
 				counts.assert_within_limits::<P>(&platform);
+				let max_counts = max_counts
+					.min(DescriptorCounts::limits(&platform))
+					.min(DescriptorCounts::MAX);
+				assert!(
+					counts.is_within_limit(max_counts),
+					"{:?} must be within max counts of {:?}",
+					counts,
+					max_counts
+				);
 
 				let table_sync = TableSync::new();
+				let buffer = BufferTable::new(&table_sync, counts, max_counts, weak.clone());
+				let image = ImageTable::new(&table_sync, counts, max_counts, weak.clone());
+				let sampler = SamplerTable::new(&table_sync, counts, max_counts, weak.clone());
+				let custom = custom_tables
+					.into_iter()
+					.map(|custom| {
+						let max_count = custom.binding().count.min(MAX_SLOTS_CAPACITY);
+						custom.register(&table_sync, max_count, weak.clone())
+					})
+					.collect::<Vec<_>>();
+				let custom_bindings = custom.iter().map(|custom| custom.binding()).collect::<Vec<_>>();
 				BindlessInner {
-					buffer: BufferTable::new(&table_sync, counts, weak.clone()),
-					image: ImageTable::new(&table_sync, counts, weak.clone()),
-					sampler: SamplerTable::new(&table_sync, counts, weak),
-					descriptor_set: Some(platform.create_descriptor_set(counts)),
+					leak_policy: Mutex::new(LeakPolicy::default()),
+					buffer,
+					image,
+					sampler,
+					custom,
+					descriptor_set: Some(platform.create_descriptor_set(max_counts, &custom_bindings)),
 					table_sync,
 					platform,
 				}
@@ -115,6 +202,28 @@ impl<P: BindlessPlatform> BindlessInstance<P> {
 	}
 }
 
+impl<P: BindlessPlatform> BindlessInstance<P> {
+	/// Set what to do with leaked resources when this instance is dropped, defaults to [`LeakPolicy::Log`].
+	pub fn set_leak_policy(&self, leak_policy: LeakPolicy) {
+		*self.leak_policy.lock() = leak_policy;
+	}
+
+	fn report_leaks(&self) {
+		let leak_policy = *self.leak_policy.lock();
+		if leak_policy == LeakPolicy::Ignore {
+			return;
+		}
+		let report = self.leak_report();
+		if !report.is_empty() {
+			if leak_policy == LeakPolicy::Panic && !std::thread::panicking() {
+				panic!("{}", report);
+			} else {
+				log::warn!("{}", report);
+			}
+		}
+	}
+}
+
 impl<P: BindlessPlatform> Bindless<P> {
 	#[inline(never)]
 	fn unreachable_bindless_dropped() -> ! {
@@ -134,6 +243,31 @@ impl<P: BindlessPlatform> Bindless<P> {
 		&self.table_sync
 	}
 
+	/// Flushes and reports all resources that are still referenced. When called during shutdown, these are the
+	/// resources that have been leaked, see [`BindlessInstance::set_leak_policy`].
+	pub fn leak_report(&self) -> LeakReport {
+		// the flush queue holds references to newly allocated slots
+		self.flush();
+		LeakReport {
+			buffers: self.buffer.leaked(),
+			images: self.image.leaked(),
+			samplers: self.sampler.leaked(),
+			custom: self.custom.iter().flat_map(|custom| custom.leaked()).collect(),
+		}
+	}
+
+	/// Collect statistics about all tables and the memory allocated by their resources. As other threads may
+	/// concurrently allocate or drop resources, the individual values may not be consistent with each other.
+	pub fn stats(&self) -> BindlessStats {
+		BindlessStats {
+			buffer: self.buffer().stats(),
+			image: self.image().stats(),
+			sampler: self.sampler().stats(),
+			buffer_bytes: self.buffer().allocated_bytes(),
+			image_bytes: self.image().allocated_bytes(),
+		}
+	}
+
 	#[inline]
 	pub fn buffer(&self) -> BufferTableAccess<'_, P> {
 		BufferTableAccess(self)
@@ -149,6 +283,15 @@ impl<P: BindlessPlatform> Bindless<P> {
 		SamplerTableAccess(self)
 	}
 
+	/// Access the custom table with content `C`, or `None` if no such table has been registered with
+	/// [`BindlessInstance::new_with_custom_tables`].
+	pub fn custom<C: CustomDescContent>(&self) -> Option<CustomTableAccess<'_, P, C>> {
+		self.custom
+			.iter()
+			.find_map(|custom| custom.as_any().downcast_ref::<CustomTable<P, C>>())
+			.map(|table| CustomTableAccess::new(self, table))
+	}
+
 	/// Flush the bindless descriptor set. All newly allocated resources before this call will be written. Failing to
 	/// flush before enqueueing work is undefined behaviour.
 	pub fn flush(&self) {
@@ -161,6 +304,9 @@ impl<P: BindlessPlatform> Bindless<P> {
 				self.image().flush_queue(),
 				self.sampler().flush_queue(),
 			);
+			for custom in &self.custom {
+				custom.flush(self);
+			}
 		}
 	}
 
@@ -178,6 +324,19 @@ impl<P: BindlessPlatform> Bindless<P> {
 	}
 }
 
+/// Statistics about all tables of a [`Bindless`] instance, see [`Bindless::stats`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BindlessStats {
+	pub buffer: TableStats,
+	pub image: TableStats,
+	pub sampler: TableStats,
+	/// The total size in bytes of all buffers currently alive
+	pub buffer_bytes: u64,
+	/// The estimated total size in bytes of all images currently alive, see
+	/// [`ImageSlot::estimated_size`](crate::descriptor::ImageSlot::estimated_size)
+	pub image_bytes: u64,
+}
+
 impl<P: BindlessPlatform> Drop for BindlessInner<P> {
 	fn drop(&mut self) {
 		unsafe {
@@ -210,3 +369,44 @@ pub struct BindlessFrameInner<P: BindlessPlatform> {
 }
 
 unsafe impl<'a, P: BindlessPlatform> TransientAccess<'a> for &'a BindlessFrame<P> {}
+
+#[cfg(test)]
+mod tests {
+	use crate::descriptor::{BindlessImageCreateInfo, BindlessImageUsage, Format, Image2d};
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	#[test]
+	fn test_stats() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let buffer = bindless.buffer().alloc_slice::<u32>(&buffer_ci("buffer"), 64)?;
+		let image = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels: 5,
+			usage: BindlessImageUsage::SAMPLED,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+
+		let stats = bindless.stats();
+		assert_eq!(stats.buffer.live_slots, 1);
+		assert_eq!(stats.buffer.flush_queue_len, 1);
+		assert_eq!(stats.image.live_slots, 1);
+		assert_eq!(stats.sampler.live_slots, 0);
+		assert_eq!(stats.buffer_bytes, 64 * 4);
+		assert_eq!(stats.image_bytes, (16 * 16 + 8 * 8 + 4 * 4 + 2 * 2 + 1) * 4);
+
+		drop((buffer, image));
+		bindless.flush();
+		for _ in 0..3 {
+			drop(bindless.frame());
+		}
+		let stats = bindless.stats();
+		assert_eq!(stats.buffer.live_slots, 0);
+		assert_eq!(stats.buffer.dead_queue_len, 1);
+		assert_eq!(stats.image.live_slots, 0);
+		assert_eq!(stats.buffer_bytes, 0);
+		assert_eq!(stats.image_bytes, 0);
+		Ok(())
+	}
+}
@@ -0,0 +1,330 @@
+use crate::descriptor::buffer_table::{
+	BindlessBufferCreateInfo, BufferAllocationError, BufferSlot, BufferTableAccess, MutDescBufferExt,
+};
+use crate::descriptor::mutdesc::{MutBoxDescExt, MutDesc};
+use crate::descriptor::{RCDesc, RCDescExt};
+use crate::pipeline::AccessLock;
+use crate::platform::BindlessPlatform;
+use parking_lot::Mutex;
+use rangemap::RangeSet;
+use rust_gpu_bindless_shaders::buffer_content::{BufferContent, BufferStruct};
+use rust_gpu_bindless_shaders::descriptor::{Buffer, MutBuffer};
+use std::fmt::{Debug, Formatter};
+use std::mem::{align_of, size_of};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A single large buffer from which many small buffers can be sub-allocated, see [`BufferTableAccess::alloc_pool`].
+///
+/// Every sub-allocation gets its own descriptor pointing to its range of the backing buffer, and can be used just like
+/// any other buffer. The range is returned to the pool once the sub-allocation's slot is dropped, and the backing
+/// buffer is freed once the pool and all of its sub-allocations are dropped.
+pub struct BufferPool<P: BindlessPlatform>(Arc<BufferPoolInner<P>>);
+
+impl<P: BindlessPlatform> Clone for BufferPool<P> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<P: BindlessPlatform> Debug for BufferPool<P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("BufferPool")
+			.field("name", &self.debug_name())
+			.field("size", &self.size())
+			.field("free_bytes", &self.free_bytes())
+			.finish()
+	}
+}
+
+struct BufferPoolInner<P: BindlessPlatform> {
+	backing: RCDesc<P, Buffer<[u8]>>,
+	/// the minimum alignment of all sub-allocations
+	alignment: u64,
+	free: Mutex<RangeSet<u64>>,
+}
+
+impl<P: BindlessPlatform> BufferPoolInner<P> {
+	/// Find the first free range fitting `size` bytes aligned to `alignment`, and remove it from the free ranges.
+	/// `size` must not be 0.
+	fn alloc_range(&self, size: u64, alignment: u64) -> Option<Range<u64>> {
+		let mut free = self.free.lock();
+		let range = free.iter().find_map(|free| {
+			let start = free.start.next_multiple_of(alignment);
+			let end = start.checked_add(size)?;
+			(end <= free.end).then_some(start..end)
+		})?;
+		free.remove(range.clone());
+		Some(range)
+	}
+}
+
+impl<P: BindlessPlatform> BufferPool<P> {
+	/// The total size of the backing buffer in bytes
+	pub fn size(&self) -> u64 {
+		self.0.backing.inner_slot().size
+	}
+
+	/// The amount of bytes not used by any sub-allocation. Due to fragmentation and alignment, the largest possible
+	/// sub-allocation may be smaller.
+	pub fn free_bytes(&self) -> u64 {
+		self.0.free.lock().iter().map(|range| range.end - range.start).sum()
+	}
+
+	pub fn debug_name(&self) -> &str {
+		self.0.backing.inner_slot().debug_name()
+	}
+
+	/// The backing buffer all sub-allocations are allocated from
+	///
+	/// # Safety
+	/// Accessing the backing buffer bypasses the access tracking of all sub-allocations. It must not be accessed in any
+	/// way that conflicts with concurrent accesses to any sub-allocation, neither on the host nor on the device.
+	pub unsafe fn backing(&self) -> &RCDesc<P, Buffer<[u8]>> {
+		&self.0.backing
+	}
+}
+
+/// A range of a [`BufferPool`], stored in the [`BufferSlot`] of a sub-allocated buffer. Returns its range to the pool
+/// when dropped.
+pub struct BufferSubAllocation<P: BindlessPlatform> {
+	pool: Arc<BufferPoolInner<P>>,
+	range: Range<u64>,
+}
+
+impl<P: BindlessPlatform> Drop for BufferSubAllocation<P> {
+	fn drop(&mut self) {
+		self.pool.free.lock().insert(self.range.clone());
+	}
+}
+
+impl<P: BindlessPlatform> BufferSubAllocation<P> {
+	pub fn pool(&self) -> BufferPool<P> {
+		BufferPool(self.pool.clone())
+	}
+
+	/// The slot of the backing buffer this was sub-allocated from
+	pub fn backing(&self) -> &BufferSlot<P> {
+		self.pool.backing.inner_slot()
+	}
+}
+
+impl<P: BindlessPlatform> BufferTableAccess<'_, P> {
+	/// Allocates a [`BufferPool`] of `size` bytes, from which buffers can be sub-allocated with the `sub_alloc_*`
+	/// functions. All sub-allocations share the `usage` of the pool.
+	pub fn alloc_pool(
+		&self,
+		create_info: &BindlessBufferCreateInfo,
+		size: u64,
+	) -> Result<BufferPool<P>, BufferAllocationError<P>> {
+		unsafe {
+			let backing = self
+				.alloc_slice::<u8>(create_info, size as usize)?
+				.into_shared_unchecked();
+			let mut free = RangeSet::new();
+			if size > 0 {
+				free.insert(0..size);
+			}
+			Ok(BufferPool(Arc::new(BufferPoolInner {
+				backing,
+				alignment: self.0.platform.min_buffer_offset_alignment(),
+				free: Mutex::new(free),
+			})))
+		}
+	}
+
+	/// # Safety
+	/// `pool` must have been allocated from this [`Bindless`](crate::descriptor::Bindless) instance and `len` and
+	/// `size` must match `T`.
+	unsafe fn sub_alloc<T: BufferContent + ?Sized>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+		len: usize,
+		size: u64,
+		alignment: usize,
+	) -> Result<MutDesc<P, MutBuffer<T>>, BufferAllocationError<P>> {
+		unsafe {
+			// descriptors and barriers can't cover empty ranges
+			if size == 0 {
+				return Err(BufferAllocationError::EmptySubAllocation {
+					pool: pool.debug_name().to_string(),
+					name: name.to_string(),
+				});
+			}
+			let range = pool
+				.0
+				.alloc_range(size, pool.0.alignment.max(alignment as u64))
+				.ok_or_else(|| BufferAllocationError::PoolOutOfMemory {
+					pool: pool.debug_name().to_string(),
+					name: name.to_string(),
+					size,
+				})?;
+			let backing = pool.0.backing.inner_slot();
+			Ok(self.alloc_slot(BufferSlot {
+				platform: self.0.platform.alias_buffer(&backing.platform),
+				len,
+				size,
+				offset: range.start,
+				sub_allocation: Some(BufferSubAllocation {
+					pool: pool.0.clone(),
+					range,
+				}),
+				usage: backing.usage,
+				allocation_scheme: backing.allocation_scheme,
+				strong_refs: Default::default(),
+				access_lock: AccessLock::new(backing.usage.initial_buffer_access()),
+				debug_name: name.to_string(),
+			})?)
+		}
+	}
+
+	/// Sub-allocates a buffer from `pool`, which must have been allocated from this
+	/// [`Bindless`](crate::descriptor::Bindless) instance.
+	pub fn sub_alloc_sized<T: BufferStruct>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+	) -> Result<MutDesc<P, MutBuffer<T>>, BufferAllocationError<P>> {
+		self.assert_same_bindless(pool);
+		unsafe {
+			self.sub_alloc(
+				pool,
+				name,
+				1,
+				size_of::<T::Transfer>() as u64,
+				align_of::<T::Transfer>(),
+			)
+		}
+	}
+
+	/// Sub-allocates a slice from `pool`, which must have been allocated from this
+	/// [`Bindless`](crate::descriptor::Bindless) instance.
+	pub fn sub_alloc_slice<T: BufferStruct>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+		len: usize,
+	) -> Result<MutDesc<P, MutBuffer<[T]>>, BufferAllocationError<P>> {
+		self.assert_same_bindless(pool);
+		unsafe {
+			self.sub_alloc(
+				pool,
+				name,
+				len,
+				size_of::<T::Transfer>() as u64 * len as u64,
+				align_of::<T::Transfer>(),
+			)
+		}
+	}
+
+	pub fn sub_alloc_from_data<T: BufferStruct>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+		data: T,
+	) -> Result<MutDesc<P, MutBuffer<T>>, BufferAllocationError<P>> {
+		let buffer = self.sub_alloc_sized(pool, name)?;
+		buffer.mapped_immediate().unwrap().write_data(data);
+		Ok(buffer)
+	}
+
+	pub fn sub_alloc_shared_from_data<T: BufferStruct>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+		data: T,
+	) -> Result<RCDesc<P, Buffer<T>>, BufferAllocationError<P>> {
+		unsafe { Ok(self.sub_alloc_from_data(pool, name, data)?.into_shared_unchecked()) }
+	}
+
+	pub fn sub_alloc_from_iter<T: BufferStruct, I>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+		iter: I,
+	) -> Result<MutDesc<P, MutBuffer<[T]>>, BufferAllocationError<P>>
+	where
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let iter = iter.into_iter();
+		let buffer = self.sub_alloc_slice(pool, name, iter.len())?;
+		buffer.mapped_immediate().unwrap().overwrite_from_iter_exact(iter);
+		Ok(buffer)
+	}
+
+	pub fn sub_alloc_shared_from_iter<T: BufferStruct, I>(
+		&self,
+		pool: &BufferPool<P>,
+		name: &str,
+		iter: I,
+	) -> Result<RCDesc<P, Buffer<[T]>>, BufferAllocationError<P>>
+	where
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		unsafe { Ok(self.sub_alloc_from_iter(pool, name, iter)?.into_shared_unchecked()) }
+	}
+
+	fn assert_same_bindless(&self, pool: &BufferPool<P>) {
+		assert!(
+			Arc::ptr_eq(&pool.0.backing.rc_slot().table_sync_arc(), &self.0.table_sync),
+			"BufferPool {} was allocated from a different Bindless instance",
+			pool.debug_name()
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BufferAllocationError, MutDescBufferExt, MutDescExt};
+	use crate::pipeline::{HostAccess, MutBufferAccessExt, TransferRead, TransferWrite};
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	#[test]
+	fn test_buffer_pool() -> anyhow::Result<()> {
+		unsafe {
+			let bindless = mock_bindless();
+			let pool = bindless.buffer().alloc_pool(&buffer_ci("pool"), 256)?;
+			let data = (0..16).collect::<Vec<u32>>();
+			let first = bindless
+				.buffer()
+				.sub_alloc_from_iter(&pool, "first", data.iter().copied())?;
+			let second = bindless.buffer().sub_alloc_slice::<u32>(&pool, "second", 16)?;
+			assert_eq!(first.inner_slot().offset, 0);
+			assert_eq!(second.inner_slot().offset, 64);
+			assert_eq!(first.inner_slot().backing().handle, pool.backing().inner_slot().handle);
+			assert_eq!(bindless.allocated_bytes(), 256);
+			assert_eq!(pool.free_bytes(), 128);
+
+			let too_large = bindless.buffer().sub_alloc_slice::<u32>(&pool, "too large", 64);
+			assert!(matches!(
+				too_large,
+				Err(BufferAllocationError::PoolOutOfMemory { size: 256, .. })
+			));
+			let empty = bindless.buffer().sub_alloc_slice::<u32>(&pool, "empty", 0);
+			assert!(matches!(empty, Err(BufferAllocationError::EmptySubAllocation { .. })));
+			assert_eq!(pool.free_bytes(), 128);
+
+			let (first, second) = bindless.execute(|cmd| {
+				let src = first.access::<TransferRead>(cmd)?;
+				let dst = second.access_as_undefined::<TransferWrite>(cmd)?;
+				cmd.copy_buffer_to_buffer_slice(&src, &dst)?;
+				Ok((src.into_desc(), dst.transition::<HostAccess>()?.into_desc()))
+			})?;
+			assert_eq!(second.mapped_immediate()?.read_iter().collect::<Vec<_>>(), data);
+			let backing = pool.backing().inner_slot().backing().memory();
+			assert_eq!(backing[64..128], backing[0..64]);
+
+			drop(first);
+			bindless.table_sync().collect_now();
+			assert_eq!(pool.free_bytes(), 192);
+			let third = bindless.buffer().sub_alloc_sized::<u32>(&pool, "third")?;
+			assert_eq!(third.inner_slot().offset, 0);
+			assert_eq!(bindless.stats().buffer.live_slots, 3);
+			Ok(())
+		}
+	}
+}
@@ -1,10 +1,14 @@
-use crate::backing::range_set::DescriptorIndexIterator;
-use crate::backing::table::{DrainFlushQueue, RcTableSlot, SlotAllocationError, Table, TableInterface, TableSync};
+use crate::backing::range_set::{DescriptorIndexIterator, DescriptorIndexRangeSet};
+use crate::backing::table::{
+	DrainFlushQueue, RcTableSlot, SlotAllocationError, Table, TableInterface, TableStats, TableSync,
+};
 use crate::descriptor::buffer_metadata_cpu::StrongMetadataCpu;
+use crate::descriptor::buffer_pool::BufferSubAllocation;
 use crate::descriptor::descriptor_content::{DescContentCpu, DescTable};
 use crate::descriptor::mutdesc::{MutBoxDescExt, MutDesc, MutDescExt};
 use crate::descriptor::{
-	AnyRCDesc, Bindless, BindlessAllocationScheme, DescContentMutCpu, DescriptorCounts, RCDesc, RCDescExt, WeakBindless,
+	AnyRCDesc, Bindless, BindlessAllocationScheme, DescContentMutCpu, DescriptorCounts, LeakedBuffer, RCDesc,
+	RCDescExt, WeakBindless,
 };
 use crate::pipeline::{AccessLock, AccessLockError, BufferAccess};
 use crate::platform::{BindlessPlatform, PendingExecution};
@@ -21,6 +25,8 @@ use std::marker::PhantomData;
 use std::mem::size_of;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
 use thiserror::Error;
 
 impl<T: BufferContent + ?Sized> DescContentCpu for Buffer<T> {
@@ -39,8 +45,8 @@ impl<T: BufferContent + ?Sized> DescContentMutCpu for MutBuffer<T> {
 impl<P: BindlessPlatform> DescTable<P> for BufferTable<P> {
 	type Slot = BufferSlot<P>;
 
-	fn get_slot(slot: &RcTableSlot) -> &Self::Slot {
-		slot.try_deref::<BufferInterface<P>>().unwrap()
+	fn try_get_slot(slot: &RcTableSlot) -> Option<&Self::Slot> {
+		slot.try_deref::<BufferInterface<P>>()
 	}
 }
 
@@ -50,7 +56,13 @@ pub struct BufferSlot<P: BindlessPlatform> {
 	pub len: usize,
 	/// the total size of this buffer in bytes
 	pub size: u64,
+	/// the offset in bytes into [`Self::platform`] where this buffer starts, non-zero for sub-allocations
+	pub offset: u64,
+	/// the [`BufferPool`] this buffer was sub-allocated from, returns its range to the pool when dropped
+	pub sub_allocation: Option<BufferSubAllocation<P>>,
 	pub usage: BindlessBufferUsage,
+	/// the allocation scheme this buffer was allocated with, sub-allocations inherit the one of their pool
+	pub allocation_scheme: BindlessAllocationScheme,
 	pub access_lock: AccessLock<BufferAccess>,
 	pub strong_refs: Mutex<StrongBackingRefs<P>>,
 	/// This may be replaced with a platform-specific getter, once you can query the name from gpu-allocator to not
@@ -70,6 +82,15 @@ impl<P: BindlessPlatform> BufferSlot<P> {
 	pub fn debug_name(&self) -> &str {
 		&self.debug_name
 	}
+
+	/// The slot owning the memory of this buffer: The backing buffer of the [`BufferPool`] for sub-allocations,
+	/// otherwise `self`.
+	pub fn backing(&self) -> &BufferSlot<P> {
+		match &self.sub_allocation {
+			None => self,
+			Some(sub) => sub.backing(),
+		}
+	}
 }
 
 pub struct BufferTable<P: BindlessPlatform> {
@@ -77,28 +98,82 @@ pub struct BufferTable<P: BindlessPlatform> {
 }
 
 impl<P: BindlessPlatform> BufferTable<P> {
-	pub fn new(table_sync: &Arc<TableSync>, counts: DescriptorCounts, bindless: WeakBindless<P>) -> Self {
+	pub fn new(
+		table_sync: &Arc<TableSync>,
+		counts: DescriptorCounts,
+		max_counts: DescriptorCounts,
+		bindless: WeakBindless<P>,
+	) -> Self {
 		Self {
 			table: table_sync
-				.register(counts.buffers, BufferInterface { bindless })
+				.register_growable(
+					counts.buffers,
+					max_counts.buffers,
+					BufferInterface {
+						bindless,
+						allocated_bytes: AtomicU64::new(0),
+					},
+				)
 				.unwrap(),
 		}
 	}
+
+	/// Collect statistics about this table, see [`Table::stats`].
+	pub fn stats(&self) -> TableStats {
+		self.table.stats()
+	}
+
+	/// The total size in bytes of all buffers currently alive in this table, not counting sub-allocations of a
+	/// [`BufferPool`] separately from its backing buffer.
+	pub fn allocated_bytes(&self) -> u64 {
+		self.table.allocated_bytes.load(Relaxed)
+	}
+
+	pub(crate) fn leaked(&self) -> Vec<LeakedBuffer> {
+		self.table
+			.referenced_slots()
+			.iter()
+			.map(|slot| {
+				let buffer = Self::get_slot(slot);
+				LeakedBuffer {
+					id: slot.id(),
+					debug_name: buffer.debug_name.clone(),
+					usage: buffer.usage,
+					size: buffer.size,
+				}
+			})
+			.collect()
+	}
 }
 
 pub struct BufferInterface<P: BindlessPlatform> {
 	bindless: WeakBindless<P>,
+	allocated_bytes: AtomicU64,
 }
 
 impl<P: BindlessPlatform> TableInterface for BufferInterface<P> {
 	type Slot = BufferSlot<P>;
 
 	fn drop_slots<'a>(&self, indices: impl DescriptorIndexIterator<'a, Self>) {
+		// sub-allocations don't own their platform buffer, their range is returned to the pool when the slot drops
+		let indices = indices.into_range_set();
+		// Safety: a subset of indices that are alive
+		let indices = unsafe {
+			DescriptorIndexRangeSet::from(
+				indices.table(),
+				indices
+					.iter()
+					.filter(|(_, buffer)| buffer.sub_allocation.is_none())
+					.map(|(index, _)| index),
+			)
+		};
+		let bytes = indices.iter().map(|(_, buffer)| buffer.size).sum::<u64>();
+		self.allocated_bytes.fetch_sub(bytes, Relaxed);
 		unsafe {
 			if let Some(bindless) = self.bindless.upgrade() {
 				bindless
 					.platform
-					.destroy_buffers(bindless.global_descriptor_set(), indices);
+					.destroy_buffers(bindless.global_descriptor_set(), &indices);
 			}
 		}
 	}
@@ -192,6 +267,10 @@ pub enum BufferAllocationError<P: BindlessPlatform> {
 	Slot(#[from] SlotAllocationError),
 	#[error("Buffer {name} must have at least one usage must be declared")]
 	NoUsageDeclared { name: String },
+	#[error("BufferPool {pool} has no free range of {size} bytes left for buffer {name}")]
+	PoolOutOfMemory { pool: String, name: String, size: u64 },
+	#[error("BufferPool {pool} can't sub-allocate buffer {name} of 0 bytes")]
+	EmptySubAllocation { pool: String, name: String },
 }
 
 impl<P: BindlessPlatform> Debug for BufferAllocationError<P> {
@@ -213,10 +292,13 @@ impl<P: BindlessPlatform> BufferTableAccess<'_, P> {
 		buffer: BufferSlot<P>,
 	) -> Result<MutDesc<P, MutBuffer<T>>, SlotAllocationError> {
 		unsafe {
-			Ok(MutDesc::new(
-				self.table.alloc_slot(buffer)?,
-				PendingExecution::<P>::new_completed(),
-			))
+			let bytes = match buffer.sub_allocation {
+				None => buffer.size,
+				Some(_) => 0,
+			};
+			let desc = MutDesc::new(self.table.alloc_slot(buffer)?, PendingExecution::<P>::new_completed());
+			self.table.allocated_bytes.fetch_add(bytes, Relaxed);
+			Ok(desc)
 		}
 	}
 
@@ -240,7 +322,10 @@ impl<P: BindlessPlatform> BufferTableAccess<'_, P> {
 				platform: buffer,
 				len: 1,
 				size,
+				offset: 0,
+				sub_allocation: None,
 				usage: create_info.usage,
+				allocation_scheme: create_info.allocation_scheme,
 				strong_refs: Default::default(),
 				access_lock: AccessLock::new(create_info.usage.initial_buffer_access()),
 				debug_name: create_info.name.to_string(),
@@ -265,7 +350,10 @@ impl<P: BindlessPlatform> BufferTableAccess<'_, P> {
 				platform: buffer,
 				len,
 				size,
+				offset: 0,
+				sub_allocation: None,
 				usage: create_info.usage,
+				allocation_scheme: create_info.allocation_scheme,
 				strong_refs: Default::default(),
 				access_lock: AccessLock::new(create_info.usage.initial_buffer_access()),
 				debug_name: create_info.name.to_string(),
@@ -444,10 +532,23 @@ impl<P: BindlessPlatform, T: BufferContent + ?Sized> MappedBuffer<'_, P, T> {
 		*self.slot.strong_refs.lock() = StrongBackingRefs::default();
 	}
 
+	/// The mapped memory of this buffer, which for sub-allocations is only a range of the backing buffer's memory
+	unsafe fn slab(&self) -> SubSlab<'_> {
+		unsafe {
+			let slab = P::mapped_buffer_to_slab(self.slot.backing());
+			assert!(self.slot.offset + self.slot.size <= slab.size() as u64);
+			SubSlab {
+				ptr: slab.base_ptr_mut().add(self.slot.offset as usize),
+				size: self.slot.size as usize,
+				_phantom: PhantomData,
+			}
+		}
+	}
+
 	unsafe fn slab_slice(&mut self) -> &mut [u8] {
 		unsafe {
-			let slab = P::mapped_buffer_to_slab(self.slot);
-			&mut slab.assume_initialized_as_bytes_mut()[0..self.slot.size as usize]
+			let slab = self.slab();
+			std::slice::from_raw_parts_mut(slab.ptr, slab.size)
 		}
 	}
 }
@@ -457,8 +558,8 @@ impl<P: BindlessPlatform, T: BufferStruct> MappedBuffer<'_, P, T> {
 	pub fn write_data(&mut self, t: T) {
 		unsafe {
 			let mut meta = StrongMetadataCpu::new(&self.table_sync, Metadata {});
-			let slab = P::mapped_buffer_to_slab(self.slot);
-			let record = presser::copy_from_slice_to_offset(&[T::write_cpu(t, &mut meta)], slab, 0).unwrap();
+			let mut slab = self.slab();
+			let record = presser::copy_from_slice_to_offset(&[T::write_cpu(t, &mut meta)], &mut slab, 0).unwrap();
 			assert_eq!(record.copy_start_offset, 0, "presser must not add padding");
 			*self.slot.strong_refs.lock() = meta.into_backing_refs();
 		}
@@ -489,14 +590,14 @@ impl<P: BindlessPlatform, T: BufferStruct> MappedBuffer<'_, P, [T]> {
 	pub fn overwrite_from_iter_exact(&mut self, iter: impl Iterator<Item = T>) {
 		unsafe {
 			let mut meta = StrongMetadataCpu::new(&self.table_sync, Metadata {});
-			let slab = P::mapped_buffer_to_slab(self.slot);
+			let mut slab = self.slab();
 			let mut written = 0;
 			let record = presser::copy_from_iter_to_offset_with_align_packed(
 				iter.map(|i| {
 					written += 1;
 					T::write_cpu(i, &mut meta)
 				}),
-				slab,
+				&mut slab,
 				0,
 				1,
 			);
@@ -527,9 +628,10 @@ impl<P: BindlessPlatform, T: BufferStruct> MappedBuffer<'_, P, [T]> {
 	pub fn write_offset(&mut self, index: usize, t: T) {
 		unsafe {
 			let mut meta = StrongMetadataCpu::new(&self.table_sync, Metadata {});
-			let slab = P::mapped_buffer_to_slab(self.slot);
+			let mut slab = self.slab();
 			let start_offset = index * size_of::<T::Transfer>();
-			let record = presser::copy_from_slice_to_offset(&[T::write_cpu(t, &mut meta)], slab, start_offset).unwrap();
+			let record =
+				presser::copy_from_slice_to_offset(&[T::write_cpu(t, &mut meta)], &mut slab, start_offset).unwrap();
 			assert_eq!(record.copy_start_offset, start_offset, "presser must not add padding");
 			self.slot.strong_refs.lock().merge(meta.into_backing_refs());
 		}
@@ -589,6 +691,27 @@ impl<P: BindlessPlatform, T: BufferStructIdentity> MappedBuffer<'_, P, [T]> {
 	}
 }
 
+/// A range of the mapped memory of a buffer
+struct SubSlab<'a> {
+	ptr: *mut u8,
+	size: usize,
+	_phantom: PhantomData<&'a mut [u8]>,
+}
+
+unsafe impl Slab for SubSlab<'_> {
+	fn base_ptr(&self) -> *const u8 {
+		self.ptr
+	}
+
+	fn base_ptr_mut(&mut self) -> *mut u8 {
+		self.ptr
+	}
+
+	fn size(&self) -> usize {
+		self.size
+	}
+}
+
 /// Stores [`RC`] to various resources, to which [`StrongDesc`] contained in some resource may refer to.
 pub struct StrongBackingRefs<P: BindlessPlatform>(pub SmallVec<[AnyRCDesc<P>; 5]>);
 
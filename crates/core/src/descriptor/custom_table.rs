@@ -0,0 +1,354 @@
+use crate::backing::range_set::{DescriptorIndexIterator, DescriptorIndexRangeSet};
+use crate::backing::table::{RcTableSlot, SlotAllocationError, Table, TableInterface, TableStats, TableSync};
+use crate::descriptor::descriptor_content::{DescContentCpu, DescTable};
+use crate::descriptor::rc::RCDesc;
+use crate::descriptor::{Bindless, RCDescExt, WeakBindless};
+use crate::platform::BindlessPlatform;
+use rust_gpu_bindless_shaders::descriptor::{CustomTableContent, DescriptorId};
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// The CPU side of a [`CustomTableContent`], see [`CustomTableCreateInfo`].
+///
+/// To use it like any other descriptor, also implement [`DescContentCpu`] with [`CustomTable`] as its
+/// [`DescContentCpu::DescTable`]:
+/// ```ignore
+/// impl DescContentCpu for MyContent {
+///     type DescTable<P: BindlessPlatform> = CustomTable<P, Self>;
+/// }
+/// ```
+pub trait CustomDescContent: CustomTableContent + 'static {
+	/// The kind of slot stored in the table
+	type Slot: Send + Sync + 'static;
+}
+
+impl<P: BindlessPlatform, C: CustomDescContent> DescTable<P> for CustomTable<P, C> {
+	type Slot = C::Slot;
+
+	fn try_get_slot(slot: &RcTableSlot) -> Option<&Self::Slot> {
+		slot.try_deref::<CustomInterface<P, C>>()
+	}
+}
+
+/// Slots of a custom table passed to its [`CustomTableInterface`]
+pub type CustomSlots<'a, P, C> = DescriptorIndexRangeSet<'a, Table<CustomInterface<P, C>>>;
+
+/// Platform-specific implementation of a custom table, writing and destroying its descriptors.
+pub trait CustomTableInterface<P: BindlessPlatform>: Send + Sync + 'static {
+	type Content: CustomDescContent;
+
+	/// Write the descriptors of newly allocated slots into `binding` of the
+	/// [global descriptor set](Bindless::global_descriptor_set).
+	///
+	/// # Safety
+	/// Called while holding the [`FlushGuard`](crate::backing::table::FlushGuard)
+	unsafe fn write_descriptors(&self, bindless: &Bindless<P>, binding: u32, slots: CustomSlots<'_, P, Self::Content>);
+
+	/// Destroy the specified slots. You have exclusive access to them, even if they are just passed by standard
+	/// reference. After this method call returns, the slots will be dropped and otherwise not accessed anymore.
+	///
+	/// # Safety
+	/// Called by gc once the slots are no longer referenced
+	unsafe fn destroy_slots(&self, bindless: &Bindless<P>, binding: u32, slots: CustomSlots<'_, P, Self::Content>);
+}
+
+/// The descriptor binding of a custom table, see [`BindlessPlatform::create_descriptor_set`].
+pub struct CustomTableBinding<P: BindlessPlatform> {
+	/// The binding within the global descriptor set
+	pub binding: u32,
+	/// The maximum amount of descriptors in this binding
+	pub count: u32,
+	/// Platform-specific information about the binding, like its descriptor type
+	pub info: P::CustomBindingInfo,
+}
+
+impl<P: BindlessPlatform> Copy for CustomTableBinding<P> {}
+
+impl<P: BindlessPlatform> Clone for CustomTableBinding<P> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<P: BindlessPlatform> Debug for CustomTableBinding<P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("CustomTableBinding")
+			.field("binding", &self.binding)
+			.field("count", &self.count)
+			.field("info", &self.info)
+			.finish()
+	}
+}
+
+type RegisterCustomTable<P> =
+	Box<dyn FnOnce(&Arc<TableSync>, CustomTableBinding<P>, u32, WeakBindless<P>) -> Box<dyn AnyCustomTable<P>>>;
+
+/// A custom table to register with [`BindlessInstance::new_with_custom_tables`].
+///
+/// [`BindlessInstance::new_with_custom_tables`]: crate::descriptor::BindlessInstance::new_with_custom_tables
+pub struct CustomTableCreateInfo<P: BindlessPlatform> {
+	binding: CustomTableBinding<P>,
+	slots_capacity: u32,
+	register: RegisterCustomTable<P>,
+}
+
+impl<P: BindlessPlatform> CustomTableCreateInfo<P> {
+	/// Create a custom table with a fixed capacity of `count` slots, with its descriptors in the
+	/// [`CustomTableContent::BINDING`] of its content within the global descriptor set.
+	pub fn new<I: CustomTableInterface<P>>(interface: I, count: u32, info: P::CustomBindingInfo) -> Self {
+		Self {
+			binding: CustomTableBinding {
+				binding: I::Content::BINDING,
+				count,
+				info,
+			},
+			slots_capacity: count,
+			register: Box::new(|table_sync, binding, slots_capacity, bindless| {
+				Box::new(CustomTable::<P, I::Content>::new(
+					table_sync,
+					binding,
+					slots_capacity,
+					CustomInterface {
+						bindless,
+						binding: binding.binding,
+						interface: Box::new(interface),
+					},
+				))
+			}),
+		}
+	}
+
+	/// Start out with a capacity of `slots_capacity` and grow on demand, up to the `count` passed to [`Self::new`].
+	pub fn initial_capacity(mut self, slots_capacity: u32) -> Self {
+		self.slots_capacity = slots_capacity;
+		self
+	}
+
+	pub fn binding(&self) -> &CustomTableBinding<P> {
+		&self.binding
+	}
+
+	pub(crate) fn register(
+		self,
+		table_sync: &Arc<TableSync>,
+		max_count: u32,
+		bindless: WeakBindless<P>,
+	) -> Box<dyn AnyCustomTable<P>> {
+		let binding = CustomTableBinding {
+			count: max_count,
+			..self.binding
+		};
+		(self.register)(table_sync, binding, self.slots_capacity.min(max_count), bindless)
+	}
+}
+
+pub struct CustomTable<P: BindlessPlatform, C: CustomDescContent> {
+	table: Arc<Table<CustomInterface<P, C>>>,
+	binding: CustomTableBinding<P>,
+}
+
+impl<P: BindlessPlatform, C: CustomDescContent> CustomTable<P, C> {
+	fn new(
+		table_sync: &Arc<TableSync>,
+		binding: CustomTableBinding<P>,
+		slots_capacity: u32,
+		interface: CustomInterface<P, C>,
+	) -> Self {
+		Self {
+			table: table_sync
+				.register_growable(slots_capacity, binding.count, interface)
+				.expect("no table left for custom table, consider an `id-layout-*` feature with more type bits"),
+			binding,
+		}
+	}
+
+	pub fn binding(&self) -> &CustomTableBinding<P> {
+		&self.binding
+	}
+
+	/// Collect statistics about this table, see [`Table::stats`].
+	pub fn stats(&self) -> TableStats {
+		self.table.stats()
+	}
+}
+
+/// Type-erased [`CustomTable`], to be stored within [`Bindless`]
+pub(crate) trait AnyCustomTable<P: BindlessPlatform>: Send + Sync + 'static {
+	fn as_any(&self) -> &dyn Any;
+
+	fn binding(&self) -> CustomTableBinding<P>;
+
+	/// # Safety
+	/// Must be called while holding the [`FlushGuard`](crate::backing::table::FlushGuard)
+	unsafe fn flush(&self, bindless: &Bindless<P>);
+
+	fn leaked(&self) -> Vec<DescriptorId>;
+}
+
+impl<P: BindlessPlatform, C: CustomDescContent> AnyCustomTable<P> for CustomTable<P, C> {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+
+	fn binding(&self) -> CustomTableBinding<P> {
+		self.binding
+	}
+
+	unsafe fn flush(&self, bindless: &Bindless<P>) {
+		let slots = self.table.drain_flush_queue().into_range_set();
+		if !slots.is_empty() {
+			let interface: &CustomInterface<P, C> = &self.table;
+			unsafe {
+				interface
+					.interface
+					.write_descriptors(bindless, self.binding.binding, slots)
+			}
+		}
+	}
+
+	fn leaked(&self) -> Vec<DescriptorId> {
+		self.table.referenced_slots().iter().map(RcTableSlot::id).collect()
+	}
+}
+
+pub struct CustomTableAccess<'a, P: BindlessPlatform, C: CustomDescContent>(pub &'a Bindless<P>, &'a CustomTable<P, C>);
+
+impl<P: BindlessPlatform, C: CustomDescContent> Deref for CustomTableAccess<'_, P, C> {
+	type Target = CustomTable<P, C>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.1
+	}
+}
+
+impl<'a, P: BindlessPlatform, C: CustomDescContent> CustomTableAccess<'a, P, C> {
+	pub(crate) fn new(bindless: &'a Bindless<P>, table: &'a CustomTable<P, C>) -> Self {
+		Self(bindless, table)
+	}
+
+	/// Allocates a new slot in this custom table. Its descriptor is written on the next [`Bindless::flush`].
+	#[inline]
+	pub fn alloc_slot(&self, slot: C::Slot) -> Result<RCDesc<P, C>, SlotAllocationError>
+	where
+		C: DescContentCpu,
+	{
+		unsafe { Ok(RCDesc::new(self.table.alloc_slot(slot)?)) }
+	}
+}
+
+pub struct CustomInterface<P: BindlessPlatform, C: CustomDescContent> {
+	bindless: WeakBindless<P>,
+	binding: u32,
+	interface: Box<dyn CustomTableInterface<P, Content = C>>,
+}
+
+impl<P: BindlessPlatform, C: CustomDescContent> TableInterface for CustomInterface<P, C> {
+	type Slot = C::Slot;
+
+	fn drop_slots<'a>(&self, indices: impl DescriptorIndexIterator<'a, Self>) {
+		unsafe {
+			if let Some(bindless) = self.bindless.upgrade() {
+				self.interface
+					.destroy_slots(&bindless, self.binding, indices.into_range_set());
+			}
+		}
+	}
+
+	fn flush<'a>(&self, _flush_queue: impl DescriptorIndexIterator<'a, Self>) {
+		// do nothing, flushing of descriptors is handled differently
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessInstance, DescriptorCounts};
+	use crate::platform::mock::{Mock, MockCreateInfo};
+	use parking_lot::Mutex;
+	use rust_gpu_bindless_shaders::descriptor::{BINDING_CUSTOM_BASE, DescContent};
+	use std::sync::Arc;
+
+	struct MockCustomContent;
+
+	impl DescContent for MockCustomContent {}
+
+	impl CustomTableContent for MockCustomContent {
+		const BINDING: u32 = BINDING_CUSTOM_BASE;
+		type Descriptor = u32;
+	}
+
+	impl DescContentCpu for MockCustomContent {
+		type DescTable<P: BindlessPlatform> = CustomTable<P, Self>;
+	}
+
+	impl CustomDescContent for MockCustomContent {
+		type Slot = u32;
+	}
+
+	#[derive(Default)]
+	struct MockCustomInterface {
+		written: Arc<Mutex<Vec<u32>>>,
+		destroyed: Arc<Mutex<Vec<u32>>>,
+	}
+
+	impl CustomTableInterface<Mock> for MockCustomInterface {
+		type Content = MockCustomContent;
+
+		unsafe fn write_descriptors(
+			&self,
+			_bindless: &Bindless<Mock>,
+			binding: u32,
+			slots: CustomSlots<'_, Mock, Self::Content>,
+		) {
+			assert_eq!(binding, BINDING_CUSTOM_BASE);
+			self.written.lock().extend(slots.iter().map(|(_, slot)| *slot));
+		}
+
+		unsafe fn destroy_slots(
+			&self,
+			_bindless: &Bindless<Mock>,
+			binding: u32,
+			slots: CustomSlots<'_, Mock, Self::Content>,
+		) {
+			assert_eq!(binding, BINDING_CUSTOM_BASE);
+			self.destroyed.lock().extend(slots.iter().map(|(_, slot)| *slot));
+		}
+	}
+
+	#[test]
+	fn test_custom_table() -> anyhow::Result<()> {
+		unsafe {
+			let interface = MockCustomInterface::default();
+			let written = interface.written.clone();
+			let destroyed = interface.destroyed.clone();
+			let bindless = BindlessInstance::<Mock>::new_with_custom_tables(
+				MockCreateInfo::default(),
+				DescriptorCounts::REASONABLE_DEFAULTS,
+				DescriptorCounts::REASONABLE_DEFAULTS,
+				vec![CustomTableCreateInfo::new(interface, 16, ())],
+			);
+			let custom_tables = &bindless.global_descriptor_set().custom_tables;
+			assert_eq!(custom_tables.len(), 1);
+			assert_eq!(custom_tables[0].binding, BINDING_CUSTOM_BASE);
+			assert_eq!(custom_tables[0].count, 16);
+
+			let table = bindless.custom::<MockCustomContent>().unwrap();
+			let desc = table.alloc_slot(42)?;
+			assert_eq!(*desc.inner_slot(), 42);
+			assert_eq!(table.stats().live_slots, 1);
+			assert!(written.lock().is_empty());
+			bindless.flush();
+			assert_eq!(*written.lock(), [42]);
+
+			assert_eq!(bindless.leak_report().custom, [desc.id()]);
+			drop(desc);
+			bindless.table_sync().collect_now();
+			assert_eq!(*destroyed.lock(), [42]);
+			assert!(bindless.leak_report().is_empty());
+			Ok(())
+		}
+	}
+}
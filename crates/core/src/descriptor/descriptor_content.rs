@@ -6,6 +6,13 @@ use rust_gpu_bindless_shaders::descriptor::DescContent;
 pub trait DescContentCpu: DescContent {
 	/// Associated non-generic [`DescTable`]
 	type DescTable<P: BindlessPlatform>: DescTable<P>;
+
+	/// Returns true if `slot` is in the [`Self::DescTable`] and its resource can be accessed as `Self`. Tables storing
+	/// multiple kinds of content must also check the content of the slot.
+	#[inline]
+	fn is_slot_of<P: BindlessPlatform>(slot: &RcTableSlot) -> bool {
+		<Self::DescTable<P> as DescTable<P>>::try_get_slot(slot).is_some()
+	}
 }
 
 /// In a resource table descriptors of varying generic arguments can be stored and are sent to the GPU in a single descriptor binding.
@@ -13,8 +20,14 @@ pub trait DescTable<P: BindlessPlatform>: Sized {
 	/// The kind of slot
 	type Slot;
 
+	/// get the slot from an [`RcTableSlot`], or `None` if it points to a table of a different slot type
+	fn try_get_slot(slot: &RcTableSlot) -> Option<&Self::Slot>;
+
 	/// get the slot from an [`RcTableSlot`] that *should* point to a table of that slot type
-	fn get_slot(slot: &RcTableSlot) -> &Self::Slot;
+	#[inline]
+	fn get_slot(slot: &RcTableSlot) -> &Self::Slot {
+		Self::try_get_slot(slot).unwrap()
+	}
 }
 
 pub trait DescContentMutCpu: DescContentCpu {
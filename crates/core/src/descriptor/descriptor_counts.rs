@@ -1,3 +1,4 @@
+use crate::backing::table::MAX_SLOTS_CAPACITY;
 use crate::platform::BindlessPlatform;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -18,6 +19,17 @@ impl DescriptorCounts {
 		samplers: 400,
 	};
 
+	/// The most descriptors any table can hold, limited by the bits available to address them in a `DescriptorId`.
+	/// Use it as the max counts of [`BindlessInstance::new_growable`] to allow tables to grow up to the platform's
+	/// limits.
+	///
+	/// [`BindlessInstance::new_growable`]: crate::descriptor::BindlessInstance::new_growable
+	pub const MAX: Self = DescriptorCounts {
+		buffers: MAX_SLOTS_CAPACITY,
+		image: MAX_SLOTS_CAPACITY,
+		samplers: MAX_SLOTS_CAPACITY,
+	};
+
 	pub fn reasonable_defaults<P: BindlessPlatform>(platform: &P) -> Self {
 		Self::REASONABLE_DEFAULTS.min(Self::limits(platform))
 	}
@@ -9,6 +9,18 @@ pub struct Extent {
 	pub depth: u32,
 }
 
+impl Extent {
+	/// The extent of mip level `level` of an image with this extent
+	pub fn mip(self, level: u32) -> Self {
+		let mip = |size: u32| (size >> level).max(1);
+		Self {
+			width: mip(self.width),
+			height: mip(self.height),
+			depth: mip(self.depth),
+		}
+	}
+}
+
 impl From<[u32; 3]> for Extent {
 	fn from(value: [u32; 3]) -> Self {
 		Extent {
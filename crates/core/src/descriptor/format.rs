@@ -0,0 +1,1452 @@
+use crate::descriptor::Extent;
+
+/// Invokes the macro `$m` with a comma separated list of all [`Format`] variants.
+///
+/// Allows platforms to generate conversions to their native format representation without repeating the entire list.
+macro_rules! all_formats {
+	($m:ident) => {
+		$m! {
+			UNDEFINED,
+			R4G4_UNORM_PACK8,
+			R4G4B4A4_UNORM_PACK16,
+			B4G4R4A4_UNORM_PACK16,
+			R5G6B5_UNORM_PACK16,
+			B5G6R5_UNORM_PACK16,
+			R5G5B5A1_UNORM_PACK16,
+			B5G5R5A1_UNORM_PACK16,
+			A1R5G5B5_UNORM_PACK16,
+			A1B5G5R5_UNORM_PACK16_KHR,
+			A8_UNORM_KHR,
+			R8_UNORM,
+			R8_SNORM,
+			R8_USCALED,
+			R8_SSCALED,
+			R8_UINT,
+			R8_SINT,
+			R8_SRGB,
+			R8G8_UNORM,
+			R8G8_SNORM,
+			R8G8_USCALED,
+			R8G8_SSCALED,
+			R8G8_UINT,
+			R8G8_SINT,
+			R8G8_SRGB,
+			R8G8B8_UNORM,
+			R8G8B8_SNORM,
+			R8G8B8_USCALED,
+			R8G8B8_SSCALED,
+			R8G8B8_UINT,
+			R8G8B8_SINT,
+			R8G8B8_SRGB,
+			B8G8R8_UNORM,
+			B8G8R8_SNORM,
+			B8G8R8_USCALED,
+			B8G8R8_SSCALED,
+			B8G8R8_UINT,
+			B8G8R8_SINT,
+			B8G8R8_SRGB,
+			R8G8B8A8_UNORM,
+			R8G8B8A8_SNORM,
+			R8G8B8A8_USCALED,
+			R8G8B8A8_SSCALED,
+			R8G8B8A8_UINT,
+			R8G8B8A8_SINT,
+			R8G8B8A8_SRGB,
+			B8G8R8A8_UNORM,
+			B8G8R8A8_SNORM,
+			B8G8R8A8_USCALED,
+			B8G8R8A8_SSCALED,
+			B8G8R8A8_UINT,
+			B8G8R8A8_SINT,
+			B8G8R8A8_SRGB,
+			A8B8G8R8_UNORM_PACK32,
+			A8B8G8R8_SNORM_PACK32,
+			A8B8G8R8_USCALED_PACK32,
+			A8B8G8R8_SSCALED_PACK32,
+			A8B8G8R8_UINT_PACK32,
+			A8B8G8R8_SINT_PACK32,
+			A8B8G8R8_SRGB_PACK32,
+			A2R10G10B10_UNORM_PACK32,
+			A2R10G10B10_SNORM_PACK32,
+			A2R10G10B10_USCALED_PACK32,
+			A2R10G10B10_SSCALED_PACK32,
+			A2R10G10B10_UINT_PACK32,
+			A2R10G10B10_SINT_PACK32,
+			A2B10G10R10_UNORM_PACK32,
+			A2B10G10R10_SNORM_PACK32,
+			A2B10G10R10_USCALED_PACK32,
+			A2B10G10R10_SSCALED_PACK32,
+			A2B10G10R10_UINT_PACK32,
+			A2B10G10R10_SINT_PACK32,
+			R16_UNORM,
+			R16_SNORM,
+			R16_USCALED,
+			R16_SSCALED,
+			R16_UINT,
+			R16_SINT,
+			R16_SFLOAT,
+			R16G16_UNORM,
+			R16G16_SNORM,
+			R16G16_USCALED,
+			R16G16_SSCALED,
+			R16G16_UINT,
+			R16G16_SINT,
+			R16G16_SFLOAT,
+			R16G16B16_UNORM,
+			R16G16B16_SNORM,
+			R16G16B16_USCALED,
+			R16G16B16_SSCALED,
+			R16G16B16_UINT,
+			R16G16B16_SINT,
+			R16G16B16_SFLOAT,
+			R16G16B16A16_UNORM,
+			R16G16B16A16_SNORM,
+			R16G16B16A16_USCALED,
+			R16G16B16A16_SSCALED,
+			R16G16B16A16_UINT,
+			R16G16B16A16_SINT,
+			R16G16B16A16_SFLOAT,
+			R32_UINT,
+			R32_SINT,
+			R32_SFLOAT,
+			R32G32_UINT,
+			R32G32_SINT,
+			R32G32_SFLOAT,
+			R32G32B32_UINT,
+			R32G32B32_SINT,
+			R32G32B32_SFLOAT,
+			R32G32B32A32_UINT,
+			R32G32B32A32_SINT,
+			R32G32B32A32_SFLOAT,
+			R64_UINT,
+			R64_SINT,
+			R64_SFLOAT,
+			R64G64_UINT,
+			R64G64_SINT,
+			R64G64_SFLOAT,
+			R64G64B64_UINT,
+			R64G64B64_SINT,
+			R64G64B64_SFLOAT,
+			R64G64B64A64_UINT,
+			R64G64B64A64_SINT,
+			R64G64B64A64_SFLOAT,
+			B10G11R11_UFLOAT_PACK32,
+			E5B9G9R9_UFLOAT_PACK32,
+			D16_UNORM,
+			X8_D24_UNORM_PACK32,
+			D32_SFLOAT,
+			S8_UINT,
+			D16_UNORM_S8_UINT,
+			D24_UNORM_S8_UINT,
+			D32_SFLOAT_S8_UINT,
+			BC1_RGB_UNORM_BLOCK,
+			BC1_RGB_SRGB_BLOCK,
+			BC1_RGBA_UNORM_BLOCK,
+			BC1_RGBA_SRGB_BLOCK,
+			BC2_UNORM_BLOCK,
+			BC2_SRGB_BLOCK,
+			BC3_UNORM_BLOCK,
+			BC3_SRGB_BLOCK,
+			BC4_UNORM_BLOCK,
+			BC4_SNORM_BLOCK,
+			BC5_UNORM_BLOCK,
+			BC5_SNORM_BLOCK,
+			BC6H_UFLOAT_BLOCK,
+			BC6H_SFLOAT_BLOCK,
+			BC7_UNORM_BLOCK,
+			BC7_SRGB_BLOCK,
+			ETC2_R8G8B8_UNORM_BLOCK,
+			ETC2_R8G8B8_SRGB_BLOCK,
+			ETC2_R8G8B8A1_UNORM_BLOCK,
+			ETC2_R8G8B8A1_SRGB_BLOCK,
+			ETC2_R8G8B8A8_UNORM_BLOCK,
+			ETC2_R8G8B8A8_SRGB_BLOCK,
+			EAC_R11_UNORM_BLOCK,
+			EAC_R11_SNORM_BLOCK,
+			EAC_R11G11_UNORM_BLOCK,
+			EAC_R11G11_SNORM_BLOCK,
+			ASTC_4X4_UNORM_BLOCK,
+			ASTC_4X4_SRGB_BLOCK,
+			ASTC_5X4_UNORM_BLOCK,
+			ASTC_5X4_SRGB_BLOCK,
+			ASTC_5X5_UNORM_BLOCK,
+			ASTC_5X5_SRGB_BLOCK,
+			ASTC_6X5_UNORM_BLOCK,
+			ASTC_6X5_SRGB_BLOCK,
+			ASTC_6X6_UNORM_BLOCK,
+			ASTC_6X6_SRGB_BLOCK,
+			ASTC_8X5_UNORM_BLOCK,
+			ASTC_8X5_SRGB_BLOCK,
+			ASTC_8X6_UNORM_BLOCK,
+			ASTC_8X6_SRGB_BLOCK,
+			ASTC_8X8_UNORM_BLOCK,
+			ASTC_8X8_SRGB_BLOCK,
+			ASTC_10X5_UNORM_BLOCK,
+			ASTC_10X5_SRGB_BLOCK,
+			ASTC_10X6_UNORM_BLOCK,
+			ASTC_10X6_SRGB_BLOCK,
+			ASTC_10X8_UNORM_BLOCK,
+			ASTC_10X8_SRGB_BLOCK,
+			ASTC_10X10_UNORM_BLOCK,
+			ASTC_10X10_SRGB_BLOCK,
+			ASTC_12X10_UNORM_BLOCK,
+			ASTC_12X10_SRGB_BLOCK,
+			ASTC_12X12_UNORM_BLOCK,
+			ASTC_12X12_SRGB_BLOCK,
+			G8B8G8R8_422_UNORM,
+			B8G8R8G8_422_UNORM,
+			G8_B8_R8_3PLANE_420_UNORM,
+			G8_B8R8_2PLANE_420_UNORM,
+			G8_B8_R8_3PLANE_422_UNORM,
+			G8_B8R8_2PLANE_422_UNORM,
+			G8_B8_R8_3PLANE_444_UNORM,
+			R10X6_UNORM_PACK16,
+			R10X6G10X6_UNORM_2PACK16,
+			R10X6G10X6B10X6A10X6_UNORM_4PACK16,
+			G10X6B10X6G10X6R10X6_422_UNORM_4PACK16,
+			B10X6G10X6R10X6G10X6_422_UNORM_4PACK16,
+			G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16,
+			G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16,
+			G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16,
+			G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16,
+			G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16,
+			R12X4_UNORM_PACK16,
+			R12X4G12X4_UNORM_2PACK16,
+			R12X4G12X4B12X4A12X4_UNORM_4PACK16,
+			G12X4B12X4G12X4R12X4_422_UNORM_4PACK16,
+			B12X4G12X4R12X4G12X4_422_UNORM_4PACK16,
+			G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16,
+			G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16,
+			G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16,
+			G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16,
+			G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16,
+			G16B16G16R16_422_UNORM,
+			B16G16R16G16_422_UNORM,
+			G16_B16_R16_3PLANE_420_UNORM,
+			G16_B16R16_2PLANE_420_UNORM,
+			G16_B16_R16_3PLANE_422_UNORM,
+			G16_B16R16_2PLANE_422_UNORM,
+			G16_B16_R16_3PLANE_444_UNORM,
+			PVRTC1_2BPP_UNORM_BLOCK_IMG,
+			PVRTC1_4BPP_UNORM_BLOCK_IMG,
+			PVRTC2_2BPP_UNORM_BLOCK_IMG,
+			PVRTC2_4BPP_UNORM_BLOCK_IMG,
+			PVRTC1_2BPP_SRGB_BLOCK_IMG,
+			PVRTC1_4BPP_SRGB_BLOCK_IMG,
+			PVRTC2_2BPP_SRGB_BLOCK_IMG,
+			PVRTC2_4BPP_SRGB_BLOCK_IMG,
+			ASTC_4X4_SFLOAT_BLOCK_EXT,
+			ASTC_5X4_SFLOAT_BLOCK_EXT,
+			ASTC_5X5_SFLOAT_BLOCK_EXT,
+			ASTC_6X5_SFLOAT_BLOCK_EXT,
+			ASTC_6X6_SFLOAT_BLOCK_EXT,
+			ASTC_8X5_SFLOAT_BLOCK_EXT,
+			ASTC_8X6_SFLOAT_BLOCK_EXT,
+			ASTC_8X8_SFLOAT_BLOCK_EXT,
+			ASTC_10X5_SFLOAT_BLOCK_EXT,
+			ASTC_10X6_SFLOAT_BLOCK_EXT,
+			ASTC_10X8_SFLOAT_BLOCK_EXT,
+			ASTC_10X10_SFLOAT_BLOCK_EXT,
+			ASTC_12X10_SFLOAT_BLOCK_EXT,
+			ASTC_12X12_SFLOAT_BLOCK_EXT,
+			G8_B8R8_2PLANE_444_UNORM,
+			G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16,
+			G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16,
+			G16_B16R16_2PLANE_444_UNORM,
+			A4R4G4B4_UNORM_PACK16,
+			A4B4G4R4_UNORM_PACK16,
+			R16G16_S10_5_NV,
+		}
+	};
+}
+#[allow(unused_imports)]
+pub(crate) use all_formats;
+
+macro_rules! decl_format {
+	($default:ident, $($format:ident,)*) => {
+		/// The format of the texels of an image.
+		///
+		/// The variants are named after and behave identically to their Vulkan counterparts, see the Vulkan spec for
+		/// details on their memory layout. Platforms convert them to their native representation.
+		#[allow(non_camel_case_types)]
+		#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+		pub enum Format {
+			#[default]
+			$default,
+			$($format,)*
+		}
+	};
+}
+all_formats!(decl_format);
+impl Format {
+	/// The size of a texel block in bytes. A texel block of a block-compressed or subsampled format contains multiple
+	/// texels, see [`Self::block_extent`]. Multi-planar formats return the size of a texel in all planes combined.
+	pub const fn block_size(self) -> u32 {
+		match self {
+			Self::UNDEFINED => 0,
+			Self::R4G4_UNORM_PACK8
+			| Self::A8_UNORM_KHR
+			| Self::R8_UNORM
+			| Self::R8_SNORM
+			| Self::R8_USCALED
+			| Self::R8_SSCALED
+			| Self::R8_UINT
+			| Self::R8_SINT
+			| Self::R8_SRGB
+			| Self::S8_UINT => 1,
+			Self::R4G4B4A4_UNORM_PACK16
+			| Self::B4G4R4A4_UNORM_PACK16
+			| Self::R5G6B5_UNORM_PACK16
+			| Self::B5G6R5_UNORM_PACK16
+			| Self::R5G5B5A1_UNORM_PACK16
+			| Self::B5G5R5A1_UNORM_PACK16
+			| Self::A1R5G5B5_UNORM_PACK16
+			| Self::A1B5G5R5_UNORM_PACK16_KHR
+			| Self::R8G8_UNORM
+			| Self::R8G8_SNORM
+			| Self::R8G8_USCALED
+			| Self::R8G8_SSCALED
+			| Self::R8G8_UINT
+			| Self::R8G8_SINT
+			| Self::R8G8_SRGB
+			| Self::R16_UNORM
+			| Self::R16_SNORM
+			| Self::R16_USCALED
+			| Self::R16_SSCALED
+			| Self::R16_UINT
+			| Self::R16_SINT
+			| Self::R16_SFLOAT
+			| Self::D16_UNORM
+			| Self::R10X6_UNORM_PACK16
+			| Self::R12X4_UNORM_PACK16
+			| Self::A4R4G4B4_UNORM_PACK16
+			| Self::A4B4G4R4_UNORM_PACK16 => 2,
+			Self::R8G8B8_UNORM
+			| Self::R8G8B8_SNORM
+			| Self::R8G8B8_USCALED
+			| Self::R8G8B8_SSCALED
+			| Self::R8G8B8_UINT
+			| Self::R8G8B8_SINT
+			| Self::R8G8B8_SRGB
+			| Self::B8G8R8_UNORM
+			| Self::B8G8R8_SNORM
+			| Self::B8G8R8_USCALED
+			| Self::B8G8R8_SSCALED
+			| Self::B8G8R8_UINT
+			| Self::B8G8R8_SINT
+			| Self::B8G8R8_SRGB
+			| Self::D16_UNORM_S8_UINT
+			| Self::G8_B8_R8_3PLANE_420_UNORM
+			| Self::G8_B8R8_2PLANE_420_UNORM
+			| Self::G8_B8_R8_3PLANE_422_UNORM
+			| Self::G8_B8R8_2PLANE_422_UNORM
+			| Self::G8_B8_R8_3PLANE_444_UNORM
+			| Self::G8_B8R8_2PLANE_444_UNORM => 3,
+			Self::R8G8B8A8_UNORM
+			| Self::R8G8B8A8_SNORM
+			| Self::R8G8B8A8_USCALED
+			| Self::R8G8B8A8_SSCALED
+			| Self::R8G8B8A8_UINT
+			| Self::R8G8B8A8_SINT
+			| Self::R8G8B8A8_SRGB
+			| Self::B8G8R8A8_UNORM
+			| Self::B8G8R8A8_SNORM
+			| Self::B8G8R8A8_USCALED
+			| Self::B8G8R8A8_SSCALED
+			| Self::B8G8R8A8_UINT
+			| Self::B8G8R8A8_SINT
+			| Self::B8G8R8A8_SRGB
+			| Self::A8B8G8R8_UNORM_PACK32
+			| Self::A8B8G8R8_SNORM_PACK32
+			| Self::A8B8G8R8_USCALED_PACK32
+			| Self::A8B8G8R8_SSCALED_PACK32
+			| Self::A8B8G8R8_UINT_PACK32
+			| Self::A8B8G8R8_SINT_PACK32
+			| Self::A8B8G8R8_SRGB_PACK32
+			| Self::A2R10G10B10_UNORM_PACK32
+			| Self::A2R10G10B10_SNORM_PACK32
+			| Self::A2R10G10B10_USCALED_PACK32
+			| Self::A2R10G10B10_SSCALED_PACK32
+			| Self::A2R10G10B10_UINT_PACK32
+			| Self::A2R10G10B10_SINT_PACK32
+			| Self::A2B10G10R10_UNORM_PACK32
+			| Self::A2B10G10R10_SNORM_PACK32
+			| Self::A2B10G10R10_USCALED_PACK32
+			| Self::A2B10G10R10_SSCALED_PACK32
+			| Self::A2B10G10R10_UINT_PACK32
+			| Self::A2B10G10R10_SINT_PACK32
+			| Self::R16G16_UNORM
+			| Self::R16G16_SNORM
+			| Self::R16G16_USCALED
+			| Self::R16G16_SSCALED
+			| Self::R16G16_UINT
+			| Self::R16G16_SINT
+			| Self::R16G16_SFLOAT
+			| Self::R32_UINT
+			| Self::R32_SINT
+			| Self::R32_SFLOAT
+			| Self::B10G11R11_UFLOAT_PACK32
+			| Self::E5B9G9R9_UFLOAT_PACK32
+			| Self::X8_D24_UNORM_PACK32
+			| Self::D32_SFLOAT
+			| Self::D24_UNORM_S8_UINT
+			| Self::G8B8G8R8_422_UNORM
+			| Self::B8G8R8G8_422_UNORM
+			| Self::R10X6G10X6_UNORM_2PACK16
+			| Self::R12X4G12X4_UNORM_2PACK16
+			| Self::R16G16_S10_5_NV => 4,
+			Self::D32_SFLOAT_S8_UINT => 5,
+			Self::R16G16B16_UNORM
+			| Self::R16G16B16_SNORM
+			| Self::R16G16B16_USCALED
+			| Self::R16G16B16_SSCALED
+			| Self::R16G16B16_UINT
+			| Self::R16G16B16_SINT
+			| Self::R16G16B16_SFLOAT
+			| Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16
+			| Self::G16_B16_R16_3PLANE_420_UNORM
+			| Self::G16_B16R16_2PLANE_420_UNORM
+			| Self::G16_B16_R16_3PLANE_422_UNORM
+			| Self::G16_B16R16_2PLANE_422_UNORM
+			| Self::G16_B16_R16_3PLANE_444_UNORM
+			| Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16
+			| Self::G16_B16R16_2PLANE_444_UNORM => 6,
+			Self::R16G16B16A16_UNORM
+			| Self::R16G16B16A16_SNORM
+			| Self::R16G16B16A16_USCALED
+			| Self::R16G16B16A16_SSCALED
+			| Self::R16G16B16A16_UINT
+			| Self::R16G16B16A16_SINT
+			| Self::R16G16B16A16_SFLOAT
+			| Self::R32G32_UINT
+			| Self::R32G32_SINT
+			| Self::R32G32_SFLOAT
+			| Self::R64_UINT
+			| Self::R64_SINT
+			| Self::R64_SFLOAT
+			| Self::BC1_RGB_UNORM_BLOCK
+			| Self::BC1_RGB_SRGB_BLOCK
+			| Self::BC1_RGBA_UNORM_BLOCK
+			| Self::BC1_RGBA_SRGB_BLOCK
+			| Self::BC4_UNORM_BLOCK
+			| Self::BC4_SNORM_BLOCK
+			| Self::ETC2_R8G8B8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A1_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A1_SRGB_BLOCK
+			| Self::EAC_R11_UNORM_BLOCK
+			| Self::EAC_R11_SNORM_BLOCK
+			| Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16
+			| Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16
+			| Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16
+			| Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16
+			| Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16
+			| Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16
+			| Self::G16B16G16R16_422_UNORM
+			| Self::B16G16R16G16_422_UNORM
+			| Self::PVRTC1_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC1_4BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_4BPP_SRGB_BLOCK_IMG => 8,
+			Self::R32G32B32_UINT | Self::R32G32B32_SINT | Self::R32G32B32_SFLOAT => 12,
+			Self::R32G32B32A32_UINT
+			| Self::R32G32B32A32_SINT
+			| Self::R32G32B32A32_SFLOAT
+			| Self::R64G64_UINT
+			| Self::R64G64_SINT
+			| Self::R64G64_SFLOAT
+			| Self::BC2_UNORM_BLOCK
+			| Self::BC2_SRGB_BLOCK
+			| Self::BC3_UNORM_BLOCK
+			| Self::BC3_SRGB_BLOCK
+			| Self::BC5_UNORM_BLOCK
+			| Self::BC5_SNORM_BLOCK
+			| Self::BC6H_UFLOAT_BLOCK
+			| Self::BC6H_SFLOAT_BLOCK
+			| Self::BC7_UNORM_BLOCK
+			| Self::BC7_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A8_SRGB_BLOCK
+			| Self::EAC_R11G11_UNORM_BLOCK
+			| Self::EAC_R11G11_SNORM_BLOCK
+			| Self::ASTC_4X4_UNORM_BLOCK
+			| Self::ASTC_4X4_SRGB_BLOCK
+			| Self::ASTC_5X4_UNORM_BLOCK
+			| Self::ASTC_5X4_SRGB_BLOCK
+			| Self::ASTC_5X5_UNORM_BLOCK
+			| Self::ASTC_5X5_SRGB_BLOCK
+			| Self::ASTC_6X5_UNORM_BLOCK
+			| Self::ASTC_6X5_SRGB_BLOCK
+			| Self::ASTC_6X6_UNORM_BLOCK
+			| Self::ASTC_6X6_SRGB_BLOCK
+			| Self::ASTC_8X5_UNORM_BLOCK
+			| Self::ASTC_8X5_SRGB_BLOCK
+			| Self::ASTC_8X6_UNORM_BLOCK
+			| Self::ASTC_8X6_SRGB_BLOCK
+			| Self::ASTC_8X8_UNORM_BLOCK
+			| Self::ASTC_8X8_SRGB_BLOCK
+			| Self::ASTC_10X5_UNORM_BLOCK
+			| Self::ASTC_10X5_SRGB_BLOCK
+			| Self::ASTC_10X6_UNORM_BLOCK
+			| Self::ASTC_10X6_SRGB_BLOCK
+			| Self::ASTC_10X8_UNORM_BLOCK
+			| Self::ASTC_10X8_SRGB_BLOCK
+			| Self::ASTC_10X10_UNORM_BLOCK
+			| Self::ASTC_10X10_SRGB_BLOCK
+			| Self::ASTC_12X10_UNORM_BLOCK
+			| Self::ASTC_12X10_SRGB_BLOCK
+			| Self::ASTC_12X12_UNORM_BLOCK
+			| Self::ASTC_12X12_SRGB_BLOCK
+			| Self::ASTC_4X4_SFLOAT_BLOCK_EXT
+			| Self::ASTC_5X4_SFLOAT_BLOCK_EXT
+			| Self::ASTC_5X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_6X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_6X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X8_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X8_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X10_SFLOAT_BLOCK_EXT
+			| Self::ASTC_12X10_SFLOAT_BLOCK_EXT
+			| Self::ASTC_12X12_SFLOAT_BLOCK_EXT => 16,
+			Self::R64G64B64_UINT | Self::R64G64B64_SINT | Self::R64G64B64_SFLOAT => 24,
+			Self::R64G64B64A64_UINT | Self::R64G64B64A64_SINT | Self::R64G64B64A64_SFLOAT => 32,
+		}
+	}
+
+	/// The width and height of a texel block in texels, `[1, 1]` for formats that are neither block-compressed nor
+	/// subsampled.
+	pub const fn block_extent(self) -> [u32; 2] {
+		match self {
+			Self::G8B8G8R8_422_UNORM
+			| Self::B8G8R8G8_422_UNORM
+			| Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16
+			| Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16
+			| Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16
+			| Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16
+			| Self::G16B16G16R16_422_UNORM
+			| Self::B16G16R16G16_422_UNORM => [2, 1],
+			Self::BC1_RGB_UNORM_BLOCK
+			| Self::BC1_RGB_SRGB_BLOCK
+			| Self::BC1_RGBA_UNORM_BLOCK
+			| Self::BC1_RGBA_SRGB_BLOCK
+			| Self::BC2_UNORM_BLOCK
+			| Self::BC2_SRGB_BLOCK
+			| Self::BC3_UNORM_BLOCK
+			| Self::BC3_SRGB_BLOCK
+			| Self::BC4_UNORM_BLOCK
+			| Self::BC4_SNORM_BLOCK
+			| Self::BC5_UNORM_BLOCK
+			| Self::BC5_SNORM_BLOCK
+			| Self::BC6H_UFLOAT_BLOCK
+			| Self::BC6H_SFLOAT_BLOCK
+			| Self::BC7_UNORM_BLOCK
+			| Self::BC7_SRGB_BLOCK
+			| Self::ETC2_R8G8B8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A1_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A1_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A8_SRGB_BLOCK
+			| Self::EAC_R11_UNORM_BLOCK
+			| Self::EAC_R11_SNORM_BLOCK
+			| Self::EAC_R11G11_UNORM_BLOCK
+			| Self::EAC_R11G11_SNORM_BLOCK
+			| Self::ASTC_4X4_UNORM_BLOCK
+			| Self::ASTC_4X4_SRGB_BLOCK
+			| Self::PVRTC1_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_4BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_4BPP_SRGB_BLOCK_IMG
+			| Self::ASTC_4X4_SFLOAT_BLOCK_EXT => [4, 4],
+			Self::ASTC_5X4_UNORM_BLOCK | Self::ASTC_5X4_SRGB_BLOCK | Self::ASTC_5X4_SFLOAT_BLOCK_EXT => [5, 4],
+			Self::ASTC_5X5_UNORM_BLOCK | Self::ASTC_5X5_SRGB_BLOCK | Self::ASTC_5X5_SFLOAT_BLOCK_EXT => [5, 5],
+			Self::ASTC_6X5_UNORM_BLOCK | Self::ASTC_6X5_SRGB_BLOCK | Self::ASTC_6X5_SFLOAT_BLOCK_EXT => [6, 5],
+			Self::ASTC_6X6_UNORM_BLOCK | Self::ASTC_6X6_SRGB_BLOCK | Self::ASTC_6X6_SFLOAT_BLOCK_EXT => [6, 6],
+			Self::PVRTC1_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_2BPP_SRGB_BLOCK_IMG => [8, 4],
+			Self::ASTC_8X5_UNORM_BLOCK | Self::ASTC_8X5_SRGB_BLOCK | Self::ASTC_8X5_SFLOAT_BLOCK_EXT => [8, 5],
+			Self::ASTC_8X6_UNORM_BLOCK | Self::ASTC_8X6_SRGB_BLOCK | Self::ASTC_8X6_SFLOAT_BLOCK_EXT => [8, 6],
+			Self::ASTC_8X8_UNORM_BLOCK | Self::ASTC_8X8_SRGB_BLOCK | Self::ASTC_8X8_SFLOAT_BLOCK_EXT => [8, 8],
+			Self::ASTC_10X5_UNORM_BLOCK | Self::ASTC_10X5_SRGB_BLOCK | Self::ASTC_10X5_SFLOAT_BLOCK_EXT => [10, 5],
+			Self::ASTC_10X6_UNORM_BLOCK | Self::ASTC_10X6_SRGB_BLOCK | Self::ASTC_10X6_SFLOAT_BLOCK_EXT => [10, 6],
+			Self::ASTC_10X8_UNORM_BLOCK | Self::ASTC_10X8_SRGB_BLOCK | Self::ASTC_10X8_SFLOAT_BLOCK_EXT => [10, 8],
+			Self::ASTC_10X10_UNORM_BLOCK | Self::ASTC_10X10_SRGB_BLOCK | Self::ASTC_10X10_SFLOAT_BLOCK_EXT => [10, 10],
+			Self::ASTC_12X10_UNORM_BLOCK | Self::ASTC_12X10_SRGB_BLOCK | Self::ASTC_12X10_SFLOAT_BLOCK_EXT => [12, 10],
+			Self::ASTC_12X12_UNORM_BLOCK | Self::ASTC_12X12_SRGB_BLOCK | Self::ASTC_12X12_SFLOAT_BLOCK_EXT => [12, 12],
+			_ => [1, 1],
+		}
+	}
+
+	/// The aspects of an image of this format, see [`FormatAspects`].
+	pub const fn aspects(self) -> FormatAspects {
+		match self {
+			Self::UNDEFINED => FormatAspects::empty(),
+			Self::D16_UNORM | Self::X8_D24_UNORM_PACK32 | Self::D32_SFLOAT => FormatAspects::DEPTH,
+			Self::S8_UINT => FormatAspects::STENCIL,
+			Self::D16_UNORM_S8_UINT | Self::D24_UNORM_S8_UINT | Self::D32_SFLOAT_S8_UINT => {
+				FormatAspects::DEPTH.union(FormatAspects::STENCIL)
+			}
+			Self::G8_B8_R8_3PLANE_420_UNORM
+			| Self::G8_B8_R8_3PLANE_422_UNORM
+			| Self::G8_B8_R8_3PLANE_444_UNORM
+			| Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16
+			| Self::G16_B16_R16_3PLANE_420_UNORM
+			| Self::G16_B16_R16_3PLANE_422_UNORM
+			| Self::G16_B16_R16_3PLANE_444_UNORM => FormatAspects::COLOR
+				.union(FormatAspects::PLANE_0)
+				.union(FormatAspects::PLANE_1)
+				.union(FormatAspects::PLANE_2),
+			Self::G8_B8R8_2PLANE_420_UNORM
+			| Self::G8_B8R8_2PLANE_422_UNORM
+			| Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16
+			| Self::G16_B16R16_2PLANE_420_UNORM
+			| Self::G16_B16R16_2PLANE_422_UNORM
+			| Self::G8_B8R8_2PLANE_444_UNORM
+			| Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16
+			| Self::G16_B16R16_2PLANE_444_UNORM => FormatAspects::COLOR
+				.union(FormatAspects::PLANE_0)
+				.union(FormatAspects::PLANE_1),
+			_ => FormatAspects::COLOR,
+		}
+	}
+
+	/// Whether this is a color format, including multi-planar color formats
+	pub const fn is_color(self) -> bool {
+		self.aspects().contains(FormatAspects::COLOR)
+	}
+
+	/// Whether this format has a depth aspect
+	pub const fn has_depth(self) -> bool {
+		self.aspects().contains(FormatAspects::DEPTH)
+	}
+
+	/// Whether this format has a stencil aspect
+	pub const fn has_stencil(self) -> bool {
+		self.aspects().contains(FormatAspects::STENCIL)
+	}
+
+	/// Whether this format has a depth or stencil aspect, or both
+	pub const fn is_depth_stencil(self) -> bool {
+		self.aspects()
+			.intersects(FormatAspects::DEPTH.union(FormatAspects::STENCIL))
+	}
+
+	/// The amount of distinct channels of a texel, excluding any unused padding bits. Subsampled and multi-planar
+	/// formats count each of their channels once, combined depth/stencil formats count as 2 channels.
+	pub const fn components(self) -> u32 {
+		match self {
+			Self::UNDEFINED => 0,
+			Self::A8_UNORM_KHR
+			| Self::R8_UNORM
+			| Self::R8_SNORM
+			| Self::R8_USCALED
+			| Self::R8_SSCALED
+			| Self::R8_UINT
+			| Self::R8_SINT
+			| Self::R8_SRGB
+			| Self::R16_UNORM
+			| Self::R16_SNORM
+			| Self::R16_USCALED
+			| Self::R16_SSCALED
+			| Self::R16_UINT
+			| Self::R16_SINT
+			| Self::R16_SFLOAT
+			| Self::R32_UINT
+			| Self::R32_SINT
+			| Self::R32_SFLOAT
+			| Self::R64_UINT
+			| Self::R64_SINT
+			| Self::R64_SFLOAT
+			| Self::D16_UNORM
+			| Self::X8_D24_UNORM_PACK32
+			| Self::D32_SFLOAT
+			| Self::S8_UINT
+			| Self::BC4_UNORM_BLOCK
+			| Self::BC4_SNORM_BLOCK
+			| Self::EAC_R11_UNORM_BLOCK
+			| Self::EAC_R11_SNORM_BLOCK
+			| Self::R10X6_UNORM_PACK16
+			| Self::R12X4_UNORM_PACK16 => 1,
+			Self::R4G4_UNORM_PACK8
+			| Self::R8G8_UNORM
+			| Self::R8G8_SNORM
+			| Self::R8G8_USCALED
+			| Self::R8G8_SSCALED
+			| Self::R8G8_UINT
+			| Self::R8G8_SINT
+			| Self::R8G8_SRGB
+			| Self::R16G16_UNORM
+			| Self::R16G16_SNORM
+			| Self::R16G16_USCALED
+			| Self::R16G16_SSCALED
+			| Self::R16G16_UINT
+			| Self::R16G16_SINT
+			| Self::R16G16_SFLOAT
+			| Self::R32G32_UINT
+			| Self::R32G32_SINT
+			| Self::R32G32_SFLOAT
+			| Self::R64G64_UINT
+			| Self::R64G64_SINT
+			| Self::R64G64_SFLOAT
+			| Self::D16_UNORM_S8_UINT
+			| Self::D24_UNORM_S8_UINT
+			| Self::D32_SFLOAT_S8_UINT
+			| Self::BC5_UNORM_BLOCK
+			| Self::BC5_SNORM_BLOCK
+			| Self::EAC_R11G11_UNORM_BLOCK
+			| Self::EAC_R11G11_SNORM_BLOCK
+			| Self::R10X6G10X6_UNORM_2PACK16
+			| Self::R12X4G12X4_UNORM_2PACK16
+			| Self::R16G16_S10_5_NV => 2,
+			Self::R5G6B5_UNORM_PACK16
+			| Self::B5G6R5_UNORM_PACK16
+			| Self::R8G8B8_UNORM
+			| Self::R8G8B8_SNORM
+			| Self::R8G8B8_USCALED
+			| Self::R8G8B8_SSCALED
+			| Self::R8G8B8_UINT
+			| Self::R8G8B8_SINT
+			| Self::R8G8B8_SRGB
+			| Self::B8G8R8_UNORM
+			| Self::B8G8R8_SNORM
+			| Self::B8G8R8_USCALED
+			| Self::B8G8R8_SSCALED
+			| Self::B8G8R8_UINT
+			| Self::B8G8R8_SINT
+			| Self::B8G8R8_SRGB
+			| Self::R16G16B16_UNORM
+			| Self::R16G16B16_SNORM
+			| Self::R16G16B16_USCALED
+			| Self::R16G16B16_SSCALED
+			| Self::R16G16B16_UINT
+			| Self::R16G16B16_SINT
+			| Self::R16G16B16_SFLOAT
+			| Self::R32G32B32_UINT
+			| Self::R32G32B32_SINT
+			| Self::R32G32B32_SFLOAT
+			| Self::R64G64B64_UINT
+			| Self::R64G64B64_SINT
+			| Self::R64G64B64_SFLOAT
+			| Self::B10G11R11_UFLOAT_PACK32
+			| Self::E5B9G9R9_UFLOAT_PACK32
+			| Self::BC1_RGB_UNORM_BLOCK
+			| Self::BC1_RGB_SRGB_BLOCK
+			| Self::BC6H_UFLOAT_BLOCK
+			| Self::BC6H_SFLOAT_BLOCK
+			| Self::ETC2_R8G8B8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8_SRGB_BLOCK
+			| Self::G8B8G8R8_422_UNORM
+			| Self::B8G8R8G8_422_UNORM
+			| Self::G8_B8_R8_3PLANE_420_UNORM
+			| Self::G8_B8R8_2PLANE_420_UNORM
+			| Self::G8_B8_R8_3PLANE_422_UNORM
+			| Self::G8_B8R8_2PLANE_422_UNORM
+			| Self::G8_B8_R8_3PLANE_444_UNORM
+			| Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16
+			| Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16
+			| Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16
+			| Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16
+			| Self::G16B16G16R16_422_UNORM
+			| Self::B16G16R16G16_422_UNORM
+			| Self::G16_B16_R16_3PLANE_420_UNORM
+			| Self::G16_B16R16_2PLANE_420_UNORM
+			| Self::G16_B16_R16_3PLANE_422_UNORM
+			| Self::G16_B16R16_2PLANE_422_UNORM
+			| Self::G16_B16_R16_3PLANE_444_UNORM
+			| Self::G8_B8R8_2PLANE_444_UNORM
+			| Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16
+			| Self::G16_B16R16_2PLANE_444_UNORM => 3,
+			Self::R4G4B4A4_UNORM_PACK16
+			| Self::B4G4R4A4_UNORM_PACK16
+			| Self::R5G5B5A1_UNORM_PACK16
+			| Self::B5G5R5A1_UNORM_PACK16
+			| Self::A1R5G5B5_UNORM_PACK16
+			| Self::A1B5G5R5_UNORM_PACK16_KHR
+			| Self::R8G8B8A8_UNORM
+			| Self::R8G8B8A8_SNORM
+			| Self::R8G8B8A8_USCALED
+			| Self::R8G8B8A8_SSCALED
+			| Self::R8G8B8A8_UINT
+			| Self::R8G8B8A8_SINT
+			| Self::R8G8B8A8_SRGB
+			| Self::B8G8R8A8_UNORM
+			| Self::B8G8R8A8_SNORM
+			| Self::B8G8R8A8_USCALED
+			| Self::B8G8R8A8_SSCALED
+			| Self::B8G8R8A8_UINT
+			| Self::B8G8R8A8_SINT
+			| Self::B8G8R8A8_SRGB
+			| Self::A8B8G8R8_UNORM_PACK32
+			| Self::A8B8G8R8_SNORM_PACK32
+			| Self::A8B8G8R8_USCALED_PACK32
+			| Self::A8B8G8R8_SSCALED_PACK32
+			| Self::A8B8G8R8_UINT_PACK32
+			| Self::A8B8G8R8_SINT_PACK32
+			| Self::A8B8G8R8_SRGB_PACK32
+			| Self::A2R10G10B10_UNORM_PACK32
+			| Self::A2R10G10B10_SNORM_PACK32
+			| Self::A2R10G10B10_USCALED_PACK32
+			| Self::A2R10G10B10_SSCALED_PACK32
+			| Self::A2R10G10B10_UINT_PACK32
+			| Self::A2R10G10B10_SINT_PACK32
+			| Self::A2B10G10R10_UNORM_PACK32
+			| Self::A2B10G10R10_SNORM_PACK32
+			| Self::A2B10G10R10_USCALED_PACK32
+			| Self::A2B10G10R10_SSCALED_PACK32
+			| Self::A2B10G10R10_UINT_PACK32
+			| Self::A2B10G10R10_SINT_PACK32
+			| Self::R16G16B16A16_UNORM
+			| Self::R16G16B16A16_SNORM
+			| Self::R16G16B16A16_USCALED
+			| Self::R16G16B16A16_SSCALED
+			| Self::R16G16B16A16_UINT
+			| Self::R16G16B16A16_SINT
+			| Self::R16G16B16A16_SFLOAT
+			| Self::R32G32B32A32_UINT
+			| Self::R32G32B32A32_SINT
+			| Self::R32G32B32A32_SFLOAT
+			| Self::R64G64B64A64_UINT
+			| Self::R64G64B64A64_SINT
+			| Self::R64G64B64A64_SFLOAT
+			| Self::BC1_RGBA_UNORM_BLOCK
+			| Self::BC1_RGBA_SRGB_BLOCK
+			| Self::BC2_UNORM_BLOCK
+			| Self::BC2_SRGB_BLOCK
+			| Self::BC3_UNORM_BLOCK
+			| Self::BC3_SRGB_BLOCK
+			| Self::BC7_UNORM_BLOCK
+			| Self::BC7_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A1_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A1_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A8_SRGB_BLOCK
+			| Self::ASTC_4X4_UNORM_BLOCK
+			| Self::ASTC_4X4_SRGB_BLOCK
+			| Self::ASTC_5X4_UNORM_BLOCK
+			| Self::ASTC_5X4_SRGB_BLOCK
+			| Self::ASTC_5X5_UNORM_BLOCK
+			| Self::ASTC_5X5_SRGB_BLOCK
+			| Self::ASTC_6X5_UNORM_BLOCK
+			| Self::ASTC_6X5_SRGB_BLOCK
+			| Self::ASTC_6X6_UNORM_BLOCK
+			| Self::ASTC_6X6_SRGB_BLOCK
+			| Self::ASTC_8X5_UNORM_BLOCK
+			| Self::ASTC_8X5_SRGB_BLOCK
+			| Self::ASTC_8X6_UNORM_BLOCK
+			| Self::ASTC_8X6_SRGB_BLOCK
+			| Self::ASTC_8X8_UNORM_BLOCK
+			| Self::ASTC_8X8_SRGB_BLOCK
+			| Self::ASTC_10X5_UNORM_BLOCK
+			| Self::ASTC_10X5_SRGB_BLOCK
+			| Self::ASTC_10X6_UNORM_BLOCK
+			| Self::ASTC_10X6_SRGB_BLOCK
+			| Self::ASTC_10X8_UNORM_BLOCK
+			| Self::ASTC_10X8_SRGB_BLOCK
+			| Self::ASTC_10X10_UNORM_BLOCK
+			| Self::ASTC_10X10_SRGB_BLOCK
+			| Self::ASTC_12X10_UNORM_BLOCK
+			| Self::ASTC_12X10_SRGB_BLOCK
+			| Self::ASTC_12X12_UNORM_BLOCK
+			| Self::ASTC_12X12_SRGB_BLOCK
+			| Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16
+			| Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16
+			| Self::PVRTC1_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC1_4BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_4BPP_SRGB_BLOCK_IMG
+			| Self::ASTC_4X4_SFLOAT_BLOCK_EXT
+			| Self::ASTC_5X4_SFLOAT_BLOCK_EXT
+			| Self::ASTC_5X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_6X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_6X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X8_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X8_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X10_SFLOAT_BLOCK_EXT
+			| Self::ASTC_12X10_SFLOAT_BLOCK_EXT
+			| Self::ASTC_12X12_SFLOAT_BLOCK_EXT
+			| Self::A4R4G4B4_UNORM_PACK16
+			| Self::A4B4G4R4_UNORM_PACK16 => 4,
+		}
+	}
+
+	/// How the values of a texel are interpreted. Combined depth/stencil formats return the numeric type of their depth
+	/// aspect, as their stencil aspect is always [`NumericType::UInt`]. Returns `None` for [`Format::UNDEFINED`].
+	pub const fn numeric_type(self) -> Option<NumericType> {
+		match self {
+			Self::UNDEFINED => None,
+			Self::R4G4_UNORM_PACK8
+			| Self::R4G4B4A4_UNORM_PACK16
+			| Self::B4G4R4A4_UNORM_PACK16
+			| Self::R5G6B5_UNORM_PACK16
+			| Self::B5G6R5_UNORM_PACK16
+			| Self::R5G5B5A1_UNORM_PACK16
+			| Self::B5G5R5A1_UNORM_PACK16
+			| Self::A1R5G5B5_UNORM_PACK16
+			| Self::A1B5G5R5_UNORM_PACK16_KHR
+			| Self::A8_UNORM_KHR
+			| Self::R8_UNORM
+			| Self::R8G8_UNORM
+			| Self::R8G8B8_UNORM
+			| Self::B8G8R8_UNORM
+			| Self::R8G8B8A8_UNORM
+			| Self::B8G8R8A8_UNORM
+			| Self::A8B8G8R8_UNORM_PACK32
+			| Self::A2R10G10B10_UNORM_PACK32
+			| Self::A2B10G10R10_UNORM_PACK32
+			| Self::R16_UNORM
+			| Self::R16G16_UNORM
+			| Self::R16G16B16_UNORM
+			| Self::R16G16B16A16_UNORM
+			| Self::D16_UNORM
+			| Self::X8_D24_UNORM_PACK32
+			| Self::D16_UNORM_S8_UINT
+			| Self::D24_UNORM_S8_UINT
+			| Self::BC1_RGB_UNORM_BLOCK
+			| Self::BC1_RGBA_UNORM_BLOCK
+			| Self::BC2_UNORM_BLOCK
+			| Self::BC3_UNORM_BLOCK
+			| Self::BC4_UNORM_BLOCK
+			| Self::BC5_UNORM_BLOCK
+			| Self::BC7_UNORM_BLOCK
+			| Self::ETC2_R8G8B8_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A1_UNORM_BLOCK
+			| Self::ETC2_R8G8B8A8_UNORM_BLOCK
+			| Self::EAC_R11_UNORM_BLOCK
+			| Self::EAC_R11G11_UNORM_BLOCK
+			| Self::ASTC_4X4_UNORM_BLOCK
+			| Self::ASTC_5X4_UNORM_BLOCK
+			| Self::ASTC_5X5_UNORM_BLOCK
+			| Self::ASTC_6X5_UNORM_BLOCK
+			| Self::ASTC_6X6_UNORM_BLOCK
+			| Self::ASTC_8X5_UNORM_BLOCK
+			| Self::ASTC_8X6_UNORM_BLOCK
+			| Self::ASTC_8X8_UNORM_BLOCK
+			| Self::ASTC_10X5_UNORM_BLOCK
+			| Self::ASTC_10X6_UNORM_BLOCK
+			| Self::ASTC_10X8_UNORM_BLOCK
+			| Self::ASTC_10X10_UNORM_BLOCK
+			| Self::ASTC_12X10_UNORM_BLOCK
+			| Self::ASTC_12X12_UNORM_BLOCK
+			| Self::G8B8G8R8_422_UNORM
+			| Self::B8G8R8G8_422_UNORM
+			| Self::G8_B8_R8_3PLANE_420_UNORM
+			| Self::G8_B8R8_2PLANE_420_UNORM
+			| Self::G8_B8_R8_3PLANE_422_UNORM
+			| Self::G8_B8R8_2PLANE_422_UNORM
+			| Self::G8_B8_R8_3PLANE_444_UNORM
+			| Self::R10X6_UNORM_PACK16
+			| Self::R10X6G10X6_UNORM_2PACK16
+			| Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16
+			| Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16
+			| Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16
+			| Self::R12X4_UNORM_PACK16
+			| Self::R12X4G12X4_UNORM_2PACK16
+			| Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16
+			| Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16
+			| Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16
+			| Self::G16B16G16R16_422_UNORM
+			| Self::B16G16R16G16_422_UNORM
+			| Self::G16_B16_R16_3PLANE_420_UNORM
+			| Self::G16_B16R16_2PLANE_420_UNORM
+			| Self::G16_B16_R16_3PLANE_422_UNORM
+			| Self::G16_B16R16_2PLANE_422_UNORM
+			| Self::G16_B16_R16_3PLANE_444_UNORM
+			| Self::PVRTC1_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC1_4BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_2BPP_UNORM_BLOCK_IMG
+			| Self::PVRTC2_4BPP_UNORM_BLOCK_IMG
+			| Self::G8_B8R8_2PLANE_444_UNORM
+			| Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16
+			| Self::G16_B16R16_2PLANE_444_UNORM
+			| Self::A4R4G4B4_UNORM_PACK16
+			| Self::A4B4G4R4_UNORM_PACK16 => Some(NumericType::UNorm),
+			Self::R8_SNORM
+			| Self::R8G8_SNORM
+			| Self::R8G8B8_SNORM
+			| Self::B8G8R8_SNORM
+			| Self::R8G8B8A8_SNORM
+			| Self::B8G8R8A8_SNORM
+			| Self::A8B8G8R8_SNORM_PACK32
+			| Self::A2R10G10B10_SNORM_PACK32
+			| Self::A2B10G10R10_SNORM_PACK32
+			| Self::R16_SNORM
+			| Self::R16G16_SNORM
+			| Self::R16G16B16_SNORM
+			| Self::R16G16B16A16_SNORM
+			| Self::BC4_SNORM_BLOCK
+			| Self::BC5_SNORM_BLOCK
+			| Self::EAC_R11_SNORM_BLOCK
+			| Self::EAC_R11G11_SNORM_BLOCK => Some(NumericType::SNorm),
+			Self::R8_USCALED
+			| Self::R8G8_USCALED
+			| Self::R8G8B8_USCALED
+			| Self::B8G8R8_USCALED
+			| Self::R8G8B8A8_USCALED
+			| Self::B8G8R8A8_USCALED
+			| Self::A8B8G8R8_USCALED_PACK32
+			| Self::A2R10G10B10_USCALED_PACK32
+			| Self::A2B10G10R10_USCALED_PACK32
+			| Self::R16_USCALED
+			| Self::R16G16_USCALED
+			| Self::R16G16B16_USCALED
+			| Self::R16G16B16A16_USCALED => Some(NumericType::UScaled),
+			Self::R8_SSCALED
+			| Self::R8G8_SSCALED
+			| Self::R8G8B8_SSCALED
+			| Self::B8G8R8_SSCALED
+			| Self::R8G8B8A8_SSCALED
+			| Self::B8G8R8A8_SSCALED
+			| Self::A8B8G8R8_SSCALED_PACK32
+			| Self::A2R10G10B10_SSCALED_PACK32
+			| Self::A2B10G10R10_SSCALED_PACK32
+			| Self::R16_SSCALED
+			| Self::R16G16_SSCALED
+			| Self::R16G16B16_SSCALED
+			| Self::R16G16B16A16_SSCALED => Some(NumericType::SScaled),
+			Self::R8_UINT
+			| Self::R8G8_UINT
+			| Self::R8G8B8_UINT
+			| Self::B8G8R8_UINT
+			| Self::R8G8B8A8_UINT
+			| Self::B8G8R8A8_UINT
+			| Self::A8B8G8R8_UINT_PACK32
+			| Self::A2R10G10B10_UINT_PACK32
+			| Self::A2B10G10R10_UINT_PACK32
+			| Self::R16_UINT
+			| Self::R16G16_UINT
+			| Self::R16G16B16_UINT
+			| Self::R16G16B16A16_UINT
+			| Self::R32_UINT
+			| Self::R32G32_UINT
+			| Self::R32G32B32_UINT
+			| Self::R32G32B32A32_UINT
+			| Self::R64_UINT
+			| Self::R64G64_UINT
+			| Self::R64G64B64_UINT
+			| Self::R64G64B64A64_UINT
+			| Self::S8_UINT => Some(NumericType::UInt),
+			Self::R8_SINT
+			| Self::R8G8_SINT
+			| Self::R8G8B8_SINT
+			| Self::B8G8R8_SINT
+			| Self::R8G8B8A8_SINT
+			| Self::B8G8R8A8_SINT
+			| Self::A8B8G8R8_SINT_PACK32
+			| Self::A2R10G10B10_SINT_PACK32
+			| Self::A2B10G10R10_SINT_PACK32
+			| Self::R16_SINT
+			| Self::R16G16_SINT
+			| Self::R16G16B16_SINT
+			| Self::R16G16B16A16_SINT
+			| Self::R32_SINT
+			| Self::R32G32_SINT
+			| Self::R32G32B32_SINT
+			| Self::R32G32B32A32_SINT
+			| Self::R64_SINT
+			| Self::R64G64_SINT
+			| Self::R64G64B64_SINT
+			| Self::R64G64B64A64_SINT => Some(NumericType::SInt),
+			Self::R8_SRGB
+			| Self::R8G8_SRGB
+			| Self::R8G8B8_SRGB
+			| Self::B8G8R8_SRGB
+			| Self::R8G8B8A8_SRGB
+			| Self::B8G8R8A8_SRGB
+			| Self::A8B8G8R8_SRGB_PACK32
+			| Self::BC1_RGB_SRGB_BLOCK
+			| Self::BC1_RGBA_SRGB_BLOCK
+			| Self::BC2_SRGB_BLOCK
+			| Self::BC3_SRGB_BLOCK
+			| Self::BC7_SRGB_BLOCK
+			| Self::ETC2_R8G8B8_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A1_SRGB_BLOCK
+			| Self::ETC2_R8G8B8A8_SRGB_BLOCK
+			| Self::ASTC_4X4_SRGB_BLOCK
+			| Self::ASTC_5X4_SRGB_BLOCK
+			| Self::ASTC_5X5_SRGB_BLOCK
+			| Self::ASTC_6X5_SRGB_BLOCK
+			| Self::ASTC_6X6_SRGB_BLOCK
+			| Self::ASTC_8X5_SRGB_BLOCK
+			| Self::ASTC_8X6_SRGB_BLOCK
+			| Self::ASTC_8X8_SRGB_BLOCK
+			| Self::ASTC_10X5_SRGB_BLOCK
+			| Self::ASTC_10X6_SRGB_BLOCK
+			| Self::ASTC_10X8_SRGB_BLOCK
+			| Self::ASTC_10X10_SRGB_BLOCK
+			| Self::ASTC_12X10_SRGB_BLOCK
+			| Self::ASTC_12X12_SRGB_BLOCK
+			| Self::PVRTC1_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC1_4BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_2BPP_SRGB_BLOCK_IMG
+			| Self::PVRTC2_4BPP_SRGB_BLOCK_IMG => Some(NumericType::Srgb),
+			Self::R16_SFLOAT
+			| Self::R16G16_SFLOAT
+			| Self::R16G16B16_SFLOAT
+			| Self::R16G16B16A16_SFLOAT
+			| Self::R32_SFLOAT
+			| Self::R32G32_SFLOAT
+			| Self::R32G32B32_SFLOAT
+			| Self::R32G32B32A32_SFLOAT
+			| Self::R64_SFLOAT
+			| Self::R64G64_SFLOAT
+			| Self::R64G64B64_SFLOAT
+			| Self::R64G64B64A64_SFLOAT
+			| Self::D32_SFLOAT
+			| Self::D32_SFLOAT_S8_UINT
+			| Self::BC6H_SFLOAT_BLOCK
+			| Self::ASTC_4X4_SFLOAT_BLOCK_EXT
+			| Self::ASTC_5X4_SFLOAT_BLOCK_EXT
+			| Self::ASTC_5X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_6X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_6X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_8X8_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X5_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X6_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X8_SFLOAT_BLOCK_EXT
+			| Self::ASTC_10X10_SFLOAT_BLOCK_EXT
+			| Self::ASTC_12X10_SFLOAT_BLOCK_EXT
+			| Self::ASTC_12X12_SFLOAT_BLOCK_EXT => Some(NumericType::SFloat),
+			Self::B10G11R11_UFLOAT_PACK32 | Self::E5B9G9R9_UFLOAT_PACK32 | Self::BC6H_UFLOAT_BLOCK => {
+				Some(NumericType::UFloat)
+			}
+			Self::R16G16_S10_5_NV => Some(NumericType::SFixed5),
+		}
+	}
+
+	/// Whether the color channels of this format are sRGB encoded
+	pub const fn is_srgb(self) -> bool {
+		matches!(self.numeric_type(), Some(NumericType::Srgb))
+	}
+
+	/// The sRGB format with the same memory layout as this linear `UNORM` format, or vice versa. Returns `None` if
+	/// there is no such format.
+	pub const fn srgb_pair(self) -> Option<Format> {
+		match self {
+			Self::R8_UNORM => Some(Self::R8_SRGB),
+			Self::R8_SRGB => Some(Self::R8_UNORM),
+			Self::R8G8_UNORM => Some(Self::R8G8_SRGB),
+			Self::R8G8_SRGB => Some(Self::R8G8_UNORM),
+			Self::R8G8B8_UNORM => Some(Self::R8G8B8_SRGB),
+			Self::R8G8B8_SRGB => Some(Self::R8G8B8_UNORM),
+			Self::B8G8R8_UNORM => Some(Self::B8G8R8_SRGB),
+			Self::B8G8R8_SRGB => Some(Self::B8G8R8_UNORM),
+			Self::R8G8B8A8_UNORM => Some(Self::R8G8B8A8_SRGB),
+			Self::R8G8B8A8_SRGB => Some(Self::R8G8B8A8_UNORM),
+			Self::B8G8R8A8_UNORM => Some(Self::B8G8R8A8_SRGB),
+			Self::B8G8R8A8_SRGB => Some(Self::B8G8R8A8_UNORM),
+			Self::A8B8G8R8_UNORM_PACK32 => Some(Self::A8B8G8R8_SRGB_PACK32),
+			Self::A8B8G8R8_SRGB_PACK32 => Some(Self::A8B8G8R8_UNORM_PACK32),
+			Self::BC1_RGB_UNORM_BLOCK => Some(Self::BC1_RGB_SRGB_BLOCK),
+			Self::BC1_RGB_SRGB_BLOCK => Some(Self::BC1_RGB_UNORM_BLOCK),
+			Self::BC1_RGBA_UNORM_BLOCK => Some(Self::BC1_RGBA_SRGB_BLOCK),
+			Self::BC1_RGBA_SRGB_BLOCK => Some(Self::BC1_RGBA_UNORM_BLOCK),
+			Self::BC2_UNORM_BLOCK => Some(Self::BC2_SRGB_BLOCK),
+			Self::BC2_SRGB_BLOCK => Some(Self::BC2_UNORM_BLOCK),
+			Self::BC3_UNORM_BLOCK => Some(Self::BC3_SRGB_BLOCK),
+			Self::BC3_SRGB_BLOCK => Some(Self::BC3_UNORM_BLOCK),
+			Self::BC7_UNORM_BLOCK => Some(Self::BC7_SRGB_BLOCK),
+			Self::BC7_SRGB_BLOCK => Some(Self::BC7_UNORM_BLOCK),
+			Self::ETC2_R8G8B8_UNORM_BLOCK => Some(Self::ETC2_R8G8B8_SRGB_BLOCK),
+			Self::ETC2_R8G8B8_SRGB_BLOCK => Some(Self::ETC2_R8G8B8_UNORM_BLOCK),
+			Self::ETC2_R8G8B8A1_UNORM_BLOCK => Some(Self::ETC2_R8G8B8A1_SRGB_BLOCK),
+			Self::ETC2_R8G8B8A1_SRGB_BLOCK => Some(Self::ETC2_R8G8B8A1_UNORM_BLOCK),
+			Self::ETC2_R8G8B8A8_UNORM_BLOCK => Some(Self::ETC2_R8G8B8A8_SRGB_BLOCK),
+			Self::ETC2_R8G8B8A8_SRGB_BLOCK => Some(Self::ETC2_R8G8B8A8_UNORM_BLOCK),
+			Self::ASTC_4X4_UNORM_BLOCK => Some(Self::ASTC_4X4_SRGB_BLOCK),
+			Self::ASTC_4X4_SRGB_BLOCK => Some(Self::ASTC_4X4_UNORM_BLOCK),
+			Self::ASTC_5X4_UNORM_BLOCK => Some(Self::ASTC_5X4_SRGB_BLOCK),
+			Self::ASTC_5X4_SRGB_BLOCK => Some(Self::ASTC_5X4_UNORM_BLOCK),
+			Self::ASTC_5X5_UNORM_BLOCK => Some(Self::ASTC_5X5_SRGB_BLOCK),
+			Self::ASTC_5X5_SRGB_BLOCK => Some(Self::ASTC_5X5_UNORM_BLOCK),
+			Self::ASTC_6X5_UNORM_BLOCK => Some(Self::ASTC_6X5_SRGB_BLOCK),
+			Self::ASTC_6X5_SRGB_BLOCK => Some(Self::ASTC_6X5_UNORM_BLOCK),
+			Self::ASTC_6X6_UNORM_BLOCK => Some(Self::ASTC_6X6_SRGB_BLOCK),
+			Self::ASTC_6X6_SRGB_BLOCK => Some(Self::ASTC_6X6_UNORM_BLOCK),
+			Self::ASTC_8X5_UNORM_BLOCK => Some(Self::ASTC_8X5_SRGB_BLOCK),
+			Self::ASTC_8X5_SRGB_BLOCK => Some(Self::ASTC_8X5_UNORM_BLOCK),
+			Self::ASTC_8X6_UNORM_BLOCK => Some(Self::ASTC_8X6_SRGB_BLOCK),
+			Self::ASTC_8X6_SRGB_BLOCK => Some(Self::ASTC_8X6_UNORM_BLOCK),
+			Self::ASTC_8X8_UNORM_BLOCK => Some(Self::ASTC_8X8_SRGB_BLOCK),
+			Self::ASTC_8X8_SRGB_BLOCK => Some(Self::ASTC_8X8_UNORM_BLOCK),
+			Self::ASTC_10X5_UNORM_BLOCK => Some(Self::ASTC_10X5_SRGB_BLOCK),
+			Self::ASTC_10X5_SRGB_BLOCK => Some(Self::ASTC_10X5_UNORM_BLOCK),
+			Self::ASTC_10X6_UNORM_BLOCK => Some(Self::ASTC_10X6_SRGB_BLOCK),
+			Self::ASTC_10X6_SRGB_BLOCK => Some(Self::ASTC_10X6_UNORM_BLOCK),
+			Self::ASTC_10X8_UNORM_BLOCK => Some(Self::ASTC_10X8_SRGB_BLOCK),
+			Self::ASTC_10X8_SRGB_BLOCK => Some(Self::ASTC_10X8_UNORM_BLOCK),
+			Self::ASTC_10X10_UNORM_BLOCK => Some(Self::ASTC_10X10_SRGB_BLOCK),
+			Self::ASTC_10X10_SRGB_BLOCK => Some(Self::ASTC_10X10_UNORM_BLOCK),
+			Self::ASTC_12X10_UNORM_BLOCK => Some(Self::ASTC_12X10_SRGB_BLOCK),
+			Self::ASTC_12X10_SRGB_BLOCK => Some(Self::ASTC_12X10_UNORM_BLOCK),
+			Self::ASTC_12X12_UNORM_BLOCK => Some(Self::ASTC_12X12_SRGB_BLOCK),
+			Self::ASTC_12X12_SRGB_BLOCK => Some(Self::ASTC_12X12_UNORM_BLOCK),
+			Self::PVRTC1_2BPP_UNORM_BLOCK_IMG => Some(Self::PVRTC1_2BPP_SRGB_BLOCK_IMG),
+			Self::PVRTC1_2BPP_SRGB_BLOCK_IMG => Some(Self::PVRTC1_2BPP_UNORM_BLOCK_IMG),
+			Self::PVRTC1_4BPP_UNORM_BLOCK_IMG => Some(Self::PVRTC1_4BPP_SRGB_BLOCK_IMG),
+			Self::PVRTC1_4BPP_SRGB_BLOCK_IMG => Some(Self::PVRTC1_4BPP_UNORM_BLOCK_IMG),
+			Self::PVRTC2_2BPP_UNORM_BLOCK_IMG => Some(Self::PVRTC2_2BPP_SRGB_BLOCK_IMG),
+			Self::PVRTC2_2BPP_SRGB_BLOCK_IMG => Some(Self::PVRTC2_2BPP_UNORM_BLOCK_IMG),
+			Self::PVRTC2_4BPP_UNORM_BLOCK_IMG => Some(Self::PVRTC2_4BPP_SRGB_BLOCK_IMG),
+			Self::PVRTC2_4BPP_SRGB_BLOCK_IMG => Some(Self::PVRTC2_4BPP_UNORM_BLOCK_IMG),
+			_ => None,
+		}
+	}
+
+	/// The size in bytes of a tightly packed row of `width` texels, rounded up to whole texel blocks. For
+	/// block-compressed formats a row is as high as a texel block.
+	pub const fn row_pitch(self, width: u32) -> u64 {
+		let [block_width, _] = self.block_extent();
+		width.div_ceil(block_width) as u64 * self.block_size() as u64
+	}
+
+	/// The size in bytes of tightly packed texel data of size `extent`, rounded up to whole texel blocks. The planes of
+	/// multi-planar formats follow each other, with the chroma planes subsampled according to
+	/// [`Self::plane_subsampling`].
+	pub const fn data_size(self, extent: Extent) -> u64 {
+		if self.aspects().contains(FormatAspects::PLANE_0) {
+			// every plane holds a single channel of a texel, apart from the combined chroma plane of 2-plane formats
+			let channel_size = self.block_size() as u64 / 3;
+			let [sub_width, sub_height] = self.plane_subsampling();
+			let luma = extent.width as u64 * extent.height as u64;
+			let chroma = extent.width.div_ceil(sub_width) as u64 * extent.height.div_ceil(sub_height) as u64;
+			return (luma + 2 * chroma) * channel_size * extent.depth as u64;
+		}
+		let [_, block_height] = self.block_extent();
+		self.row_pitch(extent.width) * extent.height.div_ceil(block_height) as u64 * extent.depth as u64
+	}
+
+	/// The size in bytes of a texel block of the single `aspect` of this format, as it is laid out in a buffer when
+	/// copying between buffers and images. Returns `None` if `aspect` is not exactly one of the [`Self::aspects`] that
+	/// can be copied on its own, like the color aspect of multi-planar formats.
+	pub const fn aspect_block_size(self, aspect: FormatAspects) -> Option<u32> {
+		let aspects = self.aspects();
+		if aspect.bits().count_ones() != 1 || !aspects.contains(aspect) {
+			return None;
+		}
+		let channel_size = self.block_size() / 3;
+		match aspect {
+			FormatAspects::COLOR if aspects.contains(FormatAspects::PLANE_0) => None,
+			FormatAspects::COLOR => Some(self.block_size()),
+			FormatAspects::DEPTH => match self {
+				Self::D16_UNORM | Self::D16_UNORM_S8_UINT => Some(2),
+				_ => Some(4),
+			},
+			FormatAspects::STENCIL => Some(1),
+			// the chroma plane of 2-plane formats contains both chroma channels
+			FormatAspects::PLANE_1 if !aspects.contains(FormatAspects::PLANE_2) => Some(2 * channel_size),
+			_ => Some(channel_size),
+		}
+	}
+
+	/// The factor by which the width and height of the chroma planes of a multi-planar format are reduced relative to
+	/// the luma plane, `[1, 1]` for all other formats.
+	pub const fn plane_subsampling(self) -> [u32; 2] {
+		match self {
+			Self::G8_B8_R8_3PLANE_420_UNORM
+			| Self::G8_B8R8_2PLANE_420_UNORM
+			| Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16
+			| Self::G16_B16_R16_3PLANE_420_UNORM
+			| Self::G16_B16R16_2PLANE_420_UNORM => [2, 2],
+			Self::G8_B8_R8_3PLANE_422_UNORM
+			| Self::G8_B8R8_2PLANE_422_UNORM
+			| Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16
+			| Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16
+			| Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16
+			| Self::G16_B16_R16_3PLANE_422_UNORM
+			| Self::G16_B16R16_2PLANE_422_UNORM => [2, 1],
+			_ => [1, 1],
+		}
+	}
+}
+
+bitflags::bitflags! {
+	/// The aspects of an image, which are the different kinds of data stored in its texels. Most formats have a single
+	/// aspect, but combined depth/stencil formats have both and multi-planar formats have a separate aspect per plane.
+	#[repr(transparent)]
+	#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+	pub struct FormatAspects: u32 {
+		const COLOR = 0b1;
+		const DEPTH = 0b10;
+		const STENCIL = 0b100;
+		const PLANE_0 = 0b1000;
+		const PLANE_1 = 0b1_0000;
+		const PLANE_2 = 0b10_0000;
+	}
+}
+
+/// How the values of the channels of a texel are interpreted, see [`Format::numeric_type`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NumericType {
+	/// Unsigned integer normalized to `[0, 1]`
+	UNorm,
+	/// Signed integer normalized to `[-1, 1]`
+	SNorm,
+	/// Unsigned integer converted to float without normalization
+	UScaled,
+	/// Signed integer converted to float without normalization
+	SScaled,
+	/// Unsigned integer
+	UInt,
+	/// Signed integer
+	SInt,
+	/// Unsigned floating point
+	UFloat,
+	/// Signed floating point
+	SFloat,
+	/// Unsigned integer normalized to `[0, 1]` using the sRGB transfer function
+	Srgb,
+	/// Signed fixed point with 5 fractional bits
+	SFixed5,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_format_info() {
+		assert_eq!(Format::R8G8B8A8_SRGB.components(), 4);
+		assert_eq!(Format::B10G11R11_UFLOAT_PACK32.components(), 3);
+		assert_eq!(Format::R10X6G10X6_UNORM_2PACK16.components(), 2);
+		assert_eq!(Format::BC5_UNORM_BLOCK.components(), 2);
+		assert_eq!(Format::D24_UNORM_S8_UINT.components(), 2);
+
+		assert_eq!(Format::R16G16_SFLOAT.numeric_type(), Some(NumericType::SFloat));
+		assert_eq!(Format::BC6H_UFLOAT_BLOCK.numeric_type(), Some(NumericType::UFloat));
+		assert_eq!(Format::D32_SFLOAT_S8_UINT.numeric_type(), Some(NumericType::SFloat));
+		assert_eq!(Format::S8_UINT.numeric_type(), Some(NumericType::UInt));
+		assert_eq!(Format::UNDEFINED.numeric_type(), None);
+
+		assert_eq!(Format::R8G8B8A8_UNORM.aspects(), FormatAspects::COLOR);
+		assert_eq!(
+			Format::D24_UNORM_S8_UINT.aspects(),
+			FormatAspects::DEPTH | FormatAspects::STENCIL
+		);
+		assert_eq!(
+			Format::G8_B8R8_2PLANE_420_UNORM.aspects(),
+			FormatAspects::COLOR | FormatAspects::PLANE_0 | FormatAspects::PLANE_1
+		);
+		assert!(Format::D16_UNORM.has_depth() && !Format::D16_UNORM.has_stencil());
+		assert!(Format::S8_UINT.is_depth_stencil() && !Format::S8_UINT.is_color());
+
+		assert!(Format::BC7_SRGB_BLOCK.is_srgb());
+		assert_eq!(Format::B8G8R8A8_UNORM.srgb_pair(), Some(Format::B8G8R8A8_SRGB));
+		assert_eq!(
+			Format::ASTC_8X8_SRGB_BLOCK.srgb_pair(),
+			Some(Format::ASTC_8X8_UNORM_BLOCK)
+		);
+		assert_eq!(Format::R16_UNORM.srgb_pair(), None);
+
+		let extent = Extent {
+			width: 10,
+			height: 6,
+			depth: 1,
+		};
+		assert_eq!(Format::R8G8B8A8_UNORM.row_pitch(10), 40);
+		assert_eq!(Format::R8G8B8A8_UNORM.data_size(extent), 240);
+		assert_eq!(Format::BC1_RGB_UNORM_BLOCK.row_pitch(10), 3 * 8);
+		assert_eq!(Format::BC1_RGB_UNORM_BLOCK.data_size(extent), 3 * 2 * 8);
+		assert_eq!(Format::ASTC_10X5_UNORM_BLOCK.data_size(extent), 2 * 16);
+		assert_eq!(Format::G8_B8R8_2PLANE_420_UNORM.data_size(extent), 60 + 2 * 5 * 3);
+		assert_eq!(
+			Format::G16_B16_R16_3PLANE_422_UNORM.data_size(extent),
+			(60 + 2 * 5 * 6) * 2
+		);
+		assert_eq!(Format::G8_B8_R8_3PLANE_444_UNORM.data_size(extent), 3 * 60);
+
+		assert_eq!(Format::R8G8B8A8_UNORM.aspect_block_size(FormatAspects::COLOR), Some(4));
+		assert_eq!(Format::R8G8B8A8_UNORM.aspect_block_size(FormatAspects::DEPTH), None);
+		assert_eq!(
+			Format::D24_UNORM_S8_UINT.aspect_block_size(FormatAspects::DEPTH),
+			Some(4)
+		);
+		assert_eq!(
+			Format::D16_UNORM_S8_UINT.aspect_block_size(FormatAspects::DEPTH),
+			Some(2)
+		);
+		assert_eq!(
+			Format::D32_SFLOAT_S8_UINT.aspect_block_size(FormatAspects::STENCIL),
+			Some(1)
+		);
+		assert_eq!(
+			Format::D24_UNORM_S8_UINT.aspect_block_size(FormatAspects::DEPTH | FormatAspects::STENCIL),
+			None
+		);
+		assert_eq!(
+			Format::G8_B8R8_2PLANE_420_UNORM.aspect_block_size(FormatAspects::COLOR),
+			None
+		);
+		assert_eq!(
+			Format::G8_B8R8_2PLANE_420_UNORM.aspect_block_size(FormatAspects::PLANE_1),
+			Some(2)
+		);
+		assert_eq!(
+			Format::G16_B16_R16_3PLANE_420_UNORM.aspect_block_size(FormatAspects::PLANE_2),
+			Some(2)
+		);
+	}
+}
@@ -1,31 +1,50 @@
 use crate::backing::range_set::DescriptorIndexIterator;
-use crate::backing::table::{DrainFlushQueue, RcTableSlot, SlotAllocationError, Table, TableInterface, TableSync};
+use crate::backing::table::{
+	DrainFlushQueue, RcTableSlot, SlotAllocationError, Table, TableInterface, TableStats, TableSync,
+};
 use crate::descriptor::{
-	Bindless, BindlessAllocationScheme, DescContentCpu, DescTable, DescriptorCounts, Extent, MutDesc, MutDescExt,
-	RCDesc, RCDescExt, WeakBindless,
+	Bindless, BindlessAllocationScheme, DescContentCpu, DescTable, DescriptorCounts, Extent, Format, LeakedImage,
+	MutDesc, MutDescExt, RCDesc, RCDescExt, WeakBindless,
 };
 use crate::pipeline::{AccessLock, ImageAccess};
 use crate::platform::{BindlessPlatform, PendingExecution};
 use rust_gpu_bindless_shaders::descriptor::{Image, ImageType, MutImage};
+use spirv_std::image::{Arrayed, Dimensionality, Multisampled};
+use std::any::{TypeId, type_name};
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
 use thiserror::Error;
 
 impl<T: ImageType> DescContentCpu for Image<T> {
 	type DescTable<P: BindlessPlatform> = ImageTable<P>;
+
+	fn is_slot_of<P: BindlessPlatform>(slot: &RcTableSlot) -> bool {
+		is_image_slot_of::<P, T>(slot)
+	}
 }
 
 impl<T: ImageType> DescContentCpu for MutImage<T> {
 	type DescTable<P: BindlessPlatform> = ImageTable<P>;
+
+	fn is_slot_of<P: BindlessPlatform>(slot: &RcTableSlot) -> bool {
+		is_image_slot_of::<P, T>(slot)
+	}
+}
+
+/// All image types share the same table, so the [`ImageSlot::image_type`] must be checked as well
+fn is_image_slot_of<P: BindlessPlatform, T: ImageType>(slot: &RcTableSlot) -> bool {
+	ImageTable::<P>::try_get_slot(slot).is_some_and(|image| image.image_type == TypeId::of::<T>())
 }
 
 impl<P: BindlessPlatform> DescTable<P> for ImageTable<P> {
 	type Slot = ImageSlot<P>;
 
-	fn get_slot(slot: &RcTableSlot) -> &Self::Slot {
-		slot.try_deref::<ImageInterface<P>>().unwrap()
+	fn try_get_slot(slot: &RcTableSlot) -> Option<&Self::Slot> {
+		slot.try_deref::<ImageInterface<P>>()
 	}
 }
 
@@ -59,7 +78,20 @@ pub struct ImageSlot<P: BindlessPlatform> {
 	pub mip_levels: u32,
 	/// The amount of array layers. Must be `1` if the image is not arrayed.
 	pub array_layers: u32,
-	pub access_lock: AccessLock<ImageAccess>,
+	/// The amount of samples per texel. Must be [`SampleCount::Sample1`] if the image is not multisampled.
+	pub samples: SampleCount,
+	/// The [`TypeId`] of the [`ImageType`] this image is accessed as, checked when upgrading a [`WeakDesc`].
+	///
+	/// [`WeakDesc`]: rust_gpu_bindless_shaders::descriptor::WeakDesc
+	pub image_type: TypeId,
+	/// The first mip level of [`Self::platform`] covered by this slot, non-zero for some views
+	pub base_mip: u32,
+	/// The first array layer of [`Self::platform`] covered by this slot, non-zero for some views
+	pub base_layer: u32,
+	/// The image this is a view of, kept alive by this view. See [`ImageTableAccess::alloc_view`].
+	pub parent: Option<RcTableSlot>,
+	/// Tracks the access of the entire image, shared by all views of the same image
+	pub access_lock: Arc<AccessLock<ImageAccess>>,
 	/// This may be replaced with a platform-specific getter, once you can query the name from gpu-allocator to not
 	/// unnecessarily duplicate the String (see my PR https://github.com/Traverse-Research/gpu-allocator/pull/257)
 	pub debug_name: String,
@@ -78,6 +110,24 @@ impl<P: BindlessPlatform> ImageSlot<P> {
 	pub fn debug_name(&self) -> &str {
 		&self.debug_name
 	}
+
+	/// Returns true if this is a view of another image, see [`ImageTableAccess::alloc_view`]
+	pub fn is_view(&self) -> bool {
+		self.parent.is_some()
+	}
+
+	/// The estimated size of this image in bytes, calculated from its format, extent, mip levels and array layers.
+	/// The actual memory used may differ due to alignment, tiling, multisampling or subsampled planes. Views do not
+	/// own any memory and are always 0.
+	pub fn estimated_size(&self) -> u64 {
+		if self.is_view() {
+			return 0;
+		}
+		let mips = (0..self.mip_levels)
+			.map(|mip| self.format.data_size(self.extent.mip(mip)))
+			.sum::<u64>();
+		mips * self.array_layers as u64
+	}
 }
 
 pub trait ImageDescExt {
@@ -110,11 +160,53 @@ pub struct ImageTable<P: BindlessPlatform> {
 }
 
 impl<P: BindlessPlatform> ImageTable<P> {
-	pub fn new(table_sync: &Arc<TableSync>, counts: DescriptorCounts, bindless: WeakBindless<P>) -> Self {
+	pub fn new(
+		table_sync: &Arc<TableSync>,
+		counts: DescriptorCounts,
+		max_counts: DescriptorCounts,
+		bindless: WeakBindless<P>,
+	) -> Self {
 		Self {
-			table: table_sync.register(counts.image, ImageInterface { bindless }).unwrap(),
+			table: table_sync
+				.register_growable(
+					counts.image,
+					max_counts.image,
+					ImageInterface {
+						bindless,
+						allocated_bytes: AtomicU64::new(0),
+					},
+				)
+				.unwrap(),
 		}
 	}
+
+	/// Collect statistics about this table, see [`Table::stats`].
+	pub fn stats(&self) -> TableStats {
+		self.table.stats()
+	}
+
+	/// The total size in bytes of all images currently alive in this table, estimated using [`ImageSlot::estimated_size`].
+	pub fn allocated_bytes(&self) -> u64 {
+		self.table.allocated_bytes.load(Relaxed)
+	}
+
+	pub(crate) fn leaked(&self) -> Vec<LeakedImage> {
+		self.table
+			.referenced_slots()
+			.iter()
+			.map(|slot| {
+				let image = Self::get_slot(slot);
+				LeakedImage {
+					id: slot.id(),
+					debug_name: image.debug_name.clone(),
+					usage: image.usage,
+					format: image.format,
+					extent: image.extent,
+					estimated_size: image.estimated_size(),
+				}
+			})
+			.collect()
+	}
 }
 
 pub struct ImageTableAccess<'a, P: BindlessPlatform>(pub &'a Bindless<P>);
@@ -148,20 +240,26 @@ bitflags::bitflags! {
 		/// Image is part of a swapchain and may be used for presenting. You may not create an image with this usage
 		/// yourself, and must acquire it from a swapchain.
 		const SWAPCHAIN = 0b100_0000;
+		/// Allows creating views with a different but compatible format, see [`ImageTableAccess::alloc_view`]
+		const MUTABLE_FORMAT = 0b1000_0000;
 	}
 }
 
 impl BindlessImageUsage {
+	/// The usages accessing the image through an image view, whose support depends on the format of the view
+	pub const VIEW_USAGE: Self = Self::SAMPLED
+		.union(Self::STORAGE)
+		.union(Self::COLOR_ATTACHMENT)
+		.union(Self::DEPTH_STENCIL_ATTACHMENT);
+
 	#[inline]
 	pub fn initial_image_access(&self) -> ImageAccess {
 		ImageAccess::Undefined
 	}
 }
 
-pub type Format = ash::vk::Format;
-
 /// The amount of samples of the Image. Must be [`SampleCount::Sample1`] if the image is not multisampled.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub enum SampleCount {
 	#[default]
 	Sample1,
@@ -215,11 +313,47 @@ impl<T: ImageType> BindlessImageCreateInfo<'_, T> {
 	#[inline]
 	pub fn validate<P: BindlessPlatform>(&self) -> Result<(), ImageAllocationError<P>> {
 		if self.usage.contains(BindlessImageUsage::SWAPCHAIN) {
-			Err(ImageAllocationError::SwapchainUsage {
+			return Err(ImageAllocationError::SwapchainUsage {
 				name: self.name.to_owned(),
-			})
-		} else {
-			Ok(())
+			});
+		}
+
+		let array_layers = |expected: &'static str| ImageAllocationError::ArrayLayers {
+			name: self.name.to_owned(),
+			array_layers: self.array_layers,
+			expected,
+		};
+		match (T::dimensionality(), T::arrayed()) {
+			(Dimensionality::Cube, Arrayed::False) if self.array_layers != 6 => {
+				return Err(array_layers("a cubemap must have exactly 6"));
+			}
+			(Dimensionality::Cube, Arrayed::True) if self.array_layers == 0 || !self.array_layers.is_multiple_of(6) => {
+				return Err(array_layers("a cubemap array must have a non-zero multiple of 6"));
+			}
+			(Dimensionality::Cube, _) => (),
+			(_, Arrayed::False) if self.array_layers != 1 => {
+				return Err(array_layers("a non-arrayed image must have exactly 1"));
+			}
+			(_, Arrayed::True) if self.array_layers == 0 => {
+				return Err(array_layers("an arrayed image must have at least 1"));
+			}
+			_ => (),
+		}
+
+		let samples = |expected: &'static str| ImageAllocationError::Samples {
+			name: self.name.to_owned(),
+			samples: self.samples,
+			expected,
+		};
+		match T::multisampled() {
+			Multisampled::False if self.samples != SampleCount::Sample1 => {
+				Err(samples("a non-multisampled image must have 1 sample"))
+			}
+			Multisampled::True if self.samples == SampleCount::Sample1 => {
+				Err(samples("a multisampled image must have more than 1 sample"))
+			}
+			Multisampled::True if self.mip_levels != 1 => Err(samples("a multisampled image must have 1 mip level")),
+			_ => Ok(()),
 		}
 	}
 }
@@ -235,6 +369,55 @@ pub enum ImageAllocationError<P: BindlessPlatform> {
 	#[error("Image {name} must not be created with {swapchain:?}, instead swapchain images must be acquired from a swapchain", swapchain = BindlessImageUsage::SWAPCHAIN
 	)]
 	SwapchainUsage { name: String },
+	#[error("Image {name} has {array_layers} array layers, but {expected}")]
+	ArrayLayers {
+		name: String,
+		array_layers: u32,
+		expected: &'static str,
+	},
+	#[error("Image {name} has {samples:?}, but {expected}")]
+	Samples {
+		name: String,
+		samples: SampleCount,
+		expected: &'static str,
+	},
+	#[error(
+		"View {name} of mips {mips:?} and layers {layers:?} exceeds the {mip_levels} mips and {array_layers} layers of image {image}"
+	)]
+	ViewOutOfRange {
+		name: String,
+		image: String,
+		mips: Range<u32>,
+		layers: Range<u32>,
+		mip_levels: u32,
+		array_layers: u32,
+	},
+	#[error("View {name} with format {format:?} requires image {image} to be created with {mutable:?}", mutable = BindlessImageUsage::MUTABLE_FORMAT)]
+	MissingMutableFormat {
+		name: String,
+		image: String,
+		format: Format,
+	},
+	#[error("View {name} with format {format:?} is incompatible with the format {image_format:?} of image {image}")]
+	IncompatibleViewFormat {
+		name: String,
+		image: String,
+		format: Format,
+		image_format: Format,
+	},
+	#[error("View {name} is incompatible with the type of image {image}: {reason}")]
+	IncompatibleViewType {
+		name: String,
+		image: String,
+		reason: &'static str,
+	},
+	#[error("View {name} with format {format:?} supports none of the usages {usage:?} of image {image}")]
+	NoViewUsage {
+		name: String,
+		image: String,
+		format: Format,
+		usage: BindlessImageUsage,
+	},
 }
 
 impl<P: BindlessPlatform> Debug for ImageAllocationError<P> {
@@ -250,16 +433,26 @@ impl<P: BindlessPlatform> ImageTableAccess<'_, P> {
 	/// Image's device and ImageView's device must be the same as the bindless device. Ownership of the Image and
 	/// ImageView is transferred to this table. You may not access or drop it afterward, except by going though the
 	/// returned `BoxDesc`. The generic T: ImageType must match the type of Image and ImageView.
+	///
+	/// # Panics
+	/// If [`ImageSlot::image_type`] is not the [`TypeId`] of `T`
 	#[inline]
 	pub unsafe fn alloc_slot<T: ImageType>(
 		&self,
 		image: ImageSlot<P>,
 	) -> Result<MutDesc<P, MutImage<T>>, SlotAllocationError> {
 		unsafe {
-			Ok(MutDesc::new(
-				self.table.alloc_slot(image)?,
-				PendingExecution::<P>::new_completed(),
-			))
+			assert_eq!(
+				image.image_type,
+				TypeId::of::<T>(),
+				"ImageSlot::image_type of \"{}\" must be the TypeId of {}",
+				image.debug_name,
+				type_name::<T>()
+			);
+			let bytes = image.estimated_size();
+			let desc = MutDesc::new(self.table.alloc_slot(image)?, PendingExecution::<P>::new_completed());
+			self.table.allocated_bytes.fetch_add(bytes, Relaxed);
+			Ok(desc)
 		}
 	}
 
@@ -285,7 +478,12 @@ impl<P: BindlessPlatform> ImageTableAccess<'_, P> {
 				extent: create_info.extent,
 				mip_levels: create_info.mip_levels,
 				array_layers: create_info.array_layers,
-				access_lock: AccessLock::new(create_info.usage.initial_image_access()),
+				samples: create_info.samples,
+				image_type: TypeId::of::<T>(),
+				base_mip: 0,
+				base_layer: 0,
+				parent: None,
+				access_lock: Arc::new(AccessLock::new(create_info.usage.initial_image_access())),
 				debug_name: create_info.name.to_string(),
 				swapchain_image_id: SwapchainImageId::default(),
 			})?)
@@ -295,17 +493,21 @@ impl<P: BindlessPlatform> ImageTableAccess<'_, P> {
 
 pub struct ImageInterface<P: BindlessPlatform> {
 	bindless: WeakBindless<P>,
+	allocated_bytes: AtomicU64,
 }
 
 impl<P: BindlessPlatform> TableInterface for ImageInterface<P> {
 	type Slot = ImageSlot<P>;
 
 	fn drop_slots<'a>(&self, indices: impl DescriptorIndexIterator<'a, Self>) {
+		let indices = indices.into_range_set();
+		let bytes = indices.iter().map(|(_, image)| image.estimated_size()).sum::<u64>();
+		self.allocated_bytes.fetch_sub(bytes, Relaxed);
 		unsafe {
 			if let Some(bindless) = self.bindless.upgrade() {
 				bindless
 					.platform
-					.destroy_images(bindless.global_descriptor_set(), indices);
+					.destroy_images(bindless.global_descriptor_set(), &indices);
 			}
 		}
 	}
@@ -314,3 +516,52 @@ impl<P: BindlessPlatform> TableInterface for ImageInterface<P> {
 		// do nothing, flushing of descriptors is handled differently
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{Cubemap, CubemapArray, Image2d, Image2dArray, Image2dMs};
+	use crate::platform::mock::Mock;
+	use crate::platform::mock::test_utils::mock_bindless;
+
+	fn alloc_image<T: ImageType>(
+		bindless: &Bindless<Mock>,
+		array_layers: u32,
+		samples: SampleCount,
+		mip_levels: u32,
+	) -> Result<(), ImageAllocationError<Mock>> {
+		bindless.image().alloc::<T>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels,
+			array_layers,
+			samples,
+			usage: BindlessImageUsage::SAMPLED,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_image_layers_and_samples() -> anyhow::Result<()> {
+		use SampleCount::{Sample1, Sample4};
+		let bindless = mock_bindless();
+		alloc_image::<Image2dArray>(&bindless, 4, Sample1, 1)?;
+		alloc_image::<Cubemap>(&bindless, 6, Sample1, 1)?;
+		alloc_image::<CubemapArray>(&bindless, 12, Sample1, 1)?;
+		alloc_image::<Image2dMs>(&bindless, 1, Sample4, 1)?;
+
+		let layers = |result| matches!(result, Err(ImageAllocationError::ArrayLayers { .. }));
+		assert!(layers(alloc_image::<Image2d>(&bindless, 2, Sample1, 1)));
+		assert!(layers(alloc_image::<Image2dArray>(&bindless, 0, Sample1, 1)));
+		assert!(layers(alloc_image::<Cubemap>(&bindless, 1, Sample1, 1)));
+		assert!(layers(alloc_image::<CubemapArray>(&bindless, 8, Sample1, 1)));
+
+		let samples = |result| matches!(result, Err(ImageAllocationError::Samples { .. }));
+		assert!(samples(alloc_image::<Image2d>(&bindless, 1, Sample4, 1)));
+		assert!(samples(alloc_image::<Image2dMs>(&bindless, 1, Sample1, 1)));
+		assert!(samples(alloc_image::<Image2dMs>(&bindless, 1, Sample4, 2)));
+		Ok(())
+	}
+}
@@ -0,0 +1,450 @@
+use crate::backing::table::RcTableSlot;
+use crate::descriptor::{BindlessImageUsage, DescTable, ImageTable};
+use crate::descriptor::{
+	Format, ImageAllocationError, ImageSlot, ImageTableAccess, MutDesc, MutDescExt, RCDesc, RCDescExt, SwapchainImageId,
+};
+use crate::platform::BindlessPlatform;
+use rust_gpu_bindless_shaders::descriptor::{Image, ImageType, MutImage};
+use spirv_std::image::{Arrayed, Dimensionality};
+use std::any::TypeId;
+
+/// Describes which mips and layers of an image a view covers, see [`ImageTableAccess::alloc_view`].
+#[derive(Copy, Clone, Debug)]
+pub struct BindlessImageViewCreateInfo<'a> {
+	/// The format to view the image as. Defaults to the format of the image, a different format requires the image to
+	/// be created with [`BindlessImageUsage::MUTABLE_FORMAT`] and must have the same texel block size and extent.
+	pub format: Option<Format>,
+	/// The first mip level of the view
+	pub base_mip: u32,
+	/// The amount of mip levels of the view
+	pub mip_count: u32,
+	/// The first array layer of the view
+	pub base_layer: u32,
+	/// The amount of array layers of the view
+	pub layer_count: u32,
+	/// Name of the view, for tracking and debugging purposes
+	pub name: &'a str,
+}
+
+impl Default for BindlessImageViewCreateInfo<'_> {
+	fn default() -> Self {
+		Self {
+			format: None,
+			base_mip: 0,
+			mip_count: 1,
+			base_layer: 0,
+			layer_count: 1,
+			name: "",
+		}
+	}
+}
+
+impl BindlessImageViewCreateInfo<'_> {
+	/// Validates this view of type `V` against the `image` of type `T` it views and returns the resolved format of the
+	/// view
+	pub fn validate<P: BindlessPlatform, T: ImageType, V: ImageType>(
+		&self,
+		image: &ImageSlot<P>,
+	) -> Result<Format, ImageAllocationError<P>> {
+		let mips = self.base_mip..self.base_mip.saturating_add(self.mip_count);
+		let layers = self.base_layer..self.base_layer.saturating_add(self.layer_count);
+		if mips.is_empty() || mips.end > image.mip_levels || layers.is_empty() || layers.end > image.array_layers {
+			return Err(ImageAllocationError::ViewOutOfRange {
+				name: self.name.to_string(),
+				image: image.debug_name.clone(),
+				mips,
+				layers,
+				mip_levels: image.mip_levels,
+				array_layers: image.array_layers,
+			});
+		}
+
+		self.validate_type::<P, T, V>(image)?;
+
+		let format = self.format.unwrap_or(image.format);
+		if format != image.format {
+			if !image.usage.contains(BindlessImageUsage::MUTABLE_FORMAT) {
+				return Err(ImageAllocationError::MissingMutableFormat {
+					name: self.name.to_string(),
+					image: image.debug_name.clone(),
+					format,
+				});
+			}
+			if format.block_size() != image.format.block_size() || format.block_extent() != image.format.block_extent()
+			{
+				return Err(ImageAllocationError::IncompatibleViewFormat {
+					name: self.name.to_string(),
+					image: image.debug_name.clone(),
+					format,
+					image_format: image.format,
+				});
+			}
+		}
+		Ok(format)
+	}
+
+	fn validate_type<P: BindlessPlatform, T: ImageType, V: ImageType>(
+		&self,
+		image: &ImageSlot<P>,
+	) -> Result<(), ImageAllocationError<P>> {
+		let incompatible = |reason: &'static str| {
+			Err(ImageAllocationError::IncompatibleViewType {
+				name: self.name.to_string(),
+				image: image.debug_name.clone(),
+				reason,
+			})
+		};
+		if V::multisampled() != T::multisampled() {
+			return incompatible("a view must be multisampled if and only if its image is");
+		}
+
+		// rect images are plain 2D images, and only cube images are created cube compatible
+		let plain = |dim: Dimensionality| match dim {
+			Dimensionality::Rect => Dimensionality::TwoD,
+			dim => dim,
+		};
+		match (plain(T::dimensionality()), plain(V::dimensionality())) {
+			(Dimensionality::Cube, Dimensionality::Cube | Dimensionality::TwoD) => (),
+			(_, Dimensionality::Cube) => return incompatible("a cube view requires a cube image"),
+			(t, v) if t != v => return incompatible("a view must have the same dimensionality as its image"),
+			_ => (),
+		}
+
+		match (V::dimensionality(), V::arrayed()) {
+			(Dimensionality::Cube, Arrayed::False) if self.layer_count != 6 => {
+				incompatible("a cube view must have exactly 6 layers")
+			}
+			(Dimensionality::Cube, Arrayed::True) if !self.layer_count.is_multiple_of(6) => {
+				incompatible("a cube array view must have a multiple of 6 layers")
+			}
+			(Dimensionality::Cube, _) => Ok(()),
+			(_, Arrayed::False) if self.layer_count != 1 => {
+				incompatible("a non-arrayed view must have exactly 1 layer")
+			}
+			(_, Arrayed::True) if T::arrayed() == Arrayed::False && T::dimensionality() != Dimensionality::Cube => {
+				incompatible("an arrayed view requires an arrayed or cube image")
+			}
+			_ => Ok(()),
+		}
+	}
+}
+
+impl<P: BindlessPlatform> ImageTableAccess<'_, P> {
+	/// Allocates a new descriptor viewing the mips and layers of `image` described by `create_info`, possibly
+	/// reinterpreting its format. The view has its own [`DescriptorId`] and keeps `image` alive.
+	///
+	/// The view shares the access tracking of the entire image with `image` and all other views of it. Only one of them
+	/// can be accessed mutably at a time, and transitioning any of them transitions all mips and layers of the image.
+	/// [`MutImageAccessExt::access_dont_care`] of a view keeps the current contents of the image.
+	///
+	/// `V` must be compatible with `T`, e.g. a single layer of an arrayed image may be viewed as its non-arrayed type,
+	/// and a cube image as a 2D array. A cube view requires a cube image.
+	///
+	/// [`MutImageAccessExt::access_dont_care`]: crate::pipeline::MutImageAccessExt::access_dont_care
+	/// [`DescriptorId`]: rust_gpu_bindless_shaders::descriptor::DescriptorId
+	pub fn alloc_view<T: ImageType, V: ImageType>(
+		&self,
+		image: &MutDesc<P, MutImage<T>>,
+		create_info: &BindlessImageViewCreateInfo,
+	) -> Result<MutDesc<P, MutImage<V>>, ImageAllocationError<P>> {
+		unsafe {
+			let slot = self.alloc_view_slot::<T, V>(image.rc_slot(), create_info)?;
+			let (slot, _) = self.alloc_slot::<V>(slot)?.into_inner();
+			Ok(MutDesc::new(slot, image.pending_execution().clone()))
+		}
+	}
+
+	/// Allocates a new descriptor viewing the mips and layers of a shared `image`, see [`Self::alloc_view`].
+	pub fn alloc_shared_view<T: ImageType, V: ImageType>(
+		&self,
+		image: &RCDesc<P, Image<T>>,
+		create_info: &BindlessImageViewCreateInfo,
+	) -> Result<RCDesc<P, Image<V>>, ImageAllocationError<P>> {
+		unsafe {
+			let slot = self.alloc_view_slot::<T, V>(image.rc_slot(), create_info)?;
+			let (slot, _) = self.alloc_slot::<V>(slot)?.into_inner();
+			Ok(RCDesc::new(slot))
+		}
+	}
+
+	unsafe fn alloc_view_slot<T: ImageType, V: ImageType>(
+		&self,
+		parent: &RcTableSlot,
+		create_info: &BindlessImageViewCreateInfo,
+	) -> Result<ImageSlot<P>, ImageAllocationError<P>> {
+		unsafe {
+			let image = ImageTable::<P>::get_slot(parent);
+			let format = create_info.validate::<P, T, V>(image)?;
+			let create_info = BindlessImageViewCreateInfo {
+				format: Some(format),
+				..*create_info
+			};
+			// a reinterpreted format may not support all usages of the image, e.g. sRGB formats usually lack storage
+			let unsupported = BindlessImageUsage::VIEW_USAGE - self.0.platform.supported_view_usage(format);
+			let usage = image.usage - unsupported;
+			if !usage.intersects(BindlessImageUsage::VIEW_USAGE) {
+				return Err(ImageAllocationError::NoViewUsage {
+					name: create_info.name.to_string(),
+					image: image.debug_name.clone(),
+					format,
+					usage: image.usage,
+				});
+			}
+			let platform = self
+				.0
+				.platform
+				.alloc_image_view::<V>(image, &create_info, usage)
+				.map_err(Into::<ImageAllocationError<P>>::into)?;
+			Ok(ImageSlot {
+				platform,
+				usage,
+				format,
+				extent: image.extent.mip(create_info.base_mip),
+				mip_levels: create_info.mip_count,
+				array_layers: create_info.layer_count,
+				samples: image.samples,
+				image_type: TypeId::of::<V>(),
+				base_mip: image.base_mip + create_info.base_mip,
+				base_layer: image.base_layer + create_info.base_layer,
+				parent: Some(parent.clone()),
+				access_lock: image.access_lock.clone(),
+				debug_name: create_info.name.to_string(),
+				swapchain_image_id: SwapchainImageId::default(),
+			})
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessImageCreateInfo, Cubemap, Image2d, Image2dArray, ImageDescExt};
+	use crate::pipeline::{ImageAccess, MutImageAccessExt, TransferRead, TransferWrite};
+	use crate::platform::mock::test_utils::mock_bindless;
+	use crate::platform::mock::{Mock, MockCommand};
+
+	#[test]
+	fn test_image_view() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let image = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels: 5,
+			usage: BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_DST | BindlessImageUsage::MUTABLE_FORMAT,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let image = bindless.execute(|cmd| Ok(image.access::<TransferWrite>(cmd)?.into_desc()))?;
+
+		let mip = bindless.image().alloc_view::<_, Image2d>(
+			&image,
+			&BindlessImageViewCreateInfo {
+				base_mip: 2,
+				name: "mip 2",
+				..BindlessImageViewCreateInfo::default()
+			},
+		)?;
+		let slot = mip.inner_slot();
+		assert_ne!(mip.id(), image.id());
+		assert!(slot.is_view());
+		assert_eq!((slot.base_mip, slot.mip_levels), (2, 1));
+		assert_eq!(slot.extent, [4, 4, 1].into());
+		assert_eq!(slot.access_lock.get()?, ImageAccess::TransferWrite);
+		assert_eq!(bindless.stats().image_bytes, image.inner_slot().estimated_size());
+
+		let srgb = bindless.image().alloc_view::<_, Image2d>(
+			&image,
+			&BindlessImageViewCreateInfo {
+				format: Some(Format::R8G8B8A8_SRGB),
+				mip_count: 5,
+				name: "srgb",
+				..BindlessImageViewCreateInfo::default()
+			},
+		)?;
+		assert_eq!(srgb.format(), Format::R8G8B8A8_SRGB);
+
+		let out_of_range = bindless.image().alloc_view::<_, Image2d>(
+			&image,
+			&BindlessImageViewCreateInfo {
+				base_mip: 4,
+				mip_count: 2,
+				..BindlessImageViewCreateInfo::default()
+			},
+		);
+		assert!(matches!(out_of_range, Err(ImageAllocationError::ViewOutOfRange { .. })));
+		let incompatible = bindless.image().alloc_view::<_, Image2d>(
+			&image,
+			&BindlessImageViewCreateInfo {
+				format: Some(Format::R16G16B16A16_SFLOAT),
+				..BindlessImageViewCreateInfo::default()
+			},
+		);
+		assert!(matches!(
+			incompatible,
+			Err(ImageAllocationError::IncompatibleViewFormat { .. })
+		));
+
+		// views keep their image alive
+		drop((image, srgb));
+		bindless.flush();
+		bindless.table_sync().collect_now();
+		assert_eq!(bindless.stats().image.live_slots, 2);
+		drop(mip);
+		bindless.table_sync().collect_now();
+		bindless.table_sync().collect_now();
+		assert_eq!(bindless.stats().image.live_slots, 0);
+		Ok(())
+	}
+
+	#[test]
+	fn test_image_view_shared_access() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let image = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels: 2,
+			usage: BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let view = bindless.image().alloc_view::<_, Image2d>(
+			&image,
+			&BindlessImageViewCreateInfo {
+				base_mip: 1,
+				name: "mip 1",
+				..BindlessImageViewCreateInfo::default()
+			},
+		)?;
+
+		// image and view can't be accessed at the same time
+		let result = bindless.execute(|cmd| {
+			let _image = image.access::<TransferWrite>(cmd)?;
+			view.access::<TransferRead>(cmd)?;
+			Ok(())
+		});
+		assert!(result.is_err());
+		let image = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels: 2,
+			usage: BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let view = bindless.image().alloc_view::<_, Image2d>(
+			&image,
+			&BindlessImageViewCreateInfo {
+				base_mip: 1,
+				name: "mip 1",
+				..BindlessImageViewCreateInfo::default()
+			},
+		)?;
+		bindless.take_executions();
+
+		// transitioning the view transitions the image, and the view doesn't discard the image contents
+		let view = bindless.execute(|cmd| Ok(view.access::<TransferWrite>(cmd)?.into_desc()))?;
+		assert_eq!(image.inner_slot().access_lock.get()?, ImageAccess::TransferWrite);
+		let view = bindless.execute(|cmd| Ok(view.access_dont_care::<TransferRead>(cmd)?.into_desc()))?;
+		let executions = bindless.take_executions();
+		assert!(executions[1].commands.contains(&MockCommand::TransitionImage {
+			image: view.inner_slot().handle,
+			src: ImageAccess::TransferWrite,
+			dst: ImageAccess::TransferRead,
+		}));
+		bindless.execute(|cmd| Ok(image.access::<TransferRead>(cmd)?.into_desc()))?;
+		let executions = bindless.take_executions();
+		assert!(
+			!executions[0]
+				.commands
+				.iter()
+				.any(|command| matches!(command, MockCommand::TransitionImage { .. }))
+		);
+		drop(view);
+		Ok(())
+	}
+
+	#[test]
+	fn test_image_view_usage() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let create_info = |usage: BindlessImageUsage| BindlessImageCreateInfo::<Image2d> {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			usage: usage | BindlessImageUsage::MUTABLE_FORMAT,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		};
+		let srgb = BindlessImageViewCreateInfo {
+			format: Some(Format::R8G8B8A8_SRGB),
+			name: "srgb",
+			..BindlessImageViewCreateInfo::default()
+		};
+
+		// the sRGB view drops the storage usage its format doesn't support
+		let image = bindless
+			.image()
+			.alloc::<Image2d>(&create_info(BindlessImageUsage::SAMPLED | BindlessImageUsage::STORAGE))?;
+		let view = bindless.image().alloc_view::<_, Image2d>(&image, &srgb)?;
+		assert_eq!(
+			view.inner_slot().usage,
+			BindlessImageUsage::SAMPLED | BindlessImageUsage::MUTABLE_FORMAT
+		);
+		let view = bindless
+			.image()
+			.alloc_view::<_, Image2d>(&image, &BindlessImageViewCreateInfo::default())?;
+		assert_eq!(view.inner_slot().usage, image.inner_slot().usage);
+
+		// no usage is left for an sRGB view of a storage-only image
+		let image = bindless
+			.image()
+			.alloc::<Image2d>(&create_info(BindlessImageUsage::STORAGE))?;
+		let view = bindless.image().alloc_view::<_, Image2d>(&image, &srgb);
+		assert!(matches!(view, Err(ImageAllocationError::NoViewUsage { .. })));
+		Ok(())
+	}
+
+	#[test]
+	fn test_image_view_type() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		fn create_info<T: ImageType>(name: &str, array_layers: u32) -> BindlessImageCreateInfo<'_, T> {
+			BindlessImageCreateInfo {
+				format: Format::R8G8B8A8_UNORM,
+				extent: [16, 16].into(),
+				array_layers,
+				usage: BindlessImageUsage::SAMPLED,
+				name,
+				..BindlessImageCreateInfo::default()
+			}
+		}
+		fn incompatible<T>(result: Result<T, ImageAllocationError<Mock>>) -> bool {
+			matches!(result, Err(ImageAllocationError::IncompatibleViewType { .. }))
+		}
+		let image = bindless.image().alloc::<Image2d>(&create_info("image", 1))?;
+		let array = bindless.image().alloc::<Image2dArray>(&create_info("array", 6))?;
+		let cube = bindless.image().alloc::<Cubemap>(&create_info("cube", 6))?;
+		let layers = |layer_count| BindlessImageViewCreateInfo {
+			layer_count,
+			..BindlessImageViewCreateInfo::default()
+		};
+		bindless.image().alloc_view::<_, Image2d>(&array, &layers(1))?;
+		bindless.image().alloc_view::<_, Image2dArray>(&array, &layers(4))?;
+		bindless.image().alloc_view::<_, Image2dArray>(&cube, &layers(6))?;
+		bindless.image().alloc_view::<_, Image2d>(&cube, &layers(1))?;
+		bindless.image().alloc_view::<_, Cubemap>(&cube, &layers(6))?;
+
+		// cube views require cube compatible images
+		assert!(incompatible(
+			bindless.image().alloc_view::<_, Cubemap>(&array, &layers(6))
+		));
+		assert!(incompatible(
+			bindless.image().alloc_view::<_, Cubemap>(&cube, &layers(1))
+		));
+		// arrayed views require arrayed images, non-arrayed views a single layer
+		assert!(incompatible(
+			bindless.image().alloc_view::<_, Image2dArray>(&image, &layers(1))
+		));
+		assert!(incompatible(
+			bindless.image().alloc_view::<_, Image2d>(&array, &layers(2))
+		));
+		Ok(())
+	}
+}
@@ -0,0 +1,123 @@
+use crate::descriptor::{BindlessBufferUsage, BindlessImageUsage, Extent, Format};
+use rust_gpu_bindless_shaders::descriptor::DescriptorId;
+use std::fmt::{Display, Formatter};
+
+/// What to do with a non-empty [`LeakReport`] when the [`BindlessInstance`] is dropped.
+///
+/// [`BindlessInstance`]: crate::descriptor::BindlessInstance
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum LeakPolicy {
+	/// Don't check for leaks
+	Ignore,
+	/// Emit the leak report as a warning using the [`log`] crate
+	#[default]
+	Log,
+	/// Panic with the leak report, unless the thread is already panicking. Intended for tests.
+	Panic,
+}
+
+/// All slots that are still referenced, see [`Bindless::leak_report`].
+///
+/// [`Bindless::leak_report`]: crate::descriptor::Bindless::leak_report
+#[derive(Clone, Debug, Default)]
+pub struct LeakReport {
+	pub buffers: Vec<LeakedBuffer>,
+	pub images: Vec<LeakedImage>,
+	pub samplers: Vec<DescriptorId>,
+	/// slots of all custom tables
+	pub custom: Vec<DescriptorId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LeakedBuffer {
+	pub id: DescriptorId,
+	pub debug_name: String,
+	pub usage: BindlessBufferUsage,
+	/// the total size of this buffer in bytes
+	pub size: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct LeakedImage {
+	pub id: DescriptorId,
+	pub debug_name: String,
+	pub usage: BindlessImageUsage,
+	pub format: Format,
+	pub extent: Extent,
+	/// see [`ImageSlot::estimated_size`](crate::descriptor::ImageSlot::estimated_size)
+	pub estimated_size: u64,
+}
+
+impl LeakReport {
+	pub fn is_empty(&self) -> bool {
+		self.buffers.is_empty() && self.images.is_empty() && self.samplers.is_empty() && self.custom.is_empty()
+	}
+}
+
+impl Display for LeakReport {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"Leaked {} buffers, {} images, {} samplers and {} custom descriptors",
+			self.buffers.len(),
+			self.images.len(),
+			self.samplers.len(),
+			self.custom.len()
+		)?;
+		for buffer in &self.buffers {
+			write!(
+				f,
+				"\n  Buffer {:?}: {:?}, {} bytes, {:?}",
+				buffer.debug_name, buffer.id, buffer.size, buffer.usage
+			)?;
+		}
+		for image in &self.images {
+			write!(
+				f,
+				"\n  Image {:?}: {:?}, {:?} {:?}, ~{} bytes, {:?}",
+				image.debug_name, image.id, image.format, image.extent, image.estimated_size, image.usage
+			)?;
+		}
+		for sampler in &self.samplers {
+			write!(f, "\n  Sampler: {:?}", sampler)?;
+		}
+		for custom in &self.custom {
+			write!(f, "\n  Custom: {:?}", custom)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	#[test]
+	fn test_leak_report() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		bindless.set_leak_policy(LeakPolicy::Panic);
+		let leaked = bindless.buffer().alloc_slice::<u32>(&buffer_ci("leaked"), 16)?;
+		drop(bindless.buffer().alloc_slice::<u32>(&buffer_ci("dropped"), 16)?);
+
+		let report = bindless.leak_report();
+		assert_eq!(report.buffers.len(), 1);
+		assert_eq!(report.buffers[0].debug_name, "leaked");
+		assert_eq!(report.buffers[0].size, 16 * 4);
+		assert!(report.images.is_empty() && report.samplers.is_empty());
+
+		drop(leaked);
+		assert!(bindless.leak_report().is_empty());
+		Ok(())
+	}
+
+	#[test]
+	#[should_panic(expected = "Leaked 1 buffers")]
+	fn test_leak_panic() {
+		let bindless = mock_bindless();
+		bindless.set_leak_policy(LeakPolicy::Panic);
+		let leaked = bindless.buffer().alloc_slice::<u32>(&buffer_ci("leaked"), 16).unwrap();
+		drop(bindless);
+		drop(leaked);
+	}
+}
@@ -1,25 +1,39 @@
 mod bindless;
 mod buffer_metadata_cpu;
+mod buffer_pool;
 mod buffer_table;
+mod custom_table;
 mod descriptor_content;
 mod descriptor_counts;
 mod extent;
+mod format;
 mod image_table;
+mod image_view;
+mod leak_report;
 mod mutdesc;
 mod rc;
 mod sampler_table;
+mod upload_ring;
+mod weak;
 
 pub use bindless::*;
 pub use buffer_metadata_cpu::*;
+pub use buffer_pool::*;
 pub use buffer_table::*;
+pub use custom_table::*;
 pub use descriptor_content::*;
 pub use descriptor_counts::*;
 pub use extent::*;
+pub use format::*;
 pub use image_table::*;
+pub use image_view::*;
+pub use leak_report::*;
 pub use mutdesc::*;
 pub use rc::*;
 pub use rust_gpu_bindless_shaders::descriptor::*;
 pub use sampler_table::*;
+pub use upload_ring::*;
+pub use weak::*;
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub enum BindlessAllocationScheme {
@@ -1,11 +1,17 @@
 use crate::backing::range_set::DescriptorIndexIterator;
-use crate::backing::table::{DrainFlushQueue, RcTableSlot, SlotAllocationError, Table, TableInterface, TableSync};
+use crate::backing::table::{
+	DrainFlushQueue, RcTableSlot, SlotAllocationError, Table, TableInterface, TableStats, TableSync,
+};
 use crate::descriptor::descriptor_content::{DescContentCpu, DescTable};
 use crate::descriptor::rc::RCDesc;
-use crate::descriptor::{Bindless, DescriptorCounts, RCDescExt, WeakBindless};
+use crate::descriptor::{Bindless, DescriptorCounts, RCDescExt, WeakBindless, WeakDescExt};
+use crate::pipeline::CompareOp;
 use crate::platform::BindlessPlatform;
-use rust_gpu_bindless_shaders::descriptor::Sampler;
+use parking_lot::Mutex;
+use rust_gpu_bindless_shaders::descriptor::{DescriptorId, Sampler, WeakDesc};
+use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::sync::Arc;
 use thiserror::Error;
@@ -17,23 +23,40 @@ impl DescContentCpu for Sampler {
 impl<P: BindlessPlatform> DescTable<P> for SamplerTable<P> {
 	type Slot = P::Sampler;
 
-	fn get_slot(slot: &RcTableSlot) -> &Self::Slot {
-		slot.try_deref::<SamplerInterface<P>>().unwrap()
+	fn try_get_slot(slot: &RcTableSlot) -> Option<&Self::Slot> {
+		slot.try_deref::<SamplerInterface<P>>()
 	}
 }
 
 pub struct SamplerTable<P: BindlessPlatform> {
 	table: Arc<Table<SamplerInterface<P>>>,
+	/// samplers allocated by [`SamplerTableAccess::get_or_alloc`]
+	cache: Mutex<HashMap<BindlessSamplerCreateInfo, WeakDesc<Sampler>>>,
 }
 
 impl<P: BindlessPlatform> SamplerTable<P> {
-	pub fn new(table_sync: &Arc<TableSync>, counts: DescriptorCounts, bindless: WeakBindless<P>) -> Self {
+	pub fn new(
+		table_sync: &Arc<TableSync>,
+		counts: DescriptorCounts,
+		max_counts: DescriptorCounts,
+		bindless: WeakBindless<P>,
+	) -> Self {
 		Self {
 			table: table_sync
-				.register(counts.samplers, SamplerInterface { bindless })
+				.register_growable(counts.samplers, max_counts.samplers, SamplerInterface { bindless })
 				.unwrap(),
+			cache: Mutex::new(HashMap::new()),
 		}
 	}
+
+	/// Collect statistics about this table, see [`Table::stats`].
+	pub fn stats(&self) -> TableStats {
+		self.table.stats()
+	}
+
+	pub(crate) fn leaked(&self) -> Vec<DescriptorId> {
+		self.table.referenced_slots().iter().map(RcTableSlot::id).collect()
+	}
 }
 
 pub struct SamplerTableAccess<'a, P: BindlessPlatform>(pub &'a Bindless<P>);
@@ -106,6 +129,22 @@ pub enum BorderColor {
 	OpaqueWhite,
 }
 
+/// How texels are combined when filtering, instead of the usual weighted average.
+///
+/// Any mode but [`ReductionMode::WeightedAverage`] requires the device feature `samplerFilterMinmax`, which the ash
+/// platform's `ash_init_single_graphics_queue` enables if the device supports it. Allocating a sampler with such a mode
+/// on a device without it fails with [`SamplerAllocationError::ReductionUnsupported`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ReductionMode {
+	/// Texels are combined by computing a weighted average of the texels in the footprint
+	#[default]
+	WeightedAverage,
+	/// Texels are combined by taking the component-wise minimum of the texels in the footprint, e.g. for Hi-Z
+	Min,
+	/// Texels are combined by taking the component-wise maximum of the texels in the footprint
+	Max,
+}
+
 #[derive(Copy, Clone, Default, Debug)]
 pub struct BindlessSamplerCreateInfo {
 	pub mag_filter: Filter,
@@ -114,10 +153,87 @@ pub struct BindlessSamplerCreateInfo {
 	pub address_mode_u: AddressMode,
 	pub address_mode_v: AddressMode,
 	pub address_mode_w: AddressMode,
+	/// Bias added to the calculated mip level, its absolute value must not exceed the device's `maxSamplerLodBias`
+	pub mip_lod_bias: f32,
 	pub max_anisotropy: Option<f32>,
+	/// Turns this sampler into a comparison sampler, which compares a reference value against the depth of depth
+	/// images using this op, for hardware PCF shadow sampling. See [`SampleCompare`].
+	///
+	/// [`SampleCompare`]: rust_gpu_bindless_shaders::descriptor::SampleCompare
+	pub compare_op: Option<CompareOp>,
 	pub min_lod: f32,
 	pub max_lod: Option<f32>,
 	pub border_color: BorderColor,
+	/// Address texels with coordinates in texels instead of between 0 and 1. Requires both filters to be equal, a
+	/// mipmap mode of [`Filter::Nearest`], a `min_lod` and `max_lod` of 0, `address_mode_u` and `address_mode_v` to be
+	/// [`AddressMode::ClampToEdge`] or [`AddressMode::ClampToBorder`], and no anisotropy or compare op.
+	pub unnormalized_coordinates: bool,
+	/// Must be [`ReductionMode::WeightedAverage`] for comparison samplers
+	pub reduction_mode: ReductionMode,
+}
+
+impl BindlessSamplerCreateInfo {
+	/// All fields with floats replaced by their bits, so `-0.0` and `0.0` compare equal and `NaN` equals itself
+	fn key(&self) -> impl Hash + Eq {
+		let float = |f: f32| if f == 0. { 0 } else { f.to_bits() };
+		(
+			(self.mag_filter, self.min_filter, self.mipmap_mode),
+			(self.address_mode_u, self.address_mode_v, self.address_mode_w),
+			float(self.mip_lod_bias),
+			self.max_anisotropy.map(float),
+			self.compare_op,
+			float(self.min_lod),
+			self.max_lod.map(float),
+			self.border_color,
+			self.unnormalized_coordinates,
+			self.reduction_mode,
+		)
+	}
+
+	/// Validates the combination of compare op, reduction mode and unnormalized coordinates
+	pub fn validate<P: BindlessPlatform>(&self) -> Result<(), SamplerAllocationError<P>> {
+		if self.compare_op.is_some() && self.reduction_mode != ReductionMode::WeightedAverage {
+			return Err(SamplerAllocationError::CompareWithReduction {
+				reduction_mode: self.reduction_mode,
+			});
+		}
+		if self.unnormalized_coordinates {
+			let clamped = |mode: AddressMode| matches!(mode, AddressMode::ClampToEdge | AddressMode::ClampToBorder);
+			let reason = if self.min_filter != self.mag_filter {
+				Some("min_filter and mag_filter must be equal")
+			} else if self.mipmap_mode != Filter::Nearest {
+				Some("mipmap_mode must be Nearest")
+			} else if self.min_lod != 0. || self.max_lod != Some(0.) {
+				Some("min_lod and max_lod must be 0")
+			} else if !clamped(self.address_mode_u) || !clamped(self.address_mode_v) {
+				Some("address_mode_u and address_mode_v must be ClampToEdge or ClampToBorder")
+			} else if self.max_anisotropy.is_some() {
+				Some("anisotropy must be disabled")
+			} else if self.compare_op.is_some() {
+				Some("compare_op must be None")
+			} else {
+				None
+			};
+			if let Some(reason) = reason {
+				return Err(SamplerAllocationError::InvalidUnnormalizedCoordinates { reason });
+			}
+		}
+		Ok(())
+	}
+}
+
+impl PartialEq for BindlessSamplerCreateInfo {
+	fn eq(&self, other: &Self) -> bool {
+		self.key() == other.key()
+	}
+}
+
+impl Eq for BindlessSamplerCreateInfo {}
+
+impl Hash for BindlessSamplerCreateInfo {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.key().hash(state)
+	}
 }
 
 #[derive(Error)]
@@ -126,6 +242,14 @@ pub enum SamplerAllocationError<P: BindlessPlatform> {
 	Platform(#[source] P::AllocationError),
 	#[error("Slot Allocation Error: {0}")]
 	Slot(#[from] SlotAllocationError),
+	#[error("Comparison samplers must use ReductionMode::WeightedAverage instead of {reduction_mode:?}")]
+	CompareWithReduction { reduction_mode: ReductionMode },
+	#[error("Sampler with unnormalized coordinates is invalid: {reason}")]
+	InvalidUnnormalizedCoordinates { reason: &'static str },
+	#[error("Sampler mip_lod_bias of {mip_lod_bias} exceeds the device's maximum of {max}")]
+	MipLodBiasOutOfRange { mip_lod_bias: f32, max: f32 },
+	#[error("Sampler with {reduction_mode:?} requires the device feature samplerFilterMinmax")]
+	ReductionUnsupported { reduction_mode: ReductionMode },
 }
 
 impl<P: BindlessPlatform> Debug for SamplerAllocationError<P> {
@@ -153,7 +277,22 @@ impl<P: BindlessPlatform> SamplerTableAccess<'_, P> {
 		&self,
 		create_info: &BindlessSamplerCreateInfo,
 	) -> Result<RCDesc<P, Sampler>, SamplerAllocationError<P>> {
+		create_info.validate()?;
 		unsafe {
+			let max = self.0.platform.max_sampler_lod_bias();
+			if create_info.mip_lod_bias.is_nan() || create_info.mip_lod_bias.abs() > max {
+				return Err(SamplerAllocationError::MipLodBiasOutOfRange {
+					mip_lod_bias: create_info.mip_lod_bias,
+					max,
+				});
+			}
+			if create_info.reduction_mode != ReductionMode::WeightedAverage
+				&& !self.0.platform.supports_sampler_reduction()
+			{
+				return Err(SamplerAllocationError::ReductionUnsupported {
+					reduction_mode: create_info.reduction_mode,
+				});
+			}
 			let sampler = self
 				.0
 				.platform
@@ -162,6 +301,23 @@ impl<P: BindlessPlatform> SamplerTableAccess<'_, P> {
 			Ok(self.alloc_slot(sampler)?)
 		}
 	}
+
+	/// Returns a sampler for `create_info`, sharing it with all other callers requesting an equal create info. A new
+	/// sampler is only allocated if no sampler of `create_info` is alive, as the amount of samplers is very limited.
+	pub fn get_or_alloc(
+		&self,
+		create_info: &BindlessSamplerCreateInfo,
+	) -> Result<RCDesc<P, Sampler>, SamplerAllocationError<P>> {
+		let mut cache = self.cache.lock();
+		if let Some(sampler) = cache.get(create_info).and_then(|weak| weak.upgrade(self.0)) {
+			return Ok(sampler);
+		}
+		// evict the samplers that have been dropped since
+		cache.retain(|_, weak| weak.upgrade(self.0).is_some());
+		let sampler = self.alloc(create_info)?;
+		cache.insert(*create_info, sampler.to_weak());
+		Ok(sampler)
+	}
 }
 
 pub struct SamplerInterface<P: BindlessPlatform> {
@@ -185,3 +341,116 @@ impl<P: BindlessPlatform> TableInterface for SamplerInterface<P> {
 		// do nothing, flushing of descriptors is handled differently
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessInstance, DescriptorCounts};
+	use crate::platform::mock::MockCreateInfo;
+	use crate::platform::mock::test_utils::mock_bindless;
+
+	#[test]
+	fn test_sampler_cache() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let linear = BindlessSamplerCreateInfo {
+			mag_filter: Filter::Linear,
+			min_filter: Filter::Linear,
+			min_lod: 0.,
+			..BindlessSamplerCreateInfo::default()
+		};
+		let first = bindless.sampler().get_or_alloc(&linear)?;
+		let negative_zero = BindlessSamplerCreateInfo { min_lod: -0., ..linear };
+		let second = bindless.sampler().get_or_alloc(&negative_zero)?;
+		assert_eq!(first.id(), second.id());
+		let nearest = bindless.sampler().get_or_alloc(&BindlessSamplerCreateInfo::default())?;
+		assert_ne!(first.id(), nearest.id());
+		assert_eq!(bindless.stats().sampler.live_slots, 2);
+
+		// once all references are dropped, a new sampler is allocated
+		let id = first.id();
+		bindless.flush();
+		drop((first, second));
+		let third = bindless.sampler().get_or_alloc(&linear)?;
+		assert_ne!(third.id(), id);
+		Ok(())
+	}
+
+	#[test]
+	fn test_sampler_validation() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let shadow = BindlessSamplerCreateInfo {
+			mag_filter: Filter::Linear,
+			min_filter: Filter::Linear,
+			compare_op: Some(CompareOp::LessOrEqual),
+			..BindlessSamplerCreateInfo::default()
+		};
+		bindless.sampler().alloc(&shadow)?;
+		let result = bindless.sampler().alloc(&BindlessSamplerCreateInfo {
+			reduction_mode: ReductionMode::Min,
+			..shadow
+		});
+		assert!(matches!(
+			result,
+			Err(SamplerAllocationError::CompareWithReduction {
+				reduction_mode: ReductionMode::Min
+			})
+		));
+
+		let unnormalized = BindlessSamplerCreateInfo {
+			max_lod: Some(0.),
+			unnormalized_coordinates: true,
+			..BindlessSamplerCreateInfo::default()
+		};
+		bindless.sampler().alloc(&unnormalized)?;
+		let result = bindless.sampler().alloc(&BindlessSamplerCreateInfo {
+			address_mode_u: AddressMode::Repeat,
+			..unnormalized
+		});
+		assert!(matches!(
+			result,
+			Err(SamplerAllocationError::InvalidUnnormalizedCoordinates { .. })
+		));
+
+		bindless.sampler().alloc(&BindlessSamplerCreateInfo {
+			mip_lod_bias: -16.,
+			reduction_mode: ReductionMode::Max,
+			..BindlessSamplerCreateInfo::default()
+		})?;
+		for mip_lod_bias in [16.5, -17., f32::NAN] {
+			let result = bindless.sampler().alloc(&BindlessSamplerCreateInfo {
+				mip_lod_bias,
+				..BindlessSamplerCreateInfo::default()
+			});
+			assert!(matches!(
+				result,
+				Err(SamplerAllocationError::MipLodBiasOutOfRange { max: 16., .. })
+			));
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_sampler_reduction_unsupported() -> anyhow::Result<()> {
+		let bindless = unsafe {
+			BindlessInstance::new(
+				MockCreateInfo {
+					sampler_filter_minmax: false,
+					..MockCreateInfo::default()
+				},
+				DescriptorCounts::REASONABLE_DEFAULTS,
+			)
+		};
+		bindless.sampler().alloc(&BindlessSamplerCreateInfo::default())?;
+		let result = bindless.sampler().alloc(&BindlessSamplerCreateInfo {
+			reduction_mode: ReductionMode::Min,
+			..BindlessSamplerCreateInfo::default()
+		});
+		assert!(matches!(
+			result,
+			Err(SamplerAllocationError::ReductionUnsupported {
+				reduction_mode: ReductionMode::Min
+			})
+		));
+		Ok(())
+	}
+}
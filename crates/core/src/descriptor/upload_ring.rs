@@ -0,0 +1,185 @@
+use crate::descriptor::buffer_pool::BufferPool;
+use crate::descriptor::buffer_table::{
+	BindlessBufferCreateInfo, BindlessBufferUsage, BufferAllocationError, MutDescBufferExt,
+};
+use crate::descriptor::mutdesc::MutBoxDescExt;
+use crate::descriptor::{Bindless, BindlessAllocationScheme, BindlessFrame, RCDesc, RCDescExt};
+use crate::platform::BindlessPlatform;
+use parking_lot::Mutex;
+use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
+use rust_gpu_bindless_shaders::descriptor::{Buffer, TransientDesc};
+use std::mem::size_of;
+
+/// Uploads per-frame data like camera or uniform data into persistently mapped buffers, without allocating and
+/// freeing a buffer for every upload.
+///
+/// Uploads are sub-allocated from [`BufferPool`]s and handed out as [`TransientDesc`] valid for a [`BindlessFrame`].
+/// Their region is reclaimed once the frame and all executions using it have completed. Any [`StrongDesc`] written
+/// into an upload are kept alive for just as long. If all pools are exhausted, a new pool twice the size of all
+/// previous pools is allocated.
+///
+/// [`StrongDesc`]: rust_gpu_bindless_shaders::descriptor::StrongDesc
+pub struct UploadRing<P: BindlessPlatform> {
+	usage: BindlessBufferUsage,
+	allocation_scheme: BindlessAllocationScheme,
+	name: String,
+	pools: Mutex<Vec<BufferPool<P>>>,
+}
+
+impl<P: BindlessPlatform> UploadRing<P> {
+	/// Create a new `UploadRing` with an initial capacity of `size` bytes. [`BindlessBufferUsage::MAP_WRITE`] and
+	/// [`BindlessBufferUsage::STORAGE_BUFFER`] are added to the usage of `create_info`.
+	pub fn new(
+		bindless: &Bindless<P>,
+		create_info: &BindlessBufferCreateInfo,
+		size: u64,
+	) -> Result<Self, BufferAllocationError<P>> {
+		let ring = Self {
+			usage: create_info.usage | BindlessBufferUsage::MAP_WRITE | BindlessBufferUsage::STORAGE_BUFFER,
+			allocation_scheme: create_info.allocation_scheme,
+			name: create_info.name.to_string(),
+			pools: Mutex::new(Vec::new()),
+		};
+		ring.pools.lock().push(ring.alloc_pool(bindless, size)?);
+		Ok(ring)
+	}
+
+	/// The total size in bytes of all pools
+	pub fn size(&self) -> u64 {
+		self.pools.lock().iter().map(|pool| pool.size()).sum()
+	}
+
+	/// The amount of bytes not used by any upload of a frame still in flight
+	pub fn free_bytes(&self) -> u64 {
+		self.pools.lock().iter().map(|pool| pool.free_bytes()).sum()
+	}
+
+	fn alloc_pool(&self, bindless: &Bindless<P>, size: u64) -> Result<BufferPool<P>, BufferAllocationError<P>> {
+		bindless.buffer().alloc_pool(
+			&BindlessBufferCreateInfo {
+				usage: self.usage,
+				allocation_scheme: self.allocation_scheme,
+				name: &self.name,
+			},
+			size,
+		)
+	}
+
+	/// Sub-allocates an upload of `size` bytes from the first pool with enough space left, growing if all pools are
+	/// exhausted.
+	fn upload_with<R>(
+		&self,
+		frame: &BindlessFrame<P>,
+		size: u64,
+		mut f: impl FnMut(&BufferPool<P>) -> Result<R, BufferAllocationError<P>>,
+	) -> Result<R, BufferAllocationError<P>> {
+		if size == 0 {
+			return Err(BufferAllocationError::EmptySubAllocation {
+				pool: self.name.clone(),
+				name: self.name.clone(),
+			});
+		}
+		let mut pools = self.pools.lock();
+		for pool in pools.iter().rev() {
+			match f(pool) {
+				Err(BufferAllocationError::PoolOutOfMemory { .. }) => (),
+				result => return result,
+			}
+		}
+		let total = pools.iter().map(|pool| pool.size()).sum::<u64>();
+		let pool = self.alloc_pool(&frame.bindless, u64::max(total * 2, size.next_power_of_two()))?;
+		let result = f(&pool);
+		pools.push(pool);
+		result
+	}
+
+	/// Upload `data` for use within `frame`
+	pub fn upload<'a, T: BufferStruct>(
+		&self,
+		frame: &'a BindlessFrame<P>,
+		data: T,
+	) -> Result<TransientDesc<'a, Buffer<T>>, BufferAllocationError<P>> {
+		let mut data = Some(data);
+		let size = size_of::<T::Transfer>() as u64;
+		let desc: RCDesc<P, Buffer<T>> = self.upload_with(frame, size, |pool| {
+			let buffer = frame.bindless.buffer().sub_alloc_sized::<T>(pool, &self.name)?;
+			buffer.mapped_immediate().unwrap().write_data(data.take().unwrap());
+			unsafe { Ok(buffer.into_shared_unchecked()) }
+		})?;
+		// the frame keeps the upload alive after the RCDesc is dropped
+		Ok(desc.to_transient(&frame))
+	}
+
+	/// Upload all elements of `iter` for use within `frame`. Returns [`BufferAllocationError::EmptySubAllocation`] if
+	/// `iter` is empty, as a descriptor can't cover an empty buffer.
+	pub fn upload_from_iter<'a, T: BufferStruct, I>(
+		&self,
+		frame: &'a BindlessFrame<P>,
+		iter: I,
+	) -> Result<TransientDesc<'a, Buffer<[T]>>, BufferAllocationError<P>>
+	where
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let iter = iter.into_iter();
+		let len = iter.len();
+		let mut iter = Some(iter);
+		let size = size_of::<T::Transfer>() as u64 * len as u64;
+		let desc: RCDesc<P, Buffer<[T]>> = self.upload_with(frame, size, |pool| {
+			let buffer = frame.bindless.buffer().sub_alloc_slice::<T>(pool, &self.name, len)?;
+			buffer
+				.mapped_immediate()
+				.unwrap()
+				.overwrite_from_iter_exact(iter.take().unwrap());
+			unsafe { Ok(buffer.into_shared_unchecked()) }
+		})?;
+		// the frame keeps the upload alive after the RCDesc is dropped
+		Ok(desc.to_transient(&frame))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	#[test]
+	fn test_upload_ring() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let ring = UploadRing::new(&bindless, &buffer_ci("ring"), 64)?;
+		{
+			let frame = bindless.frame();
+			let first = ring.upload(&frame, 42u32)?;
+			let second = ring.upload_from_iter(&frame, 0..8u32)?;
+			assert_ne!(first.id(), second.id());
+			assert_eq!(ring.size(), 64);
+			assert_eq!(ring.free_bytes(), 64 - 4 - 32);
+
+			// exhausted, grows by twice the previous size
+			ring.upload_from_iter(&frame, 0..8u32)?;
+			assert_eq!(ring.size(), 64 + 128);
+			assert_eq!(ring.free_bytes(), 64 - 4 - 32 + 128 - 32);
+
+			// uploads are kept alive until the frame ends
+			bindless.flush();
+			bindless.table_sync().collect_now();
+			assert_eq!(ring.free_bytes(), 64 - 4 - 32 + 128 - 32);
+		}
+		bindless.table_sync().collect_now();
+		assert_eq!(ring.free_bytes(), ring.size());
+		Ok(())
+	}
+
+	#[test]
+	fn test_upload_ring_empty() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let ring = UploadRing::new(&bindless, &buffer_ci("ring"), 64)?;
+		let frame = bindless.frame();
+		let empty = ring.upload_from_iter(&frame, std::iter::empty::<u32>());
+		assert!(matches!(empty, Err(BufferAllocationError::EmptySubAllocation { .. })));
+		// the ring neither sub-allocated nor grew
+		assert_eq!(ring.size(), 64);
+		assert_eq!(ring.free_bytes(), 64);
+		Ok(())
+	}
+}
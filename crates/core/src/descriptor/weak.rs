@@ -0,0 +1,82 @@
+use crate::descriptor::{Bindless, BindlessFrame, DescContentCpu, RCDesc, RCDescExt};
+use crate::platform::BindlessPlatform;
+use rust_gpu_bindless_shaders::descriptor::{TransientDesc, WeakDesc};
+
+pub trait WeakDescExt<C: DescContentCpu> {
+	/// Upgrades a WeakDesc to an RCDesc, if the descriptor pointed to is still alive. Returns `None` if the
+	/// descriptor has been dropped, its slot has been reused or it points to something other than `C`, like a
+	/// buffer or an image of a different [`ImageType`], see [`DescContentCpu::is_slot_of`].
+	///
+	/// [`ImageType`]: rust_gpu_bindless_shaders::descriptor::ImageType
+	fn upgrade<P: BindlessPlatform>(&self, bindless: &Bindless<P>) -> Option<RCDesc<P, C>>;
+
+	/// Upgrades a WeakDesc to a TransientDesc that is valid for the `frame`, if the descriptor pointed to is still
+	/// alive. See [`Self::upgrade`].
+	fn try_to_transient<'a, P: BindlessPlatform>(&self, frame: &'a BindlessFrame<P>) -> Option<TransientDesc<'a, C>>;
+}
+
+impl<C: DescContentCpu> WeakDescExt<C> for WeakDesc<C> {
+	fn upgrade<P: BindlessPlatform>(&self, bindless: &Bindless<P>) -> Option<RCDesc<P, C>> {
+		let slot = bindless.table_sync().try_recover(self.id())?;
+		if !C::is_slot_of::<P>(&slot) {
+			return None;
+		}
+		// Safety: we just checked that the slot contains C
+		Some(unsafe { RCDesc::new(slot) })
+	}
+
+	fn try_to_transient<'a, P: BindlessPlatform>(&self, frame: &'a BindlessFrame<P>) -> Option<TransientDesc<'a, C>> {
+		// the RCDesc being alive while the frame is active ensures it stays alive until the frame ends
+		Some(self.upgrade(&frame.bindless)?.to_transient(&frame))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{
+		BindlessImageCreateInfo, BindlessImageUsage, Format, Image, Image2d, Image3d, MutBoxDescExt,
+	};
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	#[test]
+	fn test_weak_upgrade() -> anyhow::Result<()> {
+		unsafe {
+			let bindless = mock_bindless();
+			let buffer = bindless
+				.buffer()
+				.alloc_slice::<u32>(&buffer_ci("buffer"), 16)?
+				.into_shared_unchecked();
+			let weak = buffer.to_weak();
+			assert_eq!(weak.upgrade(&bindless).map(|rc| rc.id()), Some(buffer.id()));
+			{
+				let frame = bindless.frame();
+				assert_eq!(weak.try_to_transient(&frame).map(|t| t.id()), Some(buffer.id()));
+			}
+
+			let wrong_table = WeakDesc::<Image<Image2d>>::new(weak.id());
+			assert!(wrong_table.upgrade(&bindless).is_none());
+
+			// images of all types share a table, but must not be upgraded to a different image type
+			let image = bindless
+				.image()
+				.alloc::<Image3d>(&BindlessImageCreateInfo {
+					format: Format::R8G8B8A8_UNORM,
+					extent: [4, 4, 4].into(),
+					usage: BindlessImageUsage::SAMPLED,
+					name: "image",
+					..BindlessImageCreateInfo::default()
+				})?
+				.into_shared_unchecked();
+			let weak_image = image.to_weak();
+			assert_eq!(weak_image.upgrade(&bindless).map(|rc| rc.id()), Some(image.id()));
+			let wrong_type = WeakDesc::<Image<Image2d>>::new(weak_image.id());
+			assert!(wrong_type.upgrade(&bindless).is_none());
+
+			drop(buffer);
+			bindless.flush();
+			assert!(weak.upgrade(&bindless).is_none());
+			Ok(())
+		}
+	}
+}
@@ -20,7 +20,7 @@ pub trait MutImageAccessExt<P: BindlessPipelinePlatform, T: ImageType>: MutDescE
 	-> Result<MutImageAccess<'a, P, T, A>, AccessError>;
 
 	/// Access this mutable image to use it for recording. Discards the contents of this image and acts as if it were
-	/// uninitialized.
+	/// uninitialized. Views keep their contents, as discarding them would discard the contents of the entire image.
 	fn access_dont_care<'a, A: ImageAccessType>(
 		self,
 		cmd: &Recording<'a, P>,
@@ -52,18 +52,22 @@ pub struct MutImageAccess<'a, P: BindlessPipelinePlatform, T: ImageType, A: Imag
 
 impl<'a, P: BindlessPipelinePlatform, T: ImageType, A: ImageAccessType> MutImageAccess<'a, P, T, A> {
 	pub fn from_dont_care(desc: MutDesc<P, MutImage<T>>, cmd: &Recording<'a, P>) -> Result<Self, AccessError> {
-		Self::from_inner(desc, cmd, |_| ImageAccess::Undefined)
+		Self::from_inner(
+			desc,
+			cmd,
+			|slot, x| if slot.is_view() { x } else { ImageAccess::Undefined },
+		)
 	}
 
 	pub fn from(desc: MutDesc<P, MutImage<T>>, cmd: &Recording<'a, P>) -> Result<Self, AccessError> {
-		Self::from_inner(desc, cmd, |x| x)
+		Self::from_inner(desc, cmd, |_, x| x)
 	}
 
 	#[inline]
 	fn from_inner(
 		desc: MutDesc<P, MutImage<T>>,
 		cmd: &Recording<'a, P>,
-		f: impl FnOnce(ImageAccess) -> ImageAccess,
+		f: impl FnOnce(&ImageSlot<P>, ImageAccess) -> ImageAccess,
 	) -> Result<Self, AccessError> {
 		unsafe {
 			let (slot, last) = desc.into_inner();
@@ -74,7 +78,8 @@ impl<'a, P: BindlessPipelinePlatform, T: ImageType, A: ImageAccessType> MutImage
 				_phantom: PhantomData,
 				_phantom2: PhantomData,
 			};
-			this.transition_inner(f(this.inner_slot().access_lock.try_lock()?), A::IMAGE_ACCESS)?;
+			let slot = this.inner_slot();
+			this.transition_inner(f(slot, slot.access_lock.try_lock()?), A::IMAGE_ACCESS)?;
 			Ok(this)
 		}
 	}
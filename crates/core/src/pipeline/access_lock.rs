@@ -28,6 +28,15 @@ impl<A: Copy + FromPrimitive + ToPrimitive> AccessLock<A> {
 		}
 	}
 
+	/// Returns the current access without locking
+	pub fn get(&self) -> Result<A, AccessLockError> {
+		match self.atomic.load(Relaxed) {
+			Self::LOCKED => Err(AccessLockError::Locked),
+			Self::SHARED => Err(AccessLockError::Shared),
+			a => Ok(A::from_u32(a).unwrap()),
+		}
+	}
+
 	pub fn try_lock(&self) -> Result<A, AccessLockError> {
 		let mut old = self.atomic.load(Relaxed);
 		loop {
@@ -1,4 +1,5 @@
 use crate::descriptor::Bindless;
+use crate::pipeline::pipeline_error::{PipelineCreationError, check_id_layout};
 use crate::platform::BindlessPipelinePlatform;
 use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
 use rust_gpu_bindless_shaders::shader::BindlessShader;
@@ -7,13 +8,17 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 impl<P: BindlessPipelinePlatform> Bindless<P> {
+	/// Fails with [`PipelineCreationError::IdLayoutMismatch`] if a shader was compiled with a different id layout.
 	pub fn create_compute_pipeline<T: BufferStruct>(
 		&self,
 		compute_shader: &impl BindlessShader<ShaderType = ComputeShader, ParamConstant = T>,
-	) -> Result<BindlessComputePipeline<P, T>, P::PipelineCreationError> {
+	) -> Result<BindlessComputePipeline<P, T>, PipelineCreationError<P>> {
+		check_id_layout::<P, _>(compute_shader)?;
 		unsafe {
 			Ok(BindlessComputePipeline {
-				pipeline: Arc::new(P::create_compute_pipeline(self, compute_shader)?),
+				pipeline: Arc::new(
+					P::create_compute_pipeline(self, compute_shader).map_err(PipelineCreationError::Platform)?,
+				),
 				_phantom: PhantomData,
 			})
 		}
@@ -31,3 +36,47 @@ impl<P: BindlessPipelinePlatform, T: BufferStruct> BindlessComputePipeline<P, T>
 		&self.pipeline
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::platform::mock::test_utils::mock_bindless;
+	use rust_gpu_bindless_shaders::descriptor::ID_LAYOUT;
+	use rust_gpu_bindless_shaders::shader::SpirvBinary;
+
+	struct TestShader(SpirvBinary<'static>);
+
+	impl BindlessShader for TestShader {
+		type ShaderType = ComputeShader;
+		type ParamConstant = u32;
+
+		fn spirv_binary(&self) -> &SpirvBinary<'_> {
+			&self.0
+		}
+	}
+
+	fn test_shader(id_layout: [u32; 3]) -> TestShader {
+		TestShader(SpirvBinary {
+			binary: &[],
+			entry_point_name: c"test_shader",
+			id_layout,
+		})
+	}
+
+	#[test]
+	fn test_id_layout() {
+		let bindless = mock_bindless();
+		bindless.create_compute_pipeline(&test_shader(ID_LAYOUT)).unwrap();
+	}
+
+	#[test]
+	fn test_id_layout_mismatch() {
+		let bindless = mock_bindless();
+		let [index, ty, version] = ID_LAYOUT;
+		let result = bindless.create_compute_pipeline(&test_shader([index - 1, ty + 1, version]));
+		assert!(matches!(
+			result,
+			Err(PipelineCreationError::IdLayoutMismatch { id_layout, .. }) if id_layout == [index - 1, ty + 1, version]
+		));
+	}
+}
@@ -0,0 +1,628 @@
+use crate::descriptor::{BufferSlot, Extent, Filter, Format, FormatAspects, ImageSlot, NumericType, SampleCount};
+use crate::pipeline::recording::CopyError;
+use crate::platform::BindlessPlatform;
+use glam::UVec3;
+
+/// A range of elements to copy from one slice buffer to another
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BufferCopyRegion {
+	/// The first element to read from the source buffer
+	pub src_offset: usize,
+	/// The first element to write to in the destination buffer
+	pub dst_offset: usize,
+	/// The amount of elements to copy
+	pub len: usize,
+}
+
+/// A box of texels within a single mip level of a range of array layers. Mips and layers are relative to the image
+/// (view) they are used with.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ImageRegion {
+	pub mip_level: u32,
+	pub base_layer: u32,
+	pub layer_count: u32,
+	/// The offset in texels into the mip level
+	pub offset: UVec3,
+	/// The extent in texels of the region
+	pub extent: Extent,
+}
+
+impl ImageRegion {
+	/// The entire mip level `mip_level` of the first `layer_count` array layers of an image of size `image_extent`
+	pub fn whole_mip(image_extent: Extent, mip_level: u32, layer_count: u32) -> Self {
+		Self {
+			mip_level,
+			base_layer: 0,
+			layer_count,
+			offset: UVec3::ZERO,
+			extent: image_extent.mip(mip_level),
+		}
+	}
+}
+
+/// A region to copy between a buffer and an image
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BufferImageCopyRegion {
+	/// The offset in bytes into the buffer where the image data starts
+	pub buffer_offset: u64,
+	/// The length in texels of a row of the image data in the buffer, or 0 if rows are tightly packed
+	pub buffer_row_length: u32,
+	/// The height in texels of a single layer or depth slice of the image data in the buffer, or 0 if they are
+	/// tightly packed
+	pub buffer_image_height: u32,
+	pub image: ImageRegion,
+	/// The single aspect of the image to copy, or empty to copy the only aspect of the image. Must be set for images
+	/// with combined depth/stencil or multi-planar formats, where every aspect is copied separately. The `image`
+	/// region of a subsampled chroma plane is measured in texels of that plane.
+	pub aspect: FormatAspects,
+}
+
+impl BufferImageCopyRegion {
+	/// Copy the tightly packed image data at the start of the buffer to or from `image`
+	pub fn tightly_packed(image: ImageRegion) -> Self {
+		Self {
+			buffer_offset: 0,
+			buffer_row_length: 0,
+			buffer_image_height: 0,
+			image,
+			aspect: FormatAspects::empty(),
+		}
+	}
+
+	/// The aspect of an image of `format` copied by this region
+	pub fn image_aspect(&self, format: Format) -> FormatAspects {
+		if self.aspect.is_empty() {
+			format.aspects()
+		} else {
+			self.aspect
+		}
+	}
+
+	/// The size in bytes of the buffer data read or written by this region when copying to or from an image of
+	/// `format`, starting at [`Self::buffer_offset`]. Returns `None` if the aspect of this region can't be copied, see
+	/// [`Format::aspect_block_size`].
+	pub fn buffer_size(&self, format: Format) -> Option<u64> {
+		let block_size = format.aspect_block_size(self.image_aspect(format))? as u64;
+		let extent = self.image.extent;
+		let row_length = if self.buffer_row_length == 0 {
+			extent.width
+		} else {
+			self.buffer_row_length
+		};
+		let image_height = if self.buffer_image_height == 0 {
+			extent.height
+		} else {
+			self.buffer_image_height
+		};
+
+		// the offset of the last block copied plus its size, rows and slices are measured in blocks
+		let [block_width, block_height] = format.block_extent();
+		let blocks = |texels: u32, block: u32| texels.div_ceil(block) as u64;
+		let row_blocks = blocks(row_length, block_width);
+		let slice_rows = blocks(image_height, block_height);
+		let slices = extent.depth as u64 * self.image.layer_count as u64;
+		Some(
+			block_size
+				* (slices.saturating_sub(1) * slice_rows * row_blocks
+					+ blocks(extent.height, block_height).saturating_sub(1) * row_blocks
+					+ blocks(extent.width, block_width)),
+		)
+	}
+}
+
+/// A region to copy or blit from one image to another. Copies require both regions to have the same extent, while
+/// blits scale the `src` region to the `dst` region.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ImageCopyRegion {
+	pub src: ImageRegion,
+	pub dst: ImageRegion,
+}
+
+pub(crate) fn validate_buffer_range<P: BindlessPlatform>(
+	buffer: &BufferSlot<P>,
+	offset: usize,
+	len: usize,
+) -> Result<(), CopyError> {
+	if offset.checked_add(len).is_none_or(|end| end > buffer.len) {
+		return Err(CopyError::BufferOutOfBounds {
+			name: buffer.debug_name.clone(),
+			offset: offset as u64,
+			size: len as u64,
+			buffer_size: buffer.len as u64,
+		});
+	}
+	Ok(())
+}
+
+pub(crate) fn validate_image_region<P: BindlessPlatform>(
+	image: &ImageSlot<P>,
+	region: &ImageRegion,
+	aspect: FormatAspects,
+) -> Result<(), CopyError> {
+	let name = || image.debug_name.clone();
+	if region.mip_level >= image.mip_levels {
+		return Err(CopyError::MipOutOfBounds {
+			name: name(),
+			mip: region.mip_level,
+			mip_levels: image.mip_levels,
+		});
+	}
+	let layers = region.base_layer..region.base_layer.saturating_add(region.layer_count);
+	if layers.is_empty() || layers.end > image.array_layers {
+		return Err(CopyError::LayersOutOfBounds {
+			name: name(),
+			layers,
+			array_layers: image.array_layers,
+		});
+	}
+
+	let mut mip_extent = image.extent.mip(region.mip_level);
+	if aspect.intersects(FormatAspects::PLANE_1.union(FormatAspects::PLANE_2)) {
+		let [sub_width, sub_height] = image.format.plane_subsampling();
+		mip_extent.width = mip_extent.width.div_ceil(sub_width);
+		mip_extent.height = mip_extent.height.div_ceil(sub_height);
+	}
+	let (offset, extent) = (region.offset, region.extent);
+	let fits = |offset: u32, size: u32, max: u32| size != 0 && offset.checked_add(size).is_some_and(|end| end <= max);
+	if !fits(offset.x, extent.width, mip_extent.width)
+		|| !fits(offset.y, extent.height, mip_extent.height)
+		|| !fits(offset.z, extent.depth, mip_extent.depth)
+	{
+		return Err(CopyError::RegionOutOfBounds {
+			name: name(),
+			offset,
+			extent,
+			mip_extent,
+		});
+	}
+
+	// regions of block-compressed images must cover whole blocks, except at the edges of the image
+	let [block_width, block_height] = image.format.block_extent();
+	let aligned = |offset: u32, size: u32, max: u32, block: u32| {
+		offset.is_multiple_of(block) && (size.is_multiple_of(block) || offset + size == max)
+	};
+	if !aligned(offset.x, extent.width, mip_extent.width, block_width)
+		|| !aligned(offset.y, extent.height, mip_extent.height, block_height)
+	{
+		return Err(CopyError::UnalignedRegion {
+			name: name(),
+			offset,
+			extent,
+			block_extent: [block_width, block_height],
+		});
+	}
+	Ok(())
+}
+
+pub(crate) fn validate_buffer_image_region<P: BindlessPlatform>(
+	buffer: &BufferSlot<P>,
+	image: &ImageSlot<P>,
+	region: &BufferImageCopyRegion,
+) -> Result<(), CopyError> {
+	let aspect = region.image_aspect(image.format);
+	let Some(block_size) = image.format.aspect_block_size(aspect) else {
+		return Err(CopyError::InvalidAspect {
+			name: image.debug_name.clone(),
+			format: image.format,
+			aspect,
+		});
+	};
+	validate_image_region(image, &region.image, aspect)?;
+	let extent = region.image.extent;
+	let row_length = if region.buffer_row_length == 0 {
+		extent.width
+	} else {
+		region.buffer_row_length
+	};
+	let image_height = if region.buffer_image_height == 0 {
+		extent.height
+	} else {
+		region.buffer_image_height
+	};
+	if row_length < extent.width || image_height < extent.height {
+		return Err(CopyError::InvalidBufferLayout {
+			name: buffer.debug_name.clone(),
+			row_length: region.buffer_row_length,
+			image_height: region.buffer_image_height,
+			extent,
+		});
+	}
+
+	// rows and slices of block-compressed data must consist of whole blocks
+	let [block_width, block_height] = image.format.block_extent();
+	if !region.buffer_row_length.is_multiple_of(block_width) || !region.buffer_image_height.is_multiple_of(block_height)
+	{
+		return Err(CopyError::UnalignedBufferLayout {
+			name: buffer.debug_name.clone(),
+			row_length: region.buffer_row_length,
+			image_height: region.buffer_image_height,
+			block_extent: [block_width, block_height],
+		});
+	}
+
+	// the offset of sub-allocated buffers is part of the offset seen by the device
+	let alignment = if image.format.is_depth_stencil() {
+		4
+	} else {
+		block_size as u64
+	};
+	let device_offset = buffer.offset + region.buffer_offset;
+	if !device_offset.is_multiple_of(alignment) {
+		return Err(CopyError::UnalignedBufferOffset {
+			name: buffer.debug_name.clone(),
+			offset: device_offset,
+			alignment,
+		});
+	}
+
+	// the aspect was validated above
+	let size = region.buffer_size(image.format).unwrap();
+	if region
+		.buffer_offset
+		.checked_add(size)
+		.is_none_or(|end| end > buffer.size)
+	{
+		return Err(CopyError::BufferOutOfBounds {
+			name: buffer.debug_name.clone(),
+			offset: region.buffer_offset,
+			size,
+			buffer_size: buffer.size,
+		});
+	}
+	Ok(())
+}
+
+pub(crate) fn validate_image_copy_region<P: BindlessPlatform>(
+	src: &ImageSlot<P>,
+	dst: &ImageSlot<P>,
+	region: &ImageCopyRegion,
+	blit: Option<Filter>,
+) -> Result<(), CopyError> {
+	let depth_stencil = src.format.is_depth_stencil() || dst.format.is_depth_stencil();
+	if blit == Some(Filter::Linear) && depth_stencil {
+		return Err(CopyError::LinearDepthStencilBlit {
+			src: src.debug_name.clone(),
+			dst: dst.debug_name.clone(),
+		});
+	}
+	let blit = blit.is_some();
+	validate_image_region(src, &region.src, src.format.aspects())?;
+	validate_image_region(dst, &region.dst, dst.format.aspects())?;
+	if region.src.layer_count != region.dst.layer_count || (!blit && region.src.extent != region.dst.extent) {
+		return Err(CopyError::MismatchedRegions {
+			src: src.debug_name.clone(),
+			dst: dst.debug_name.clone(),
+			src_region: region.src,
+			dst_region: region.dst,
+		});
+	}
+	if src.samples != dst.samples || (blit && src.samples != SampleCount::Sample1) {
+		return Err(CopyError::MismatchedSamples {
+			src: src.debug_name.clone(),
+			src_samples: src.samples,
+			dst: dst.debug_name.clone(),
+			dst_samples: dst.samples,
+		});
+	}
+	let compatible = if blit {
+		// integer formats can only be blitted to integer formats of the same signedness, depth/stencil formats only to
+		// the same format
+		let integer = |format: Format| match format.numeric_type() {
+			Some(NumericType::UInt) => Some(false),
+			Some(NumericType::SInt) => Some(true),
+			_ => None,
+		};
+		src.format.block_extent() == [1, 1]
+			&& dst.format.block_extent() == [1, 1]
+			&& integer(src.format) == integer(dst.format)
+			&& (src.format == dst.format || (src.format.is_color() && dst.format.is_color()))
+	} else if depth_stencil {
+		src.format == dst.format
+	} else {
+		src.format.block_size() == dst.format.block_size() && src.format.block_extent() == dst.format.block_extent()
+	};
+	if !compatible {
+		return Err(CopyError::IncompatibleFormats {
+			src: src.debug_name.clone(),
+			src_format: src.format,
+			dst: dst.debug_name.clone(),
+			dst_format: dst.format,
+		});
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessImageCreateInfo, BindlessImageUsage, Image2d, Image2dArray, MutDescExt};
+	use crate::pipeline::{
+		CopyError, MutBufferAccessExt, MutImageAccessExt, RecordingError, TransferRead, TransferWrite,
+	};
+	use crate::platform::mock::Mock;
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	fn image_ci(format: Format, name: &str) -> BindlessImageCreateInfo<'_, Image2d> {
+		BindlessImageCreateInfo {
+			format,
+			extent: [8, 8].into(),
+			usage: BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+			name,
+			..BindlessImageCreateInfo::default()
+		}
+	}
+
+	fn unwrap_copy_error(result: Result<(), RecordingError<Mock>>) -> CopyError {
+		match result {
+			Err(RecordingError::CopyError(err)) => err,
+			result => panic!("expected CopyError, got {result:?}"),
+		}
+	}
+
+	#[test]
+	fn test_blit_formats() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let blit_filter =
+			|src: Format, dst: Format, filter: Filter| -> anyhow::Result<Result<(), RecordingError<Mock>>> {
+				let src = bindless.image().alloc(&image_ci(src, "src"))?;
+				let dst = bindless.image().alloc(&image_ci(dst, "dst"))?;
+				let region = ImageRegion::whole_mip([8, 8].into(), 0, 1);
+				Ok(bindless.execute(|cmd| {
+					let src = src.access::<TransferRead>(cmd)?;
+					let dst = dst.access::<TransferWrite>(cmd)?;
+					cmd.blit_image(
+						&src,
+						&dst,
+						&ImageCopyRegion {
+							src: region,
+							dst: region,
+						},
+						filter,
+					)
+				}))
+			};
+		let blit = |src: Format, dst: Format| blit_filter(src, dst, Filter::Nearest);
+		let incompatible = |result| matches!(unwrap_copy_error(result), CopyError::IncompatibleFormats { .. });
+
+		blit(Format::R8G8B8A8_UNORM, Format::R16G16B16A16_SFLOAT)??;
+		blit(Format::R8G8B8A8_UINT, Format::R16G16B16A16_UINT)??;
+		blit(Format::D32_SFLOAT, Format::D32_SFLOAT)??;
+		assert!(incompatible(blit(Format::D32_SFLOAT, Format::R32_SFLOAT)?));
+		assert!(incompatible(blit(Format::R32_SFLOAT, Format::D32_SFLOAT)?));
+		assert!(incompatible(blit(Format::D32_SFLOAT, Format::D16_UNORM)?));
+		assert!(incompatible(blit(Format::R8G8B8A8_UINT, Format::R8G8B8A8_UNORM)?));
+		assert!(incompatible(blit(Format::R8G8B8A8_SNORM, Format::R8G8B8A8_SINT)?));
+		assert!(incompatible(blit(Format::R8G8B8A8_UINT, Format::R8G8B8A8_SINT)?));
+
+		// depth/stencil formats can only be blitted with nearest filtering
+		blit_filter(Format::R8G8B8A8_UNORM, Format::R8G8B8A8_UNORM, Filter::Linear)??;
+		let result = blit_filter(Format::D32_SFLOAT, Format::D32_SFLOAT, Filter::Linear)?;
+		assert!(matches!(
+			unwrap_copy_error(result),
+			CopyError::LinearDepthStencilBlit { .. }
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_copy_formats() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let copy = |src: Format, dst: Format| -> anyhow::Result<Result<(), RecordingError<Mock>>> {
+			let src = bindless.image().alloc(&image_ci(src, "src"))?;
+			let dst = bindless.image().alloc(&image_ci(dst, "dst"))?;
+			let region = ImageRegion::whole_mip([8, 8].into(), 0, 1);
+			Ok(bindless.execute(|cmd| {
+				let src = src.access::<TransferRead>(cmd)?;
+				let dst = dst.access::<TransferWrite>(cmd)?;
+				cmd.copy_image_to_image(
+					&src,
+					&dst,
+					&ImageCopyRegion {
+						src: region,
+						dst: region,
+					},
+				)
+			}))
+		};
+		let incompatible = |result| matches!(unwrap_copy_error(result), CopyError::IncompatibleFormats { .. });
+
+		// color formats only need the same block size, depth/stencil formats must match exactly
+		copy(Format::R32_SFLOAT, Format::R8G8B8A8_UNORM)??;
+		copy(Format::D32_SFLOAT, Format::D32_SFLOAT)??;
+		assert!(incompatible(copy(Format::D32_SFLOAT, Format::R32_SFLOAT)?));
+		assert!(incompatible(copy(Format::R16_UNORM, Format::D16_UNORM)?));
+		Ok(())
+	}
+
+	#[test]
+	fn test_buffer_image_copy_rules() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let copy =
+			|format: Format, region: BufferImageCopyRegion| -> anyhow::Result<Result<(), RecordingError<Mock>>> {
+				let buffer = bindless.buffer().alloc_from_iter(&buffer_ci("buffer"), [0u32; 256])?;
+				let image = bindless.image().alloc(&image_ci(format, "image"))?;
+				Ok(bindless.execute(|cmd| {
+					let buffer = buffer.access::<TransferRead>(cmd)?;
+					let image = image.access::<TransferWrite>(cmd)?;
+					cmd.copy_buffer_to_image_region(&buffer, &image, &region)
+				}))
+			};
+		let whole = BufferImageCopyRegion::tightly_packed(ImageRegion::whole_mip([8, 8].into(), 0, 1));
+
+		// row length and image height of block-compressed formats must be whole blocks
+		copy(
+			Format::BC1_RGB_UNORM_BLOCK,
+			BufferImageCopyRegion {
+				buffer_row_length: 12,
+				buffer_image_height: 8,
+				..whole
+			},
+		)??;
+		let result = copy(
+			Format::BC1_RGB_UNORM_BLOCK,
+			BufferImageCopyRegion {
+				buffer_row_length: 10,
+				..whole
+			},
+		)?;
+		assert!(matches!(
+			unwrap_copy_error(result),
+			CopyError::UnalignedBufferLayout { row_length: 10, .. }
+		));
+		let result = copy(
+			Format::BC1_RGB_UNORM_BLOCK,
+			BufferImageCopyRegion {
+				buffer_image_height: 9,
+				..whole
+			},
+		)?;
+		assert!(matches!(
+			unwrap_copy_error(result),
+			CopyError::UnalignedBufferLayout { image_height: 9, .. }
+		));
+
+		// depth/stencil data must be 4 byte aligned within the buffer
+		copy(
+			Format::D16_UNORM,
+			BufferImageCopyRegion {
+				buffer_offset: 4,
+				..whole
+			},
+		)??;
+		let result = copy(
+			Format::D16_UNORM,
+			BufferImageCopyRegion {
+				buffer_offset: 2,
+				..whole
+			},
+		)?;
+		assert!(matches!(
+			unwrap_copy_error(result),
+			CopyError::UnalignedBufferOffset { alignment: 4, .. }
+		));
+
+		// the buffer offset must be aligned including the offset of a sub-allocation
+		let copy_sub_allocated = |buffer_offset| -> anyhow::Result<Result<(), RecordingError<Mock>>> {
+			let pool = bindless.buffer().alloc_pool(&buffer_ci("pool"), 512)?;
+			let _first = bindless.buffer().sub_alloc_slice::<u32>(&pool, "first", 4)?;
+			let buffer = bindless.buffer().sub_alloc_slice::<u32>(&pool, "buffer", 64)?;
+			assert_eq!(buffer.inner_slot().offset, 16);
+			let image = bindless.image().alloc(&image_ci(Format::R32G32B32_SFLOAT, "image"))?;
+			Ok(bindless.execute(|cmd| {
+				let buffer = buffer.access::<TransferRead>(cmd)?;
+				let image = image.access::<TransferWrite>(cmd)?;
+				cmd.copy_buffer_to_image_region(
+					&buffer,
+					&image,
+					&BufferImageCopyRegion {
+						buffer_offset,
+						..BufferImageCopyRegion::tightly_packed(ImageRegion::whole_mip([2, 2].into(), 0, 1))
+					},
+				)
+			}))
+		};
+		copy_sub_allocated(8)??;
+		assert!(matches!(
+			unwrap_copy_error(copy_sub_allocated(0)?),
+			CopyError::UnalignedBufferOffset {
+				offset: 16,
+				alignment: 12,
+				..
+			}
+		));
+
+		// combined depth/stencil and multi-planar formats require a single aspect to be selected
+		copy(
+			Format::D24_UNORM_S8_UINT,
+			BufferImageCopyRegion {
+				aspect: FormatAspects::STENCIL,
+				..whole
+			},
+		)??;
+		let result = copy(Format::D24_UNORM_S8_UINT, whole)?;
+		assert!(matches!(unwrap_copy_error(result), CopyError::InvalidAspect { .. }));
+		let result = copy(
+			Format::R8G8B8A8_UNORM,
+			BufferImageCopyRegion {
+				aspect: FormatAspects::DEPTH,
+				..whole
+			},
+		)?;
+		assert!(matches!(unwrap_copy_error(result), CopyError::InvalidAspect { .. }));
+		let result = copy(Format::G8_B8R8_2PLANE_420_UNORM, whole)?;
+		assert!(matches!(unwrap_copy_error(result), CopyError::InvalidAspect { .. }));
+		let chroma = BufferImageCopyRegion {
+			aspect: FormatAspects::PLANE_1,
+			..BufferImageCopyRegion::tightly_packed(ImageRegion::whole_mip([4, 4].into(), 0, 1))
+		};
+		copy(Format::G8_B8R8_2PLANE_420_UNORM, chroma)??;
+		let result = copy(
+			Format::G8_B8R8_2PLANE_420_UNORM,
+			BufferImageCopyRegion {
+				aspect: FormatAspects::PLANE_1,
+				..whole
+			},
+		)?;
+		assert!(matches!(unwrap_copy_error(result), CopyError::RegionOutOfBounds { .. }));
+		Ok(())
+	}
+
+	#[test]
+	fn test_buffer_image_copy_bounds() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let copy = |region: BufferImageCopyRegion| -> anyhow::Result<Result<(), RecordingError<Mock>>> {
+			let buffer = bindless.buffer().alloc_from_iter(&buffer_ci("buffer"), 0..16u32)?;
+			let image = bindless.image().alloc::<Image2dArray>(&BindlessImageCreateInfo {
+				format: Format::R8G8B8A8_UNORM,
+				extent: [8, 8].into(),
+				mip_levels: 2,
+				array_layers: 2,
+				usage: BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+				name: "image",
+				..BindlessImageCreateInfo::default()
+			})?;
+			Ok(bindless.execute(|cmd| {
+				let buffer = buffer.access::<TransferRead>(cmd)?;
+				let image = image.access::<TransferWrite>(cmd)?;
+				cmd.copy_buffer_to_image_region(&buffer, &image, &region)
+			}))
+		};
+		// a 2x2 region of mip 1 of layer 1, with rows 4 texels apart in the buffer
+		let region = BufferImageCopyRegion {
+			buffer_offset: 4,
+			buffer_row_length: 4,
+			buffer_image_height: 0,
+			image: ImageRegion {
+				mip_level: 1,
+				base_layer: 1,
+				layer_count: 1,
+				offset: UVec3::new(2, 2, 0),
+				extent: [2, 2].into(),
+			},
+			aspect: FormatAspects::empty(),
+		};
+		copy(region)??;
+
+		let buffer_too_small = BufferImageCopyRegion {
+			buffer_offset: 4 * 12,
+			..region
+		};
+		assert!(matches!(
+			unwrap_copy_error(copy(buffer_too_small)?),
+			CopyError::BufferOutOfBounds { .. }
+		));
+		let mut region_too_large = region;
+		region_too_large.image.extent = [3, 2].into();
+		assert!(matches!(
+			unwrap_copy_error(copy(region_too_large)?),
+			CopyError::RegionOutOfBounds { .. }
+		));
+		let mut layer_too_large = region;
+		layer_too_large.image.base_layer = 2;
+		assert!(matches!(
+			unwrap_copy_error(copy(layer_too_large)?),
+			CopyError::LayersOutOfBounds { .. }
+		));
+		Ok(())
+	}
+}
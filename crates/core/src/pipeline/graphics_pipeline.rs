@@ -1,4 +1,6 @@
-use crate::descriptor::Bindless;
+use crate::descriptor::{Bindless, SampleCount};
+use crate::pipeline::pipeline_error::{PipelineCreationError, check_id_layout};
+use crate::pipeline::pipeline_state::{ColorBlendState, DepthStencilState, InputAssemblyState, RasterizationState};
 use crate::pipeline::rendering::RenderPassFormat;
 use crate::platform::BindlessPipelinePlatform;
 use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
@@ -7,45 +9,36 @@ use rust_gpu_bindless_shaders::shader_type::{FragmentShader, VertexShader};
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-pub type PipelineInputAssemblyStateCreateInfo<'a> = ash::vk::PipelineInputAssemblyStateCreateInfo<'a>;
-pub type PipelineRasterizationStateCreateInfo<'a> = ash::vk::PipelineRasterizationStateCreateInfo<'a>;
-pub type PipelineDepthStencilStateCreateInfo<'a> = ash::vk::PipelineDepthStencilStateCreateInfo<'a>;
-pub type PipelineColorBlendStateCreateInfo<'a> = ash::vk::PipelineColorBlendStateCreateInfo<'a>;
-
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct GraphicsPipelineCreateInfo<'a> {
-	// pub ia_topology: PrimitiveTopology,
-	// pub ia_primitive_restart_enable: bool,
-	// pub raster_polygon_mode: PolygonMode,
-	// pub raster_cull_mode: CullModeFlags,
-	// pub raster_front_face: FrontFace,
-
 	// pub vertex_input_state: PipelineVertexInputStateCreateInfo<'a>,
-	pub input_assembly_state: PipelineInputAssemblyStateCreateInfo<'a>,
+	pub input_assembly_state: InputAssemblyState,
 	// pub tessellation_state: Option<PipelineTessellationStateCreateInfo<'a>>,
 	// pub viewport_state: PipelineViewportStateCreateInfo<'a>,
-	pub rasterization_state: PipelineRasterizationStateCreateInfo<'a>,
-	// pub multisample_state: PipelineMultisampleStateCreateInfo<'a>,
-	pub depth_stencil_state: PipelineDepthStencilStateCreateInfo<'a>,
-	pub color_blend_state: PipelineColorBlendStateCreateInfo<'a>,
+	pub rasterization_state: RasterizationState,
+	// multisample state is derived from RenderPassFormat::samples
+	pub depth_stencil_state: DepthStencilState,
+	pub color_blend_state: ColorBlendState<'a>,
 }
 
 impl<P: BindlessPipelinePlatform> Bindless<P> {
+	/// Fails with [`PipelineCreationError::IdLayoutMismatch`] if a shader was compiled with a different id layout.
 	pub fn create_graphics_pipeline<T: BufferStruct>(
 		&self,
 		render_pass: &RenderPassFormat,
 		create_info: &GraphicsPipelineCreateInfo,
 		vertex_shader: &impl BindlessShader<ShaderType = VertexShader, ParamConstant = T>,
 		fragment_shader: &impl BindlessShader<ShaderType = FragmentShader, ParamConstant = T>,
-	) -> Result<BindlessGraphicsPipeline<P, T>, P::PipelineCreationError> {
+	) -> Result<BindlessGraphicsPipeline<P, T>, PipelineCreationError<P>> {
+		check_id_layout::<P, _>(vertex_shader)?;
+		check_id_layout::<P, _>(fragment_shader)?;
 		unsafe {
 			Ok(BindlessGraphicsPipeline {
-				pipeline: Arc::new(P::create_graphics_pipeline(
-					self,
-					render_pass,
-					create_info,
-					vertex_shader,
-					fragment_shader,
-				)?),
+				pipeline: Arc::new(
+					P::create_graphics_pipeline(self, render_pass, create_info, vertex_shader, fragment_shader)
+						.map_err(PipelineCreationError::Platform)?,
+				),
+				samples: render_pass.samples,
 				_phantom: PhantomData,
 			})
 		}
@@ -55,6 +48,7 @@ impl<P: BindlessPipelinePlatform> Bindless<P> {
 #[derive(Debug, Clone)]
 pub struct BindlessGraphicsPipeline<P: BindlessPipelinePlatform, T: BufferStruct> {
 	pipeline: Arc<P::GraphicsPipeline>,
+	samples: SampleCount,
 	_phantom: PhantomData<T>,
 }
 
@@ -62,4 +56,8 @@ impl<P: BindlessPipelinePlatform, T: BufferStruct> BindlessGraphicsPipeline<P, T
 	pub fn inner(&self) -> &Arc<P::GraphicsPipeline> {
 		&self.pipeline
 	}
+	/// The sample count of the [`RenderPassFormat`] this pipeline was created for
+	pub fn samples(&self) -> SampleCount {
+		self.samples
+	}
 }
@@ -0,0 +1,192 @@
+use crate::descriptor::{BindlessBufferCreateInfo, BindlessBufferUsage, MutDesc, MutDescExt};
+use crate::pipeline::access_buffer::MutBufferAccessExt;
+use crate::pipeline::access_error::AccessError;
+use crate::pipeline::access_type::{TransferRead, TransferWrite};
+use crate::pipeline::copy_region::BufferCopyRegion;
+use crate::pipeline::recording::{HasResourceContext, Recording, RecordingError};
+use crate::platform::BindlessPipelinePlatform;
+use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
+use rust_gpu_bindless_shaders::descriptor::MutBuffer;
+
+pub trait MutDescBufferResizeExt<P: BindlessPipelinePlatform, T: BufferStruct> {
+	/// Allocates a new buffer of `new_len` elements and records a copy of the contents of this buffer into it. When
+	/// growing, the elements past the old length are uninitialized. When shrinking, only the first `new_len` elements
+	/// are copied. This buffer is retired by the GC once the recording completes. Returns this buffer unchanged if
+	/// `new_len` equals its current length.
+	///
+	/// The new buffer shares the usage, allocation scheme and name of this buffer. The usage must include
+	/// [`BindlessBufferUsage::TRANSFER_SRC`] and [`BindlessBufferUsage::TRANSFER_DST`], which is checked before
+	/// allocating the new buffer.
+	fn resize(self, cmd: &mut Recording<'_, P>, new_len: usize) -> Result<Self, RecordingError<P>>
+	where
+		Self: Sized;
+}
+
+impl<P: BindlessPipelinePlatform, T: BufferStruct> MutDescBufferResizeExt<P, T> for MutDesc<P, MutBuffer<[T]>> {
+	fn resize(self, cmd: &mut Recording<'_, P>, new_len: usize) -> Result<Self, RecordingError<P>> {
+		let old = self.inner_slot();
+		if new_len == old.len {
+			return Ok(self);
+		}
+		let required = BindlessBufferUsage::TRANSFER_SRC | BindlessBufferUsage::TRANSFER_DST;
+		if !old.usage.contains(required) {
+			return Err(AccessError::MissingBufferUsage {
+				name: old.debug_name().to_string(),
+				usage: old.usage,
+				missing_usage: required,
+			}
+			.into());
+		}
+		let new = cmd.bindless().buffer().alloc_slice::<T>(
+			&BindlessBufferCreateInfo {
+				usage: old.usage,
+				allocation_scheme: old.allocation_scheme,
+				name: old.debug_name(),
+			},
+			new_len,
+		)?;
+		// the copied contents may contain StrongDesc, which must be kept alive
+		*new.inner_slot().strong_refs.lock() = old.strong_refs.lock().clone();
+		let len = usize::min(old.len, new_len);
+
+		let src = self.access::<TransferRead>(cmd)?;
+		let dst = new.access::<TransferWrite>(cmd)?;
+		cmd.copy_buffer_to_buffer_region(
+			&src,
+			&dst,
+			&BufferCopyRegion {
+				src_offset: 0,
+				dst_offset: 0,
+				len,
+			},
+		)?;
+		Ok(dst.into_desc())
+	}
+}
+
+/// A buffer of `T`s which grows its capacity by doubling, preserving its contents. See
+/// [`MutDescBufferResizeExt::resize`].
+pub struct GrowableBuffer<P: BindlessPipelinePlatform, T: BufferStruct> {
+	buffer: MutDesc<P, MutBuffer<[T]>>,
+}
+
+impl<P: BindlessPipelinePlatform, T: BufferStruct> GrowableBuffer<P, T> {
+	pub fn new(buffer: MutDesc<P, MutBuffer<[T]>>) -> Self {
+		Self { buffer }
+	}
+
+	/// The amount of `T`s the buffer can hold
+	pub fn capacity(&self) -> usize {
+		self.buffer.inner_slot().len
+	}
+
+	pub fn buffer(&self) -> &MutDesc<P, MutBuffer<[T]>> {
+		&self.buffer
+	}
+
+	pub fn into_inner(self) -> MutDesc<P, MutBuffer<[T]>> {
+		self.buffer
+	}
+
+	/// Ensures the capacity is at least `min_capacity`. If the buffer needs to grow, its capacity is at least doubled
+	/// and its contents copied within `cmd`.
+	pub fn reserve(self, cmd: &mut Recording<'_, P>, min_capacity: usize) -> Result<Self, RecordingError<P>> {
+		let capacity = self.capacity();
+		if min_capacity <= capacity {
+			return Ok(self);
+		}
+		let new_capacity = usize::max(min_capacity, capacity * 2);
+		Ok(Self::new(self.buffer.resize(cmd, new_capacity)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessAllocationScheme, MutDescBufferExt, MutDescExt};
+	use crate::pipeline::HostAccess;
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+
+	#[test]
+	fn test_growable_buffer() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let data = (0..4).collect::<Vec<u32>>();
+		let buffer = GrowableBuffer::new(bindless.buffer().alloc_from_iter(
+			&BindlessBufferCreateInfo {
+				allocation_scheme: BindlessAllocationScheme::Dedicated,
+				..buffer_ci("growable")
+			},
+			data.iter().copied(),
+		)?);
+		let old_id = buffer.buffer().id();
+
+		let buffer = bindless.execute(|cmd| {
+			let buffer = buffer.reserve(cmd, 4)?;
+			assert_eq!(buffer.buffer().id(), old_id);
+			buffer.reserve(cmd, 5)
+		})?;
+		assert_eq!(buffer.capacity(), 8);
+		assert_eq!(buffer.buffer().inner_slot().debug_name(), "growable");
+		assert_eq!(
+			buffer.buffer().inner_slot().allocation_scheme,
+			BindlessAllocationScheme::Dedicated
+		);
+		let resized = bindless.execute(|cmd| {
+			let resized = buffer.into_inner().resize(cmd, 20)?.access::<TransferWrite>(cmd)?;
+			Ok(resized.transition::<HostAccess>()?.into_desc())
+		})?;
+		assert_eq!(resized.inner_slot().len, 20);
+		assert_eq!(
+			resized.mapped_immediate()?.read_iter().take(4).collect::<Vec<_>>(),
+			data
+		);
+
+		// the old buffers are retired by the GC
+		bindless.table_sync().collect_now();
+		assert_eq!(bindless.stats().buffer.live_slots, 1);
+		Ok(())
+	}
+
+	#[test]
+	fn test_resize_shrink() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let data = (0..8).collect::<Vec<u32>>();
+		let buffer = bindless
+			.buffer()
+			.alloc_from_iter(&buffer_ci("shrink"), data.iter().copied())?;
+		let resized = bindless.execute(|cmd| {
+			let resized = buffer.resize(cmd, 3)?.access::<TransferWrite>(cmd)?;
+			Ok(resized.transition::<HostAccess>()?.into_desc())
+		})?;
+		assert_eq!(resized.inner_slot().len, 3);
+		assert_eq!(resized.mapped_immediate()?.read_iter().collect::<Vec<_>>(), data[..3]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_resize_checks_usage() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let buffer = bindless.buffer().alloc_slice::<u32>(
+			&BindlessBufferCreateInfo {
+				usage: BindlessBufferUsage::MAP_WRITE | BindlessBufferUsage::TRANSFER_SRC,
+				..buffer_ci("no transfer dst")
+			},
+			4,
+		)?;
+		let id = buffer.id();
+		let allocated = bindless.allocated_bytes();
+
+		// resizing to the same length is a no-op and doesn't need any usage
+		let buffer = bindless.execute(|cmd| buffer.resize(cmd, 4))?;
+		assert_eq!(buffer.id(), id);
+
+		// the usage is checked before allocating the new buffer
+		let result = bindless.execute(|cmd| buffer.resize(cmd, 8));
+		assert!(matches!(
+			result,
+			Err(RecordingError::AccessError(AccessError::MissingBufferUsage { .. }))
+		));
+		assert_eq!(bindless.allocated_bytes(), allocated);
+		Ok(())
+	}
+}
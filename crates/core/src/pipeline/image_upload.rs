@@ -0,0 +1,336 @@
+use crate::descriptor::{
+	BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessImageCreateInfo,
+	BindlessImageUsage, FormatAspects, ImageTableAccess, MutDescBufferExt, RCDesc,
+};
+use crate::pipeline::access_buffer::MutBufferAccessExt;
+use crate::pipeline::access_image::MutImageAccessExt;
+use crate::pipeline::access_type::{TransferRead, TransferWrite};
+use crate::pipeline::copy_region::{BufferImageCopyRegion, ImageRegion};
+use crate::pipeline::recording::{CopyError, RecordingError};
+use crate::platform::BindlessPipelinePlatform;
+use rust_gpu_bindless_shaders::descriptor::{Image, ImageType};
+use std::future::Future;
+
+impl<P: BindlessPipelinePlatform> ImageTableAccess<'_, P> {
+	/// Allocates a new image and uploads `data` into mip 0 of all of its layers. `data` must contain the tightly packed
+	/// texels of every layer, one layer after another, see [`Format::data_size`]. Multi-planar formats contain every
+	/// plane of all layers, one plane after another. Combined depth/stencil formats are not supported, as each of their
+	/// aspects must be uploaded separately with [`Self::alloc_from_regions`]. If the image has more than one mip level,
+	/// all other mips are generated from mip 0, see [`Recording::generate_mips`].
+	///
+	/// See [`Self::alloc_from_regions`] for details on how the upload is executed.
+	///
+	/// [`Format::data_size`]: crate::descriptor::Format::data_size
+	/// [`Recording::generate_mips`]: crate::pipeline::Recording::generate_mips
+	pub fn alloc_from_data<T: ImageType>(
+		&self,
+		create_info: &BindlessImageCreateInfo<T>,
+		data: &[u8],
+	) -> Result<impl Future<Output = RCDesc<P, Image<T>>> + use<P, T>, RecordingError<P>> {
+		let format = create_info.format;
+		let expected = format.data_size(create_info.extent) * create_info.array_layers as u64;
+		if data.is_empty() || data.len() as u64 != expected {
+			return Err(CopyError::InvalidDataSize {
+				name: create_info.name.to_string(),
+				size: data.len() as u64,
+				expected,
+			}
+			.into());
+		}
+		// check before allocating anything, so failing to upload or generate mips doesn't waste an allocation
+		let aspects = format.aspects();
+		if aspects.contains(FormatAspects::DEPTH | FormatAspects::STENCIL) {
+			return Err(CopyError::InvalidAspect {
+				name: create_info.name.to_string(),
+				format,
+				aspect: aspects,
+			}
+			.into());
+		}
+		if create_info.mip_levels > 1 && !unsafe { self.0.platform.supports_blit(format) } {
+			return Err(CopyError::BlitUnsupported {
+				name: create_info.name.to_string(),
+				format,
+			}
+			.into());
+		}
+
+		let region = ImageRegion::whole_mip(create_info.extent, 0, create_info.array_layers);
+		let regions = if aspects.contains(FormatAspects::PLANE_0) {
+			let [sub_width, sub_height] = format.plane_subsampling();
+			let mut buffer_offset = 0;
+			[FormatAspects::PLANE_0, FormatAspects::PLANE_1, FormatAspects::PLANE_2]
+				.into_iter()
+				.filter(|plane| aspects.contains(*plane))
+				.map(|aspect| {
+					let mut image = region;
+					if aspect != FormatAspects::PLANE_0 {
+						image.extent.width = image.extent.width.div_ceil(sub_width);
+						image.extent.height = image.extent.height.div_ceil(sub_height);
+					}
+					let plane = BufferImageCopyRegion {
+						buffer_offset,
+						aspect,
+						..BufferImageCopyRegion::tightly_packed(image)
+					};
+					buffer_offset += plane.buffer_size(format).unwrap();
+					plane
+				})
+				.collect()
+		} else {
+			vec![BufferImageCopyRegion::tightly_packed(region)]
+		};
+		self.upload(create_info, data, &regions, create_info.mip_levels > 1)
+	}
+
+	/// Allocates a new image and uploads the `regions` of `data` into it, e.g. to upload precomputed mips or individual
+	/// layers. The buffer offsets of `regions` are relative to the start of `data`. Mips and layers not covered by any
+	/// region have undefined contents.
+	///
+	/// The data is copied into a staging buffer and the upload is executed immediately. The returned future resolves to
+	/// the shared image once the upload has completed. [`BindlessImageUsage::TRANSFER_DST`] is added to the usage of
+	/// `create_info`, as well as [`BindlessImageUsage::TRANSFER_SRC`] if mips are generated.
+	pub fn alloc_from_regions<T: ImageType>(
+		&self,
+		create_info: &BindlessImageCreateInfo<T>,
+		data: &[u8],
+		regions: &[BufferImageCopyRegion],
+	) -> Result<impl Future<Output = RCDesc<P, Image<T>>> + use<P, T>, RecordingError<P>> {
+		if data.is_empty() {
+			let expected = regions
+				.iter()
+				.filter_map(|region| Some(region.buffer_offset + region.buffer_size(create_info.format)?))
+				.max()
+				.unwrap_or(0);
+			return Err(CopyError::InvalidDataSize {
+				name: create_info.name.to_string(),
+				size: 0,
+				expected,
+			}
+			.into());
+		}
+		self.upload(create_info, data, regions, false)
+	}
+
+	fn upload<T: ImageType>(
+		&self,
+		create_info: &BindlessImageCreateInfo<T>,
+		data: &[u8],
+		regions: &[BufferImageCopyRegion],
+		generate_mips: bool,
+	) -> Result<impl Future<Output = RCDesc<P, Image<T>>> + use<P, T>, RecordingError<P>> {
+		let mut usage = create_info.usage | BindlessImageUsage::TRANSFER_DST;
+		if generate_mips {
+			usage |= BindlessImageUsage::TRANSFER_SRC;
+		}
+		let image = self.alloc(&BindlessImageCreateInfo { usage, ..*create_info })?;
+		let staging = self.0.buffer().alloc_slice::<u8>(
+			&BindlessBufferCreateInfo {
+				usage: BindlessBufferUsage::MAP_WRITE | BindlessBufferUsage::TRANSFER_SRC,
+				allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+				name: &format!("{} staging buffer", create_info.name),
+			},
+			data.len(),
+		)?;
+		staging.mapped_immediate().unwrap().as_mut_slice().copy_from_slice(data);
+
+		self.0.execute(|cmd| {
+			let staging = staging.access::<TransferRead>(cmd)?;
+			let image = image.access_dont_care::<TransferWrite>(cmd)?;
+			for region in regions {
+				cmd.copy_buffer_to_image_region(&staging, &image, region)?;
+			}
+			if generate_mips {
+				cmd.generate_mips(&image)?;
+			}
+			Ok(image.into_shared())
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{Format, Image2d, Image2dArray, RCDescExt};
+	use crate::platform::mock::MockCommand;
+	use crate::platform::mock::test_utils::mock_bindless;
+
+	#[test]
+	fn test_alloc_from_data() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let image_ci = BindlessImageCreateInfo::<Image2d> {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [8, 8].into(),
+			mip_levels: 4,
+			usage: BindlessImageUsage::SAMPLED,
+			name: "texture",
+			..BindlessImageCreateInfo::default()
+		};
+		let image = futures::executor::block_on(bindless.image().alloc_from_data(&image_ci, &[0xFF; 256])?);
+		let slot = image.inner_slot();
+		assert_eq!(
+			slot.usage,
+			BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST
+		);
+		let executions = bindless.take_executions();
+		let region = BufferImageCopyRegion::tightly_packed(ImageRegion::whole_mip([8, 8].into(), 0, 1));
+		assert!(executions[0].commands.iter().any(|command| matches!(
+			command,
+			MockCommand::CopyBufferToImage { dst, region: r, .. } if *dst == slot.handle && *r == region
+		)));
+		assert!(executions[0].commands.contains(&MockCommand::GenerateMips {
+			image: slot.handle,
+			mip_levels: 4,
+		}));
+
+		let result = bindless.image().alloc_from_data(&image_ci, &[0xFF; 255]);
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::InvalidDataSize {
+				size: 255,
+				expected: 256,
+				..
+			}))
+		));
+
+		let array_ci = BindlessImageCreateInfo::<Image2dArray> {
+			format: Format::R8_UNORM,
+			extent: [4, 4].into(),
+			array_layers: 2,
+			usage: BindlessImageUsage::SAMPLED,
+			name: "array",
+			..BindlessImageCreateInfo::default()
+		};
+		let layer = |layer: u32, buffer_offset: u64| BufferImageCopyRegion {
+			buffer_offset,
+			image: ImageRegion {
+				base_layer: layer,
+				layer_count: 1,
+				..ImageRegion::whole_mip([4, 4].into(), 0, 1)
+			},
+			..BufferImageCopyRegion::tightly_packed(ImageRegion::whole_mip([4, 4].into(), 0, 1))
+		};
+		let array = futures::executor::block_on(bindless.image().alloc_from_regions(
+			&array_ci,
+			&[0; 32],
+			&[layer(1, 0), layer(0, 16)],
+		)?);
+		assert_eq!(
+			array.inner_slot().usage,
+			BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_DST
+		);
+		let executions = bindless.take_executions();
+		let copies = executions[0]
+			.commands
+			.iter()
+			.filter(|command| matches!(command, MockCommand::CopyBufferToImage { .. }))
+			.count();
+		assert_eq!(copies, 2);
+		assert!(
+			!executions[0]
+				.commands
+				.iter()
+				.any(|command| matches!(command, MockCommand::GenerateMips { .. }))
+		);
+
+		let result = bindless
+			.image()
+			.alloc_from_regions(&array_ci, &[0; 16], &[layer(0, 16)]);
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::BufferOutOfBounds { .. }))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_alloc_from_data_validation() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let image_ci = BindlessImageCreateInfo::<Image2d> {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [8, 8].into(),
+			usage: BindlessImageUsage::SAMPLED,
+			name: "texture",
+			..BindlessImageCreateInfo::default()
+		};
+		let result = bindless.image().alloc_from_data(&image_ci, &[]);
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::InvalidDataSize {
+				size: 0,
+				expected: 256,
+				..
+			}))
+		));
+		let region = BufferImageCopyRegion {
+			buffer_offset: 16,
+			..BufferImageCopyRegion::tightly_packed(ImageRegion::whole_mip([2, 2].into(), 0, 1))
+		};
+		let result = bindless.image().alloc_from_regions(&image_ci, &[], &[region]);
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::InvalidDataSize {
+				size: 0,
+				expected: 32,
+				..
+			}))
+		));
+
+		// mips of formats that can't be blitted can't be generated, so nothing is allocated
+		let result = bindless.image().alloc_from_data(
+			&BindlessImageCreateInfo {
+				format: Format::BC7_UNORM_BLOCK,
+				mip_levels: 2,
+				..image_ci
+			},
+			&[0; 64],
+		);
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::BlitUnsupported { .. }))
+		));
+		assert_eq!(bindless.stats().image.live_slots, 0);
+
+		// combined depth/stencil formats have no single aspect to upload, so nothing is allocated
+		let result = bindless.image().alloc_from_data(
+			&BindlessImageCreateInfo {
+				format: Format::D32_SFLOAT_S8_UINT,
+				..image_ci
+			},
+			&[0; 8 * 8 * 5],
+		);
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::InvalidAspect { .. }))
+		));
+		assert_eq!(bindless.stats().image.live_slots, 0);
+
+		// every plane of multi-planar formats is uploaded separately
+		let planar = futures::executor::block_on(bindless.image().alloc_from_data(
+			&BindlessImageCreateInfo {
+				format: Format::G8_B8R8_2PLANE_420_UNORM,
+				..image_ci
+			},
+			&[0; 64 + 2 * 16],
+		)?);
+		let handle = planar.inner_slot().handle;
+		let executions = bindless.take_executions();
+		let regions = executions[0]
+			.commands
+			.iter()
+			.filter_map(|command| match command {
+				MockCommand::CopyBufferToImage { dst, region, .. } if *dst == handle => Some(*region),
+				_ => None,
+			})
+			.map(|region| (region.aspect, region.buffer_offset, region.image.extent))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			regions,
+			[
+				(FormatAspects::PLANE_0, 0, [8, 8, 1].into()),
+				(FormatAspects::PLANE_1, 64, [4, 4, 1].into())
+			]
+		);
+		Ok(())
+	}
+}
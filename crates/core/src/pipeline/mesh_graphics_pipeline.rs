@@ -1,7 +1,6 @@
-use crate::descriptor::Bindless;
-use crate::pipeline::graphics_pipeline::{
-	PipelineColorBlendStateCreateInfo, PipelineDepthStencilStateCreateInfo, PipelineRasterizationStateCreateInfo,
-};
+use crate::descriptor::{Bindless, SampleCount};
+use crate::pipeline::pipeline_error::{PipelineCreationError, check_id_layout};
+use crate::pipeline::pipeline_state::{ColorBlendState, DepthStencilState, RasterizationState};
 use crate::pipeline::rendering::RenderPassFormat;
 use crate::platform::BindlessPipelinePlatform;
 use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
@@ -10,13 +9,15 @@ use rust_gpu_bindless_shaders::shader_type::{FragmentShader, MeshShader, TaskSha
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct MeshGraphicsPipelineCreateInfo<'a> {
-	pub rasterization_state: PipelineRasterizationStateCreateInfo<'a>,
-	pub depth_stencil_state: PipelineDepthStencilStateCreateInfo<'a>,
-	pub color_blend_state: PipelineColorBlendStateCreateInfo<'a>,
+	pub rasterization_state: RasterizationState,
+	pub depth_stencil_state: DepthStencilState,
+	pub color_blend_state: ColorBlendState<'a>,
 }
 
 impl<P: BindlessPipelinePlatform> Bindless<P> {
+	/// Fails with [`PipelineCreationError::IdLayoutMismatch`] if a shader was compiled with a different id layout.
 	pub fn create_mesh_graphics_pipeline<T: BufferStruct>(
 		&self,
 		render_pass: &RenderPassFormat,
@@ -24,17 +25,26 @@ impl<P: BindlessPipelinePlatform> Bindless<P> {
 		task_shader: Option<&impl BindlessShader<ShaderType = TaskShader, ParamConstant = T>>,
 		mesh_shader: &impl BindlessShader<ShaderType = MeshShader, ParamConstant = T>,
 		fragment_shader: &impl BindlessShader<ShaderType = FragmentShader, ParamConstant = T>,
-	) -> Result<BindlessMeshGraphicsPipeline<P, T>, P::PipelineCreationError> {
+	) -> Result<BindlessMeshGraphicsPipeline<P, T>, PipelineCreationError<P>> {
+		if let Some(task_shader) = task_shader {
+			check_id_layout::<P, _>(task_shader)?;
+		}
+		check_id_layout::<P, _>(mesh_shader)?;
+		check_id_layout::<P, _>(fragment_shader)?;
 		unsafe {
 			Ok(BindlessMeshGraphicsPipeline {
-				pipeline: Arc::new(P::create_mesh_graphics_pipeline(
-					self,
-					render_pass,
-					create_info,
-					task_shader,
-					mesh_shader,
-					fragment_shader,
-				)?),
+				pipeline: Arc::new(
+					P::create_mesh_graphics_pipeline(
+						self,
+						render_pass,
+						create_info,
+						task_shader,
+						mesh_shader,
+						fragment_shader,
+					)
+					.map_err(PipelineCreationError::Platform)?,
+				),
+				samples: render_pass.samples,
 				_phantom: PhantomData,
 			})
 		}
@@ -44,6 +54,7 @@ impl<P: BindlessPipelinePlatform> Bindless<P> {
 #[derive(Debug, Clone)]
 pub struct BindlessMeshGraphicsPipeline<P: BindlessPipelinePlatform, T: BufferStruct> {
 	pipeline: Arc<P::MeshGraphicsPipeline>,
+	samples: SampleCount,
 	_phantom: PhantomData<T>,
 }
 
@@ -51,4 +62,8 @@ impl<P: BindlessPipelinePlatform, T: BufferStruct> BindlessMeshGraphicsPipeline<
 	pub fn inner(&self) -> &Arc<P::MeshGraphicsPipeline> {
 		&self.pipeline
 	}
+	/// The sample count of the [`RenderPassFormat`] this pipeline was created for
+	pub fn samples(&self) -> SampleCount {
+		self.samples
+	}
 }
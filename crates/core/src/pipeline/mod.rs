@@ -4,9 +4,14 @@ mod access_image;
 mod access_lock;
 mod access_type;
 mod compute_pipeline;
+mod copy_region;
 mod graphics_pipeline;
+mod growable_buffer;
+mod image_upload;
 mod mesh_graphics_pipeline;
 mod mut_or_shared;
+mod pipeline_error;
+mod pipeline_state;
 mod recording;
 mod rendering;
 
@@ -16,8 +21,12 @@ pub use access_image::*;
 pub use access_lock::*;
 pub use access_type::*;
 pub use compute_pipeline::*;
+pub use copy_region::*;
 pub use graphics_pipeline::*;
+pub use growable_buffer::*;
 pub use mesh_graphics_pipeline::*;
 pub use mut_or_shared::*;
+pub use pipeline_error::*;
+pub use pipeline_state::*;
 pub use recording::*;
 pub use rendering::*;
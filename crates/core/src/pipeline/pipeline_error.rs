@@ -0,0 +1,40 @@
+use crate::platform::BindlessPipelinePlatform;
+use rust_gpu_bindless_shaders::descriptor::ID_LAYOUT;
+use rust_gpu_bindless_shaders::shader::BindlessShader;
+use std::fmt::{Debug, Display, Formatter};
+use thiserror::Error;
+
+#[derive(Error)]
+pub enum PipelineCreationError<P: BindlessPipelinePlatform> {
+	#[error("Platform Error: {0}")]
+	Platform(#[source] P::PipelineCreationError),
+	#[error(
+		"Shader {entry_point_name} was compiled with id layout {id_layout:?}, but the CPU side uses {expected:?}. Select the same layout with `ShaderSymbolsBuilder::id_layout` as with the `id-layout-*` feature.",
+		expected = ID_LAYOUT
+	)]
+	IdLayoutMismatch {
+		entry_point_name: String,
+		id_layout: [u32; 3],
+	},
+}
+
+impl<P: BindlessPipelinePlatform> Debug for PipelineCreationError<P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self, f)
+	}
+}
+
+/// Checks that `shader` was compiled with the same [`ID_LAYOUT`] as the CPU side, as the shader would otherwise decode
+/// every descriptor id incorrectly.
+pub(crate) fn check_id_layout<P: BindlessPipelinePlatform, S: BindlessShader>(
+	shader: &S,
+) -> Result<(), PipelineCreationError<P>> {
+	let binary = shader.spirv_binary();
+	if binary.id_layout != ID_LAYOUT {
+		return Err(PipelineCreationError::IdLayoutMismatch {
+			entry_point_name: binary.entry_point_name.to_string_lossy().into_owned(),
+			id_layout: binary.id_layout,
+		});
+	}
+	Ok(())
+}
@@ -0,0 +1,297 @@
+/// How vertices are assembled into primitives.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct InputAssemblyState {
+	/// The primitive topology vertices are assembled into
+	pub topology: PrimitiveTopology,
+	/// Whether a special index value restarts the assembly of strip and fan primitives. Only valid for indexed draws.
+	pub primitive_restart_enable: bool,
+}
+
+/// The primitive topology vertices are assembled into.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum PrimitiveTopology {
+	/// Each vertex is a separate point
+	PointList,
+	/// Every two vertices form a separate line
+	LineList,
+	/// Every vertex forms a line with the previous vertex
+	LineStrip,
+	/// Every three vertices form a separate triangle
+	#[default]
+	TriangleList,
+	/// Every vertex forms a triangle with the previous two vertices
+	TriangleStrip,
+	/// Every vertex forms a triangle with the previous and the first vertex
+	TriangleFan,
+}
+
+/// How primitives are rasterized.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RasterizationState {
+	/// Clamp fragment depth values to the depth range instead of clipping primitives against the near and far plane
+	pub depth_clamp_enable: bool,
+	/// How polygons are rasterized
+	pub polygon_mode: PolygonMode,
+	/// Which faces of triangles are culled
+	pub cull_mode: CullMode,
+	/// Which winding order of triangles is considered to be front facing
+	pub front_face: FrontFace,
+	/// Bias fragment depth values, or `None` to disable depth bias
+	pub depth_bias: Option<DepthBias>,
+}
+
+/// How polygons are rasterized.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum PolygonMode {
+	/// Polygons are filled
+	#[default]
+	Fill,
+	/// Only the edges of polygons are drawn as lines
+	Line,
+	/// Only the vertices of polygons are drawn as points
+	Point,
+}
+
+/// Which faces of triangles are culled.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CullMode {
+	/// No triangles are culled
+	#[default]
+	None,
+	/// Front facing triangles are culled
+	Front,
+	/// Back facing triangles are culled
+	Back,
+	/// All triangles are culled
+	FrontAndBack,
+}
+
+/// Which winding order of triangles is considered to be front facing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum FrontFace {
+	/// Triangles with their vertices in counter-clockwise order are front facing
+	#[default]
+	CounterClockwise,
+	/// Triangles with their vertices in clockwise order are front facing
+	Clockwise,
+}
+
+/// Bias applied to fragment depth values.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DepthBias {
+	/// Constant depth value added to each fragment
+	pub constant_factor: f32,
+	/// Maximum (or minimum if negative) depth bias of a fragment
+	pub clamp: f32,
+	/// Factor applied to a fragment's slope in depth bias calculations
+	pub slope_factor: f32,
+}
+
+/// Depth and stencil testing of fragments.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DepthStencilState {
+	/// Test fragment depth against the depth attachment using [`Self::depth_compare_op`]
+	pub depth_test_enable: bool,
+	/// Write the depth of fragments passing the depth test into the depth attachment
+	pub depth_write_enable: bool,
+	/// The comparison used by the depth test
+	pub depth_compare_op: CompareOp,
+	/// Discard fragments outside the `(min, max)` depth bounds, or `None` to disable depth bounds testing
+	pub depth_bounds: Option<(f32, f32)>,
+	/// Stencil testing of front and back facing primitives, or `None` to disable stencil testing
+	pub stencil: Option<StencilState>,
+}
+
+/// A comparison between a reference and a stored value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CompareOp {
+	/// The comparison always evaluates false
+	#[default]
+	Never,
+	/// reference < stored
+	Less,
+	/// reference == stored
+	Equal,
+	/// reference <= stored
+	LessOrEqual,
+	/// reference > stored
+	Greater,
+	/// reference != stored
+	NotEqual,
+	/// reference >= stored
+	GreaterOrEqual,
+	/// The comparison always evaluates true
+	Always,
+}
+
+/// Stencil testing of front and back facing primitives.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct StencilState {
+	/// Stencil operations for front facing primitives
+	pub front: StencilOpState,
+	/// Stencil operations for back facing primitives
+	pub back: StencilOpState,
+}
+
+/// The stencil test and the operations applied to the stencil attachment based on its result.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct StencilOpState {
+	/// Operation applied to samples failing the stencil test
+	pub fail_op: StencilOp,
+	/// Operation applied to samples passing both the stencil and the depth test
+	pub pass_op: StencilOp,
+	/// Operation applied to samples passing the stencil test but failing the depth test
+	pub depth_fail_op: StencilOp,
+	/// The comparison used by the stencil test
+	pub compare_op: CompareOp,
+	/// Bits of the stencil values participating in the stencil test
+	pub compare_mask: u32,
+	/// Bits of the stencil values updated by the stencil operations
+	pub write_mask: u32,
+	/// Reference value used by the stencil test
+	pub reference: u32,
+}
+
+/// Operation applied to the stored stencil value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum StencilOp {
+	/// Keep the current value
+	#[default]
+	Keep,
+	/// Set the value to 0
+	Zero,
+	/// Set the value to the reference value
+	Replace,
+	/// Increment the value and clamp it to the maximum representable value
+	IncrementAndClamp,
+	/// Decrement the value and clamp it to 0
+	DecrementAndClamp,
+	/// Bitwise invert the value
+	Invert,
+	/// Increment the value and wrap to 0 on overflow
+	IncrementAndWrap,
+	/// Decrement the value and wrap to the maximum representable value on underflow
+	DecrementAndWrap,
+}
+
+/// Blending of fragment outputs with the color attachments.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ColorBlendState<'a> {
+	/// Blending per color attachment, must contain one entry for each color attachment of the render pass
+	pub attachments: &'a [ColorBlendAttachment],
+	/// The constant color used by the [`BlendFactor::ConstantColor`] and [`BlendFactor::ConstantAlpha`] factors
+	pub blend_constants: [f32; 4],
+}
+
+/// Blending of fragment outputs with a single color attachment.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColorBlendAttachment {
+	/// How fragment outputs are blended with the attachment, or `None` to write the fragment outputs unmodified
+	pub blend: Option<BlendState>,
+	/// Which color components are written to the attachment
+	pub color_write_mask: ColorComponents,
+}
+
+impl Default for ColorBlendAttachment {
+	fn default() -> Self {
+		Self {
+			blend: None,
+			color_write_mask: ColorComponents::RGBA,
+		}
+	}
+}
+
+/// How fragment outputs are blended with the attachment.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct BlendState {
+	/// Blending of the color components
+	pub color: BlendComponent,
+	/// Blending of the alpha component
+	pub alpha: BlendComponent,
+}
+
+impl BlendState {
+	/// Blending for premultiplied alpha: `src + (1 - src_alpha) * dst`
+	pub const PREMULTIPLIED_ALPHA_BLENDING: Self = Self {
+		color: BlendComponent {
+			src_factor: BlendFactor::One,
+			dst_factor: BlendFactor::OneMinusSrcAlpha,
+			operation: BlendOp::Add,
+		},
+		alpha: BlendComponent {
+			src_factor: BlendFactor::One,
+			dst_factor: BlendFactor::OneMinusSrcAlpha,
+			operation: BlendOp::Add,
+		},
+	};
+}
+
+/// Blending of some components: `operation(src * src_factor, dst * dst_factor)`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BlendComponent {
+	/// Factor multiplied with the fragment output
+	pub src_factor: BlendFactor,
+	/// Factor multiplied with the value stored in the attachment
+	pub dst_factor: BlendFactor,
+	/// Operation combining both factored values
+	pub operation: BlendOp,
+}
+
+impl Default for BlendComponent {
+	/// Replaces the stored value with the fragment output
+	fn default() -> Self {
+		Self {
+			src_factor: BlendFactor::One,
+			dst_factor: BlendFactor::Zero,
+			operation: BlendOp::Add,
+		}
+	}
+}
+
+/// Factor a blended value is multiplied with.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BlendFactor {
+	Zero,
+	One,
+	SrcColor,
+	OneMinusSrcColor,
+	DstColor,
+	OneMinusDstColor,
+	SrcAlpha,
+	OneMinusSrcAlpha,
+	DstAlpha,
+	OneMinusDstAlpha,
+	ConstantColor,
+	OneMinusConstantColor,
+	ConstantAlpha,
+	OneMinusConstantAlpha,
+	SrcAlphaSaturate,
+}
+
+/// Operation combining the factored fragment output and stored value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BlendOp {
+	/// src + dst
+	Add,
+	/// src - dst
+	Subtract,
+	/// dst - src
+	ReverseSubtract,
+	/// min(src, dst), ignoring blend factors
+	Min,
+	/// max(src, dst), ignoring blend factors
+	Max,
+}
+
+bitflags::bitflags! {
+	/// Color components of an attachment
+	#[repr(transparent)]
+	#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+	pub struct ColorComponents: u32 {
+		const R = 0b1;
+		const G = 0b10;
+		const B = 0b100;
+		const A = 0b1000;
+		const RGBA = 0b1111;
+	}
+}
@@ -1,4 +1,7 @@
-use crate::descriptor::{Bindless, BindlessBufferUsage, BindlessImageUsage};
+use crate::descriptor::{
+	Bindless, BindlessBufferUsage, BindlessImageUsage, BufferAllocationError, Extent, Filter, Format, FormatAspects,
+	ImageAllocationError, SampleCount, StrongMetadataCpu,
+};
 use crate::pipeline::access_buffer::MutBufferAccess;
 use crate::pipeline::access_error::AccessError;
 use crate::pipeline::access_image::MutImageAccess;
@@ -6,14 +9,18 @@ use crate::pipeline::access_type::{
 	BufferAccessType, ImageAccessType, IndirectCommandReadable, TransferReadable, TransferWriteable,
 };
 use crate::pipeline::compute_pipeline::BindlessComputePipeline;
+use crate::pipeline::copy_region::{
+	BufferCopyRegion, BufferImageCopyRegion, ImageCopyRegion, ImageRegion, validate_buffer_image_region,
+	validate_buffer_range, validate_image_copy_region,
+};
 use crate::pipeline::mut_or_shared::{MutOrSharedBuffer, MutOrSharedImage};
-use crate::pipeline::rendering::RenderingError;
-use crate::platform::ash::Ash;
+use crate::pipeline::rendering::{ClearValue, RenderingError};
 use crate::platform::{BindlessPipelinePlatform, RecordingContext};
-use rust_gpu_bindless_shaders::buffer_content::{BufferContent, BufferStruct};
+use glam::UVec3;
+use rust_gpu_bindless_shaders::buffer_content::{BufferContent, BufferStruct, Metadata};
 use rust_gpu_bindless_shaders::descriptor::{ImageType, TransientAccess};
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Range};
 use thiserror::Error;
 
 impl<P: BindlessPipelinePlatform> Bindless<P> {
@@ -25,6 +32,9 @@ impl<P: BindlessPipelinePlatform> Bindless<P> {
 	}
 }
 
+/// The maximum amount of bytes [`Recording::update_buffer`] can write at once
+pub const UPDATE_BUFFER_MAX_SIZE: u64 = 65536;
+
 pub struct Recording<'a, P: BindlessPipelinePlatform> {
 	platform: P::RecordingContext<'a>,
 }
@@ -47,14 +57,14 @@ impl<P: BindlessPipelinePlatform> DerefMut for Recording<'_, P> {
 
 pub unsafe trait HasResourceContext<'a, P: BindlessPipelinePlatform>: TransientAccess<'a> + Sized {
 	/// Gets the [`Bindless`] of this execution
-	fn bindless(&self) -> &Bindless<Ash>;
+	fn bindless(&self) -> &Bindless<P>;
 
 	fn resource_context(&self) -> &'a P::RecordingResourceContext;
 }
 
 unsafe impl<'a, P: BindlessPipelinePlatform> HasResourceContext<'a, P> for Recording<'a, P> {
 	#[inline]
-	fn bindless(&self) -> &Bindless<Ash> {
+	fn bindless(&self) -> &Bindless<P> {
 		self.platform.bindless()
 	}
 
@@ -113,14 +123,37 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 		src.has_required_usage(BindlessBufferUsage::TRANSFER_SRC)?;
 		dst.has_required_usage(BindlessBufferUsage::TRANSFER_DST)?;
 		unsafe {
+			validate_buffer_range(dst.inner_slot(), 0, src.inner_slot().len)?;
 			self.platform
 				.copy_buffer_to_buffer_slice(src, dst)
 				.map_err(Into::<RecordingError<P>>::into)
 		}
 	}
 
-	/// Copy data from a buffer to an image. It is assumed that the image data is tightly packed within the buffer.
-	/// Partial copies and copying to mips other than mip 0 is not yet possible.
+	/// Copy a range of elements of one buffer of a slice to another buffer of the same slice.
+	pub fn copy_buffer_to_buffer_region<
+		T: BufferStruct,
+		SA: BufferAccessType + TransferReadable,
+		DA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: impl MutOrSharedBuffer<P, [T], SA>,
+		dst: &MutBufferAccess<P, [T], DA>,
+		region: &BufferCopyRegion,
+	) -> Result<(), RecordingError<P>> {
+		src.has_required_usage(BindlessBufferUsage::TRANSFER_SRC)?;
+		dst.has_required_usage(BindlessBufferUsage::TRANSFER_DST)?;
+		unsafe {
+			validate_buffer_range(src.inner_slot(), region.src_offset, region.len)?;
+			validate_buffer_range(dst.inner_slot(), region.dst_offset, region.len)?;
+			self.platform
+				.copy_buffer_to_buffer_region(src, dst, region)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Copy data from a buffer to mip 0 of all layers of an image. It is assumed that the image data is tightly packed
+	/// within the buffer, see [`Self::copy_buffer_to_image_region`] for partial copies.
 	pub fn copy_buffer_to_image<
 		BT: BufferContent + ?Sized,
 		BA: BufferAccessType + TransferReadable,
@@ -130,19 +163,36 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 		&mut self,
 		src_buffer: &MutBufferAccess<P, BT, BA>,
 		dst_image: &MutImageAccess<P, IT, IA>,
+	) -> Result<(), RecordingError<P>> {
+		let image = unsafe { dst_image.inner_slot() };
+		let region = ImageRegion::whole_mip(image.extent, 0, image.array_layers);
+		self.copy_buffer_to_image_region(src_buffer, dst_image, &BufferImageCopyRegion::tightly_packed(region))
+	}
+
+	/// Copy data from a region of a buffer to a region of an image.
+	pub fn copy_buffer_to_image_region<
+		BT: BufferContent + ?Sized,
+		BA: BufferAccessType + TransferReadable,
+		IT: ImageType,
+		IA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src_buffer: &MutBufferAccess<P, BT, BA>,
+		dst_image: &MutImageAccess<P, IT, IA>,
+		region: &BufferImageCopyRegion,
 	) -> Result<(), RecordingError<P>> {
 		src_buffer.has_required_usage(BindlessBufferUsage::TRANSFER_SRC)?;
 		dst_image.has_required_usage(BindlessImageUsage::TRANSFER_DST)?;
-		// TODO soundness: missing bounds checks
 		unsafe {
+			validate_buffer_image_region(src_buffer.inner_slot(), dst_image.inner_slot(), region)?;
 			self.platform
-				.copy_buffer_to_image(src_buffer, dst_image)
+				.copy_buffer_to_image(src_buffer, dst_image, region)
 				.map_err(Into::<RecordingError<P>>::into)
 		}
 	}
 
-	/// Copy data from an image to a buffer. It is assumed that the image data is tightly packed within the buffer.
-	/// Partial copies and copying to mips other than mip 0 is not yet possible.
+	/// Copy data from mip 0 of all layers of an image to a buffer. The image data is tightly packed within the buffer,
+	/// see [`Self::copy_image_to_buffer_region`] for partial copies.
 	///
 	/// # Safety
 	/// This allows any data to be written to the buffer, without checking the buffer's type, potentially transmuting
@@ -156,13 +206,254 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 		&mut self,
 		src_image: &MutImageAccess<P, IT, IA>,
 		dst_buffer: &MutBufferAccess<P, BT, BA>,
+	) -> Result<(), RecordingError<P>> {
+		unsafe {
+			let image = src_image.inner_slot();
+			let region = ImageRegion::whole_mip(image.extent, 0, image.array_layers);
+			self.copy_image_to_buffer_region(src_image, dst_buffer, &BufferImageCopyRegion::tightly_packed(region))
+		}
+	}
+
+	/// Copy data from a region of an image to a region of a buffer.
+	///
+	/// # Safety
+	/// This allows any data to be written to the buffer, without checking the buffer's type, potentially transmuting
+	/// data.
+	pub unsafe fn copy_image_to_buffer_region<
+		IT: ImageType,
+		IA: ImageAccessType + TransferReadable,
+		BT: BufferContent + ?Sized,
+		BA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src_image: &MutImageAccess<P, IT, IA>,
+		dst_buffer: &MutBufferAccess<P, BT, BA>,
+		region: &BufferImageCopyRegion,
 	) -> Result<(), RecordingError<P>> {
 		src_image.has_required_usage(BindlessImageUsage::TRANSFER_SRC)?;
 		dst_buffer.has_required_usage(BindlessBufferUsage::TRANSFER_DST)?;
-		// TODO soundness: missing bounds checks
 		unsafe {
+			validate_buffer_image_region(dst_buffer.inner_slot(), src_image.inner_slot(), region)?;
 			self.platform
-				.copy_image_to_buffer(src_image, dst_buffer)
+				.copy_image_to_buffer(src_image, dst_buffer, region)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Copy a region of one image to a region of the same size of another image. Both images must have formats of the
+	/// same block size and extent, depth/stencil formats must be the same format.
+	pub fn copy_image_to_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src_image: &MutImageAccess<P, ST, SA>,
+		dst_image: &MutImageAccess<P, DT, DA>,
+		region: &ImageCopyRegion,
+	) -> Result<(), RecordingError<P>> {
+		src_image.has_required_usage(BindlessImageUsage::TRANSFER_SRC)?;
+		dst_image.has_required_usage(BindlessImageUsage::TRANSFER_DST)?;
+		unsafe {
+			validate_image_copy_region(src_image.inner_slot(), dst_image.inner_slot(), region, None)?;
+			self.platform
+				.copy_image_to_image(src_image, dst_image, region)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Blit a region of one image to a region of another image, scaling and converting the format if necessary. Both
+	/// images must be single-sampled and not block-compressed. Integer formats can only be blitted to integer formats of
+	/// the same signedness and depth/stencil formats only to the same format using [`Filter::Nearest`].
+	pub fn blit_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src_image: &MutImageAccess<P, ST, SA>,
+		dst_image: &MutImageAccess<P, DT, DA>,
+		region: &ImageCopyRegion,
+		filter: Filter,
+	) -> Result<(), RecordingError<P>> {
+		src_image.has_required_usage(BindlessImageUsage::TRANSFER_SRC)?;
+		dst_image.has_required_usage(BindlessImageUsage::TRANSFER_DST)?;
+		unsafe {
+			validate_image_copy_region(src_image.inner_slot(), dst_image.inner_slot(), region, Some(filter))?;
+			self.platform
+				.blit_image(src_image, dst_image, region, filter)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Generate all mips of an image by repeatedly downsampling mip 0 of `image` into the following mips. Mip 0 must
+	/// already contain the image data, the contents of all other mips are overwritten. All mips are transitioned
+	/// internally and are back in access `A` afterward.
+	///
+	/// The image must have both [`BindlessImageUsage::TRANSFER_SRC`] and [`BindlessImageUsage::TRANSFER_DST`]. Mips are
+	/// only generated using blits, there is no fallback for formats that can't be blitted, like block-compressed or
+	/// multi-planar formats. These fail with [`CopyError::BlitUnsupported`] and their mips have to be provided
+	/// otherwise, e.g. with [`ImageTableAccess::alloc_from_regions`]. Depth/stencil formats and formats not supporting
+	/// linear filtering are downsampled using nearest filtering instead.
+	///
+	/// [`ImageTableAccess::alloc_from_regions`]: crate::descriptor::ImageTableAccess::alloc_from_regions
+	pub fn generate_mips<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<P, T, A>,
+	) -> Result<(), RecordingError<P>> {
+		image.has_required_usage(BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST)?;
+		if unsafe { image.inner_slot() }.mip_levels <= 1 {
+			return Ok(());
+		}
+		unsafe {
+			let slot = image.inner_slot();
+			if !self.bindless().platform.supports_blit(slot.format) {
+				return Err(CopyError::BlitUnsupported {
+					name: slot.debug_name.clone(),
+					format: slot.format,
+				}
+				.into());
+			}
+			self.platform
+				.generate_mips(image)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Fill the entire buffer with repetitions of the 4-byte value `data`. The size and offset of the buffer must be a
+	/// multiple of 4.
+	///
+	/// # Safety
+	/// This writes `data` into the buffer without checking the buffer's type, potentially transmuting data.
+	pub unsafe fn fill_buffer<T: BufferContent + ?Sized, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<P, T, A>,
+		data: u32,
+	) -> Result<(), RecordingError<P>> {
+		buffer.has_required_usage(BindlessBufferUsage::TRANSFER_DST)?;
+		unsafe {
+			let slot = buffer.inner_slot();
+			if !slot.offset.is_multiple_of(4) {
+				return Err(CopyError::UnalignedBufferOffset {
+					name: slot.debug_name.clone(),
+					offset: slot.offset,
+					alignment: 4,
+				}
+				.into());
+			}
+			if !slot.size.is_multiple_of(4) {
+				return Err(CopyError::UnalignedBufferSize {
+					name: slot.debug_name.clone(),
+					size: slot.size,
+					alignment: 4,
+				}
+				.into());
+			}
+			self.platform
+				.fill_buffer(buffer, data)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Write the small amount of `data` into the buffer at element `offset`, by embedding it directly in the command
+	/// stream. The byte offset and size of `data` must be a multiple of 4 and `data` may be at most
+	/// [`UPDATE_BUFFER_MAX_SIZE`] bytes large, use a staging buffer and [`Self::copy_buffer_to_buffer_region`] for
+	/// larger uploads.
+	pub fn update_buffer<T: BufferStruct, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<P, [T], A>,
+		offset: usize,
+		data: &[T],
+	) -> Result<(), RecordingError<P>> {
+		buffer.has_required_usage(BindlessBufferUsage::TRANSFER_DST)?;
+		unsafe {
+			let slot = buffer.inner_slot();
+			validate_buffer_range(slot, offset, data.len())?;
+			if data.is_empty() {
+				return Ok(());
+			}
+			let stride = size_of::<T::Transfer>() as u64;
+			let byte_offset = offset as u64 * stride;
+			let size = data.len() as u64 * stride;
+			if size > UPDATE_BUFFER_MAX_SIZE {
+				return Err(CopyError::UpdateTooLarge {
+					name: slot.debug_name.clone(),
+					size,
+				}
+				.into());
+			}
+			if !(slot.offset + byte_offset).is_multiple_of(4) {
+				return Err(CopyError::UnalignedBufferOffset {
+					name: slot.debug_name.clone(),
+					offset: slot.offset + byte_offset,
+					alignment: 4,
+				}
+				.into());
+			}
+			if !size.is_multiple_of(4) {
+				return Err(CopyError::UnalignedBufferSize {
+					name: slot.debug_name.clone(),
+					size,
+					alignment: 4,
+				}
+				.into());
+			}
+
+			let mut meta = StrongMetadataCpu::new(&self.bindless().table_sync, Metadata {});
+			let transfer = data.iter().map(|t| T::write_cpu(*t, &mut meta)).collect::<Vec<_>>();
+			// the written StrongDesc must be kept alive, like any other write into the buffer
+			slot.strong_refs.lock().merge(meta.into_backing_refs());
+			// Safety: T::Transfer is plain old data
+			let bytes = std::slice::from_raw_parts(transfer.as_ptr().cast::<u8>(), size as usize);
+			self.platform
+				.update_buffer(buffer, byte_offset, bytes)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Clear all mips and layers of an image with a color format to `value`, which must not be
+	/// [`ClearValue::DepthStencil`].
+	pub fn clear_color_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<P, T, A>,
+		value: ClearValue,
+	) -> Result<(), RecordingError<P>> {
+		image.has_required_usage(BindlessImageUsage::TRANSFER_DST)?;
+		unsafe {
+			if matches!(value, ClearValue::DepthStencil { .. }) || !image.inner_slot().format.is_color() {
+				return Err(CopyError::InvalidClearValue {
+					name: image.inner_slot().debug_name.clone(),
+					value,
+				}
+				.into());
+			}
+			self.platform
+				.clear_color_image(image, value)
+				.map_err(Into::<RecordingError<P>>::into)
+		}
+	}
+
+	/// Clear all mips and layers of an image with a depth and / or stencil format to `depth` and `stencil`. `depth` must
+	/// be within `0.0..=1.0`.
+	pub fn clear_depth_stencil_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<P, T, A>,
+		depth: f32,
+		stencil: u32,
+	) -> Result<(), RecordingError<P>> {
+		image.has_required_usage(BindlessImageUsage::TRANSFER_DST)?;
+		unsafe {
+			if !image.inner_slot().format.is_depth_stencil() || !(0. ..=1.).contains(&depth) {
+				return Err(CopyError::InvalidClearValue {
+					name: image.inner_slot().debug_name.clone(),
+					value: ClearValue::DepthStencil { depth, stencil },
+				}
+				.into());
+			}
+			self.platform
+				.clear_depth_stencil_image(image, depth, stencil)
 				.map_err(Into::<RecordingError<P>>::into)
 		}
 	}
@@ -207,6 +498,10 @@ pub enum RecordingError<P: BindlessPipelinePlatform> {
 	CopyError(#[from] CopyError),
 	#[error("Rendering Error: {0}")]
 	RenderingError(#[from] RenderingError),
+	#[error("Buffer Allocation Error: {0}")]
+	BufferAllocationError(#[from] BufferAllocationError<P>),
+	#[error("Image Allocation Error: {0}")]
+	ImageAllocationError(#[from] ImageAllocationError<P>),
 }
 
 impl<P: BindlessPipelinePlatform> Debug for RecordingError<P> {
@@ -216,10 +511,273 @@ impl<P: BindlessPipelinePlatform> Debug for RecordingError<P> {
 }
 
 #[derive(Error)]
-pub enum CopyError {}
+#[non_exhaustive]
+pub enum CopyError {
+	#[error("Buffer \"{name}\" of size {buffer_size} is out of bounds when accessing {size} at offset {offset}")]
+	BufferOutOfBounds {
+		name: String,
+		offset: u64,
+		size: u64,
+		buffer_size: u64,
+	},
+	#[error("Buffer offset {offset} into buffer \"{name}\" must be a multiple of {alignment}")]
+	UnalignedBufferOffset { name: String, offset: u64, alignment: u64 },
+	#[error("Size {size} written to buffer \"{name}\" must be a multiple of {alignment}")]
+	UnalignedBufferSize { name: String, size: u64, alignment: u64 },
+	#[error("Updating buffer \"{name}\" with {size} bytes exceeds the limit of {UPDATE_BUFFER_MAX_SIZE} bytes")]
+	UpdateTooLarge { name: String, size: u64 },
+	#[error(
+		"Buffer \"{name}\" with row length {row_length} and image height {image_height} can't hold a region of {extent:?}"
+	)]
+	InvalidBufferLayout {
+		name: String,
+		row_length: u32,
+		image_height: u32,
+		extent: Extent,
+	},
+	#[error(
+		"Buffer \"{name}\" with row length {row_length} and image height {image_height} is not aligned to the block extent {block_extent:?}"
+	)]
+	UnalignedBufferLayout {
+		name: String,
+		row_length: u32,
+		image_height: u32,
+		block_extent: [u32; 2],
+	},
+	#[error("Image \"{name}\" of format {format:?} can't copy {aspect:?} to or from a buffer, select a single aspect")]
+	InvalidAspect {
+		name: String,
+		format: Format,
+		aspect: FormatAspects,
+	},
+	#[error("Image \"{name}\" has {mip_levels} mip levels, mip {mip} is out of bounds")]
+	MipOutOfBounds { name: String, mip: u32, mip_levels: u32 },
+	#[error("Image \"{name}\" has {array_layers} array layers, layers {layers:?} are out of bounds")]
+	LayersOutOfBounds {
+		name: String,
+		layers: Range<u32>,
+		array_layers: u32,
+	},
+	#[error("Region at {offset:?} of {extent:?} is empty or out of bounds of image \"{name}\" of {mip_extent:?}")]
+	RegionOutOfBounds {
+		name: String,
+		offset: UVec3,
+		extent: Extent,
+		mip_extent: Extent,
+	},
+	#[error(
+		"Region at {offset:?} of {extent:?} of image \"{name}\" is not aligned to its block extent {block_extent:?}"
+	)]
+	UnalignedRegion {
+		name: String,
+		offset: UVec3,
+		extent: Extent,
+		block_extent: [u32; 2],
+	},
+	#[error("Regions {src_region:?} of \"{src}\" and {dst_region:?} of \"{dst}\" differ in size or layer count")]
+	MismatchedRegions {
+		src: String,
+		dst: String,
+		src_region: ImageRegion,
+		dst_region: ImageRegion,
+	},
+	#[error("Image \"{src}\" with {src_samples:?} and image \"{dst}\" with {dst_samples:?} can't be copied or blitted")]
+	MismatchedSamples {
+		src: String,
+		src_samples: SampleCount,
+		dst: String,
+		dst_samples: SampleCount,
+	},
+	#[error(
+		"Image \"{src}\" with format {src_format:?} and image \"{dst}\" with format {dst_format:?} are incompatible"
+	)]
+	IncompatibleFormats {
+		src: String,
+		src_format: Format,
+		dst: String,
+		dst_format: Format,
+	},
+	#[error("Blit from image \"{src}\" to image \"{dst}\" of depth/stencil formats requires Filter::Nearest")]
+	LinearDepthStencilBlit { src: String, dst: String },
+	#[error("Image \"{name}\" has format {format:?}, which does not support blits")]
+	BlitUnsupported { name: String, format: Format },
+	#[error("Image \"{name}\" can't be cleared to {value:?}")]
+	InvalidClearValue { name: String, value: ClearValue },
+	#[error("Image \"{name}\" requires {expected} bytes of data but got {size} bytes")]
+	InvalidDataSize { name: String, size: u64, expected: u64 },
+}
 
 impl Debug for CopyError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		Display::fmt(&self, f)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessBufferCreateInfo, BindlessImageCreateInfo, Image2d, MutDescExt};
+	use crate::pipeline::{MutBufferAccessExt, MutImageAccessExt, TransferWrite};
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+	use crate::platform::mock::{Mock, MockCommand};
+
+	#[test]
+	fn test_generate_mips() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let image = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels: 5,
+			usage: BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+			name: "mipped",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let handle = image.inner_slot().handle;
+		bindless.execute(|cmd| {
+			let image = image.access::<TransferWrite>(cmd)?;
+			cmd.generate_mips(&image)?;
+			Ok(())
+		})?;
+		let executions = bindless.take_executions();
+		assert!(executions[0].commands.contains(&MockCommand::GenerateMips {
+			image: handle,
+			mip_levels: 5,
+		}));
+
+		let sampled = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [16, 16].into(),
+			mip_levels: 5,
+			usage: BindlessImageUsage::TRANSFER_DST,
+			name: "sampled",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let result = bindless.execute(|cmd| {
+			let image = sampled.access::<TransferWrite>(cmd)?;
+			cmd.generate_mips(&image)
+		});
+		assert!(matches!(
+			result,
+			Err(RecordingError::AccessError(AccessError::MissingImageUsage { .. }))
+		));
+
+		let compressed = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::BC7_UNORM_BLOCK,
+			extent: [16, 16].into(),
+			mip_levels: 5,
+			usage: BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+			name: "compressed",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let result = bindless.execute(|cmd| {
+			let image = compressed.access::<TransferWrite>(cmd)?;
+			cmd.generate_mips(&image)
+		});
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::BlitUnsupported {
+				format: Format::BC7_UNORM_BLOCK,
+				..
+			}))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_fill_buffer_usage() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let buffer = bindless.buffer().alloc_slice::<u32>(
+			&BindlessBufferCreateInfo {
+				usage: BindlessBufferUsage::MAP_WRITE,
+				..buffer_ci("no transfer")
+			},
+			8,
+		)?;
+		let result = bindless.execute(|cmd| {
+			let buffer = buffer.access::<TransferWrite>(cmd)?;
+			unsafe { cmd.fill_buffer(&buffer, 0) }
+		});
+		assert!(matches!(
+			result,
+			Err(RecordingError::AccessError(AccessError::MissingBufferUsage { .. }))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_update_buffer_bounds() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let buffer = bindless.buffer().alloc_slice::<u32>(&buffer_ci("buffer"), 8)?;
+		let result = bindless.execute(|cmd| {
+			let buffer = buffer.access::<TransferWrite>(cmd)?;
+			cmd.update_buffer(&buffer, 6, &[1, 2, 3])
+		});
+		assert!(matches!(
+			result,
+			Err(RecordingError::CopyError(CopyError::BufferOutOfBounds { .. }))
+		));
+
+		// empty updates are skipped
+		let buffer = bindless.buffer().alloc_slice::<u32>(&buffer_ci("buffer"), 8)?;
+		bindless.execute(|cmd| {
+			let buffer = buffer.access::<TransferWrite>(cmd)?;
+			cmd.update_buffer(&buffer, 8, &[])
+		})?;
+		let executions = bindless.take_executions();
+		assert!(
+			!executions
+				.iter()
+				.flat_map(|execution| &execution.commands)
+				.any(|command| matches!(command, MockCommand::UpdateBuffer { .. }))
+		);
+		Ok(())
+	}
+
+	fn image_ci(format: Format) -> BindlessImageCreateInfo<'static, Image2d> {
+		BindlessImageCreateInfo {
+			format,
+			extent: [8, 8].into(),
+			usage: BindlessImageUsage::TRANSFER_DST,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		}
+	}
+
+	#[test]
+	fn test_clear_image_values() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let clear = |format: Format, value: ClearValue| -> anyhow::Result<Result<(), RecordingError<Mock>>> {
+			let image = bindless.image().alloc(&image_ci(format))?;
+			Ok(bindless.execute(|cmd| {
+				let image = image.access::<TransferWrite>(cmd)?;
+				match value {
+					ClearValue::DepthStencil { depth, stencil } => {
+						cmd.clear_depth_stencil_image(&image, depth, stencil)
+					}
+					value => cmd.clear_color_image(&image, value),
+				}
+			}))
+		};
+		let invalid = |result: Result<(), RecordingError<Mock>>| {
+			matches!(
+				result,
+				Err(RecordingError::CopyError(CopyError::InvalidClearValue { .. }))
+			)
+		};
+		let depth_stencil = |depth| ClearValue::DepthStencil { depth, stencil: 0 };
+
+		clear(Format::R8G8B8A8_UNORM, ClearValue::ColorF([1.; 4]))??;
+		clear(Format::D32_SFLOAT, depth_stencil(1.))??;
+		// color images can't be cleared to depth and depth images can't be cleared to a color
+		assert!(invalid(clear(Format::R8G8B8A8_UNORM, depth_stencil(1.))?));
+		let image = bindless.image().alloc(&image_ci(Format::R8G8B8A8_UNORM))?;
+		assert!(invalid(bindless.execute(|cmd| {
+			let image = image.access::<TransferWrite>(cmd)?;
+			cmd.clear_color_image(&image, depth_stencil(1.))
+		})));
+		assert!(invalid(clear(Format::D32_SFLOAT, ClearValue::ColorF([0.; 4]))?));
+		// depth must be within 0..=1
+		assert!(invalid(clear(Format::D32_SFLOAT, depth_stencil(1.5))?));
+		Ok(())
+	}
+}
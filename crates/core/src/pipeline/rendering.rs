@@ -1,4 +1,6 @@
-use crate::descriptor::{Bindless, BindlessBufferUsage, Extent, Format};
+use crate::descriptor::{
+	Bindless, BindlessBufferUsage, Extent, Format, FormatAspects, ImageSlot, NumericType, SampleCount,
+};
 use crate::pipeline::access_image::MutImageAccess;
 use crate::pipeline::access_type::{
 	ColorAttachment, DepthStencilAttachment, ImageAccessType, IndexReadable, IndirectCommandReadable,
@@ -8,14 +10,14 @@ use crate::pipeline::mesh_graphics_pipeline::BindlessMeshGraphicsPipeline;
 use crate::pipeline::mut_or_shared::MutOrSharedBuffer;
 use crate::pipeline::recording::{HasResourceContext, Recording, RecordingError};
 use crate::pipeline::rendering::RenderingError::MismatchedColorAttachmentCount;
-use crate::platform::ash::Ash;
 use crate::platform::{BindlessPipelinePlatform, RenderingContext};
 use glam::{IVec2, UVec2};
 use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
-use rust_gpu_bindless_shaders::descriptor::{Image2d, TransientAccess};
+use rust_gpu_bindless_shaders::descriptor::{Image2d, ImageType, ImageTypeImpl, TransientAccess};
 use rust_gpu_bindless_shaders::utils::rect::IRect2;
 use rust_gpu_bindless_shaders::utils::viewport::Viewport;
 use smallvec::SmallVec;
+use spirv_std::image::{Arrayed, Dimensionality};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Deref, DerefMut};
 use thiserror::Error;
@@ -23,11 +25,13 @@ use thiserror::Error;
 pub type DrawIndexedIndirectCommand = spirv_std::indirect_command::DrawIndexedIndirectCommand;
 pub type DrawIndirectCommand = spirv_std::indirect_command::DrawIndirectCommand;
 
-/// A RenderPass defines the formats of the color and depth attachments.
+/// A RenderPass defines the formats and sample count of the color and depth attachments.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct RenderPassFormat {
 	pub color_attachments: SmallVec<[Format; 5]>,
 	pub depth_attachment: Option<Format>,
+	/// The sample count of all attachments and of the pipelines rendering into them
+	pub samples: SampleCount,
 }
 
 impl RenderPassFormat {
@@ -35,8 +39,17 @@ impl RenderPassFormat {
 		RenderPassFormat {
 			color_attachments: SmallVec::from(color_attachments),
 			depth_attachment,
+			samples: SampleCount::Sample1,
 		}
 	}
+
+	/// Render with `samples` per pixel, the attachments must be [`Image2dMs`] with the same sample count
+	///
+	/// [`Image2dMs`]: rust_gpu_bindless_shaders::descriptor::Image2dMs
+	pub fn with_samples(mut self, samples: SampleCount) -> Self {
+		self.samples = samples;
+		self
+	}
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -52,7 +65,7 @@ pub enum StoreOp {
 	DontCare,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ClearValue {
 	ColorF([f32; 4]),
 	ColorU([u32; 4]),
@@ -60,15 +73,48 @@ pub enum ClearValue {
 	DepthStencil { depth: f32, stencil: u32 },
 }
 
-pub struct RenderingAttachment<'a, 'b, P: BindlessPipelinePlatform, A: ImageAccessType> {
-	pub image: &'b mut MutImageAccess<'a, P, Image2d, A>,
+/// How the samples of a multisampled attachment are combined when resolving
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ResolveMode {
+	/// Average of all samples, the only mode valid for color attachments with float or normalized formats
+	Average,
+	/// The value of sample 0, the only mode valid for color attachments with integer formats. Always supported for
+	/// depth stencil attachments.
+	SampleZero,
+	/// The minimum of all samples, only valid for depth stencil attachments and may not be supported by the device
+	Min,
+	/// The maximum of all samples, only valid for depth stencil attachments and may not be supported by the device
+	Max,
+}
+
+/// The image types that can be rendered into: [`Image2d`], or [`Image2dMs`] for multisampled render passes
+///
+/// [`Image2dMs`]: rust_gpu_bindless_shaders::descriptor::Image2dMs
+pub trait RenderingImageType: ImageType {}
+
+impl<const MULTISAMPLED: u32> RenderingImageType
+	for ImageTypeImpl<f32, { Dimensionality::TwoD as u32 }, { Arrayed::False as u32 }, MULTISAMPLED>
+{
+}
+
+pub struct RenderingAttachment<'a, 'b, P: BindlessPipelinePlatform, A: ImageAccessType, T: RenderingImageType = Image2d>
+{
+	pub image: &'b mut MutImageAccess<'a, P, T, A>,
 	pub load_op: LoadOp,
 	pub store_op: StoreOp,
+	/// The single-sampled image the multisampled `image` is resolved into at the end of rendering
+	pub resolve: Option<ResolveAttachment<'a, 'b, P, A>>,
+}
+
+pub struct ResolveAttachment<'a, 'b, P: BindlessPipelinePlatform, A: ImageAccessType> {
+	pub image: &'b mut MutImageAccess<'a, P, Image2d, A>,
+	pub mode: ResolveMode,
 }
 
 pub struct Rendering<'a: 'b, 'b, P: BindlessPipelinePlatform> {
 	platform: P::RenderingContext<'a, 'b>,
 	extent: UVec2,
+	samples: SampleCount,
 }
 
 unsafe impl<'a, P: BindlessPipelinePlatform> TransientAccess<'a> for Rendering<'a, '_, P> {}
@@ -91,7 +137,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> DerefMut for Rendering<'a, 'b, P>
 
 unsafe impl<'a: 'b, 'b, P: BindlessPipelinePlatform> HasResourceContext<'a, P> for Rendering<'a, 'b, P> {
 	#[inline]
-	fn bindless(&self) -> &Bindless<Ash> {
+	fn bindless(&self) -> &Bindless<P> {
 		self.platform.bindless()
 	}
 
@@ -102,11 +148,15 @@ unsafe impl<'a: 'b, 'b, P: BindlessPipelinePlatform> HasResourceContext<'a, P> f
 }
 
 impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
-	pub fn begin_rendering(
+	/// Begin rendering into `color_attachments` and `depth_attachment`, which must match `format`. If `format` is
+	/// multisampled, the attachments must be [`Image2dMs`] and may be resolved into single-sampled images.
+	///
+	/// [`Image2dMs`]: rust_gpu_bindless_shaders::descriptor::Image2dMs
+	pub fn begin_rendering<T: RenderingImageType>(
 		&mut self,
 		format: RenderPassFormat,
-		color_attachments: &[RenderingAttachment<'a, '_, P, ColorAttachment>],
-		depth_attachment: Option<RenderingAttachment<'a, '_, P, DepthStencilAttachment>>,
+		color_attachments: &[RenderingAttachment<'a, '_, P, ColorAttachment, T>],
+		depth_attachment: Option<RenderingAttachment<'a, '_, P, DepthStencilAttachment, T>>,
 		f: impl FnOnce(&mut Rendering<'a, '_, P>) -> Result<(), RecordingError<P>>,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
@@ -121,6 +171,8 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 						}
 						.into());
 					}
+					validate_samples(slot, &format)?;
+					validate_resolve(self.bindless(), depth_attachment, &format, slot.extent, true)?;
 					slot.extent
 				} else {
 					return Err(RenderingError::DepthAttachmentMissing.into());
@@ -165,9 +217,12 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 					}
 					.into());
 				}
+				validate_samples(slot, &format)?;
+				validate_resolve(self.bindless(), x, &format, extent, false)?;
 			}
 
 			let extent = UVec2::from(extent);
+			let samples = format.samples;
 			let mut rendering: Rendering<'a, '_, P> = Rendering {
 				platform: <P::RenderingContext<'a, '_> as RenderingContext<P>>::begin_rendering(
 					self.inner_mut(),
@@ -178,6 +233,7 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 				)
 				.map_err(Into::<RecordingError<P>>::into)?,
 				extent,
+				samples,
 			};
 			rendering.set_viewport_to_extent();
 			rendering.set_scissor_to_extent();
@@ -191,7 +247,77 @@ impl<'a, P: BindlessPipelinePlatform> Recording<'a, P> {
 	}
 }
 
+fn validate_samples<P: BindlessPipelinePlatform>(
+	slot: &ImageSlot<P>,
+	format: &RenderPassFormat,
+) -> Result<(), RenderingError> {
+	if slot.samples != format.samples {
+		return Err(RenderingError::MismatchedAttachmentSamples {
+			name: slot.debug_name.to_string(),
+			samples: slot.samples,
+			expected: format.samples,
+		});
+	}
+	Ok(())
+}
+
+unsafe fn validate_resolve<P: BindlessPipelinePlatform, A: ImageAccessType, T: RenderingImageType>(
+	bindless: &Bindless<P>,
+	attachment: &RenderingAttachment<'_, '_, P, A, T>,
+	format: &RenderPassFormat,
+	extent: Extent,
+	depth: bool,
+) -> Result<(), RenderingError> {
+	unsafe {
+		let Some(resolve) = &attachment.resolve else {
+			return Ok(());
+		};
+		let slot = attachment.image.inner_slot();
+		let resolve_slot = resolve.image.inner_slot();
+		let reason = if format.samples == SampleCount::Sample1 {
+			Some("the render pass is not multisampled")
+		} else if resolve_slot.format != slot.format {
+			Some("its format differs from the attachment")
+		} else if resolve_slot.extent != extent {
+			Some("its size differs from the attachment")
+		} else if depth && resolve.mode == ResolveMode::Average {
+			Some("depth attachments can't be resolved with ResolveMode::Average")
+		} else if depth {
+			let supported = [FormatAspects::DEPTH, FormatAspects::STENCIL]
+				.into_iter()
+				.filter(|aspect| slot.format.aspects().contains(*aspect))
+				.all(|aspect| P::supports_depth_stencil_resolve(bindless, resolve.mode, aspect));
+			(!supported)
+				.then_some("the device doesn't support resolving its depth or stencil aspect with this ResolveMode")
+		} else if matches!(slot.format.numeric_type(), Some(NumericType::UInt | NumericType::SInt)) {
+			(resolve.mode != ResolveMode::SampleZero)
+				.then_some("integer color attachments can only be resolved with ResolveMode::SampleZero")
+		} else {
+			(resolve.mode != ResolveMode::Average)
+				.then_some("non-integer color attachments can only be resolved with ResolveMode::Average")
+		};
+		match reason {
+			None => Ok(()),
+			Some(reason) => Err(RenderingError::InvalidResolveAttachment {
+				name: resolve_slot.debug_name.to_string(),
+				attachment: slot.debug_name.to_string(),
+				reason,
+			}),
+		}
+	}
+}
+
 impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
+	fn validate_pipeline_samples(&self, samples: SampleCount) -> Result<(), RenderingError> {
+		if samples != self.samples {
+			return Err(RenderingError::MismatchedPipelineSamples {
+				samples,
+				expected: self.samples,
+			});
+		}
+		Ok(())
+	}
+
 	pub fn draw<T: BufferStruct>(
 		&mut self,
 		pipeline: &BindlessGraphicsPipeline<P, T>,
@@ -199,6 +325,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
 		param: T,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
+			self.validate_pipeline_samples(pipeline.samples())?;
 			self.platform
 				.draw(pipeline, count, param)
 				.map_err(Into::<RecordingError<P>>::into)
@@ -213,6 +340,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
 		param: T,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
+			self.validate_pipeline_samples(pipeline.samples())?;
 			index_buffer.has_required_usage(BindlessBufferUsage::INDEX_BUFFER)?;
 			self.platform
 				.draw_indexed(pipeline, index_buffer, count, param)
@@ -227,6 +355,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
 		param: T,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
+			self.validate_pipeline_samples(pipeline.samples())?;
 			indirect.has_required_usage(BindlessBufferUsage::INDIRECT_BUFFER)?;
 			self.platform
 				.draw_indirect(pipeline, indirect, param)
@@ -247,6 +376,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
 		param: T,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
+			self.validate_pipeline_samples(pipeline.samples())?;
 			index_buffer.has_required_usage(BindlessBufferUsage::INDEX_BUFFER)?;
 			indirect.has_required_usage(BindlessBufferUsage::INDIRECT_BUFFER)?;
 			self.platform
@@ -262,6 +392,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
 		param: T,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
+			self.validate_pipeline_samples(pipeline.samples())?;
 			self.platform
 				.draw_mesh_tasks(pipeline, group_counts, param)
 				.map_err(Into::<RecordingError<P>>::into)
@@ -275,6 +406,7 @@ impl<'a: 'b, 'b, P: BindlessPipelinePlatform> Rendering<'a, 'b, P> {
 		param: T,
 	) -> Result<(), RecordingError<P>> {
 		unsafe {
+			self.validate_pipeline_samples(pipeline.samples())?;
 			indirect.has_required_usage(BindlessBufferUsage::INDIRECT_BUFFER)?;
 			self.platform
 				.draw_mesh_tasks_indirect(pipeline, indirect, param)
@@ -346,6 +478,23 @@ pub enum RenderingError {
 		size: Extent,
 		expected_size: Extent,
 	},
+	#[error("Attachment \"{name}\" has {samples:?} but the RenderPassFormat expects {expected:?}")]
+	MismatchedAttachmentSamples {
+		name: String,
+		samples: SampleCount,
+		expected: SampleCount,
+	},
+	#[error("Resolve attachment \"{name}\" of attachment \"{attachment}\" is invalid: {reason}")]
+	InvalidResolveAttachment {
+		name: String,
+		attachment: String,
+		reason: &'static str,
+	},
+	#[error("Pipeline was created for {samples:?} but the render pass has {expected:?}")]
+	MismatchedPipelineSamples {
+		samples: SampleCount,
+		expected: SampleCount,
+	},
 }
 
 impl Debug for RenderingError {
@@ -370,3 +519,170 @@ impl IndexTypeTrait for u32 {
 impl IndexTypeTrait for u16 {
 	const INDEX_TYPE: IndexType = IndexType::U16;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessImageCreateInfo, BindlessImageUsage, Image2dMs, MutDescExt};
+	use crate::pipeline::MutImageAccessExt;
+	use crate::platform::mock::MockCommand;
+	use crate::platform::mock::test_utils::mock_bindless;
+
+	#[test]
+	fn test_msaa_resolve() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		fn image_ci<T: ImageType>(samples: SampleCount, name: &str) -> BindlessImageCreateInfo<'_, T> {
+			BindlessImageCreateInfo {
+				format: Format::R8G8B8A8_UNORM,
+				extent: [16, 16].into(),
+				samples,
+				usage: BindlessImageUsage::COLOR_ATTACHMENT,
+				name,
+				..BindlessImageCreateInfo::default()
+			}
+		}
+		let render_pass = RenderPassFormat::new(&[Format::R8G8B8A8_UNORM], None).with_samples(SampleCount::Sample4);
+		let msaa = bindless
+			.image()
+			.alloc::<Image2dMs>(&image_ci(SampleCount::Sample4, "msaa"))?;
+		let resolve = bindless
+			.image()
+			.alloc::<Image2d>(&image_ci(SampleCount::Sample1, "resolve"))?;
+		let msaa_handle = msaa.inner_slot().handle;
+		let resolve_handle = resolve.inner_slot().handle;
+
+		bindless.execute(|cmd| {
+			let mut msaa = msaa.access::<ColorAttachment>(cmd)?;
+			let mut resolve = resolve.access::<ColorAttachment>(cmd)?;
+			cmd.begin_rendering(
+				render_pass.clone(),
+				&[RenderingAttachment {
+					image: &mut msaa,
+					load_op: LoadOp::DontCare,
+					store_op: StoreOp::DontCare,
+					resolve: Some(ResolveAttachment {
+						image: &mut resolve,
+						mode: ResolveMode::Average,
+					}),
+				}],
+				None,
+				|_| Ok(()),
+			)
+		})?;
+		let executions = bindless.take_executions();
+		assert!(executions[0].commands.contains(&MockCommand::BeginRendering {
+			render_area: UVec2::new(16, 16),
+			color_attachments: SmallVec::from_slice(&[msaa_handle]),
+			depth_attachment: None,
+			resolve_attachments: SmallVec::from_slice(&[resolve_handle]),
+		}));
+
+		let single_sampled = bindless
+			.image()
+			.alloc::<Image2d>(&image_ci(SampleCount::Sample1, "single sampled"))?;
+		let result = bindless.execute(|cmd| {
+			let mut image = single_sampled.access::<ColorAttachment>(cmd)?;
+			cmd.begin_rendering(
+				render_pass.clone(),
+				&[RenderingAttachment {
+					image: &mut image,
+					load_op: LoadOp::DontCare,
+					store_op: StoreOp::DontCare,
+					resolve: None,
+				}],
+				None,
+				|_| Ok(()),
+			)
+		});
+		assert!(matches!(
+			result,
+			Err(RecordingError::RenderingError(
+				RenderingError::MismatchedAttachmentSamples { .. }
+			))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_resolve_modes() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let resolve = |format: Format, mode: ResolveMode| {
+			let depth = format.is_depth_stencil();
+			let usage = if depth {
+				BindlessImageUsage::DEPTH_STENCIL_ATTACHMENT
+			} else {
+				BindlessImageUsage::COLOR_ATTACHMENT
+			};
+			let msaa = bindless.image().alloc::<Image2dMs>(&BindlessImageCreateInfo {
+				format,
+				extent: [16, 16].into(),
+				samples: SampleCount::Sample4,
+				usage,
+				name: "msaa",
+				..BindlessImageCreateInfo::default()
+			})?;
+			let resolve = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+				format,
+				extent: [16, 16].into(),
+				usage,
+				name: "resolve",
+				..BindlessImageCreateInfo::default()
+			})?;
+			let result = bindless.execute(|cmd| {
+				if depth {
+					let mut msaa = msaa.access::<DepthStencilAttachment>(cmd)?;
+					let mut resolve = resolve.access::<DepthStencilAttachment>(cmd)?;
+					cmd.begin_rendering(
+						RenderPassFormat::new(&[], Some(format)).with_samples(SampleCount::Sample4),
+						&[],
+						Some(RenderingAttachment {
+							image: &mut msaa,
+							load_op: LoadOp::DontCare,
+							store_op: StoreOp::DontCare,
+							resolve: Some(ResolveAttachment {
+								image: &mut resolve,
+								mode,
+							}),
+						}),
+						|_| Ok(()),
+					)
+				} else {
+					let mut msaa = msaa.access::<ColorAttachment>(cmd)?;
+					let mut resolve = resolve.access::<ColorAttachment>(cmd)?;
+					cmd.begin_rendering(
+						RenderPassFormat::new(&[format], None).with_samples(SampleCount::Sample4),
+						&[RenderingAttachment {
+							image: &mut msaa,
+							load_op: LoadOp::DontCare,
+							store_op: StoreOp::DontCare,
+							resolve: Some(ResolveAttachment {
+								image: &mut resolve,
+								mode,
+							}),
+						}],
+						None,
+						|_| Ok(()),
+					)
+				}
+			});
+			anyhow::Ok(match result {
+				Ok(()) => true,
+				Err(RecordingError::RenderingError(RenderingError::InvalidResolveAttachment { .. })) => false,
+				Err(e) => return Err(e.into()),
+			})
+		};
+
+		assert!(resolve(Format::R8G8B8A8_UNORM, ResolveMode::Average)?);
+		assert!(!resolve(Format::R8G8B8A8_UNORM, ResolveMode::Max)?);
+		// integer formats can't be averaged
+		assert!(resolve(Format::R8G8B8A8_UINT, ResolveMode::SampleZero)?);
+		assert!(!resolve(Format::R8G8B8A8_UINT, ResolveMode::Average)?);
+		assert!(!resolve(Format::R32_SINT, ResolveMode::Average)?);
+		// depth and stencil are limited to the modes supported by the device
+		assert!(resolve(Format::D32_SFLOAT, ResolveMode::Max)?);
+		assert!(!resolve(Format::D32_SFLOAT, ResolveMode::Average)?);
+		assert!(resolve(Format::D24_UNORM_S8_UINT, ResolveMode::SampleZero)?);
+		assert!(!resolve(Format::D24_UNORM_S8_UINT, ResolveMode::Min)?);
+		Ok(())
+	}
+}
@@ -2,14 +2,14 @@ use crate::backing::range_set::{DescriptorIndexIterator, DescriptorIndexRangeSet
 use crate::backing::table::DrainFlushQueue;
 use crate::descriptor::{
 	Bindless, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessImageCreateInfo, BindlessImageUsage,
-	BindlessSamplerCreateInfo, BufferAllocationError, BufferInterface, BufferSlot, DescriptorCounts,
-	ImageAllocationError, ImageInterface, SamplerAllocationError, SamplerInterface, WeakBindless,
+	BindlessImageViewCreateInfo, BindlessSamplerCreateInfo, BufferAllocationError, BufferInterface, BufferSlot,
+	CustomTableBinding, DescriptorCounts, Format, ImageAllocationError, ImageInterface, ImageSlot, ReductionMode,
+	SamplerAllocationError, SamplerInterface, WeakBindless,
 };
 use crate::platform::BindlessPlatform;
-use crate::platform::ash::image_format::FormatExt;
 use crate::platform::ash::{
-	AshExecutionManager, AshPendingExecution, bindless_image_type_to_vk_image_type,
-	bindless_image_type_to_vk_image_view_type,
+	AshExecutionManager, AshPendingExecution, bindless_image_type_to_vk_image_create_flags,
+	bindless_image_type_to_vk_image_type, bindless_image_type_to_vk_image_view_type,
 };
 use ash::ext::{debug_utils, mesh_shader};
 use ash::khr::{surface, swapchain};
@@ -18,10 +18,11 @@ use ash::vk::{
 	ComponentMapping, DebugUtilsObjectNameInfoEXT, DescriptorBindingFlags, DescriptorBufferInfo, DescriptorImageInfo,
 	DescriptorPool, DescriptorPoolCreateFlags, DescriptorPoolCreateInfo, DescriptorPoolSize, DescriptorSet,
 	DescriptorSetAllocateInfo, DescriptorSetLayout, DescriptorSetLayoutBindingFlagsCreateInfo,
-	DescriptorSetLayoutCreateFlags, DescriptorSetLayoutCreateInfo, DescriptorType, Handle, ImageLayout,
-	ImageSubresourceRange, ImageTiling, ImageViewCreateInfo, LOD_CLAMP_NONE, PhysicalDeviceProperties2,
+	DescriptorSetLayoutCreateFlags, DescriptorSetLayoutCreateInfo, DescriptorType, FormatFeatureFlags, Handle,
+	ImageLayout, ImageSubresourceRange, ImageTiling, ImageViewCreateInfo, ImageViewUsageCreateInfo, LOD_CLAMP_NONE,
+	PhysicalDeviceFeatures2, PhysicalDeviceProperties2, PhysicalDeviceVulkan12Features,
 	PhysicalDeviceVulkan12Properties, PipelineCache, PipelineLayout, PipelineLayoutCreateInfo, PushConstantRange,
-	SamplerCreateInfo, ShaderStageFlags, SharingMode, WriteDescriptorSet,
+	SamplerCreateInfo, SamplerReductionModeCreateInfo, ShaderStageFlags, SharingMode, TRUE, WriteDescriptorSet,
 };
 use gpu_allocator::AllocationError;
 use gpu_allocator::vulkan::{Allocation, AllocationCreateDesc, Allocator};
@@ -70,26 +71,50 @@ impl Ash {
 		&self,
 		image: ash::vk::Image,
 		create_info: &BindlessImageCreateInfo<T>,
+	) -> Result<Option<ash::vk::ImageView>, <Ash as BindlessPlatform>::AllocationError> {
+		unsafe {
+			self.create_image_view_range::<T>(
+				image,
+				create_info.usage,
+				create_info.format,
+				ImageSubresourceRange {
+					aspect_mask: create_info.format.aspects().to_ash_image_aspect_flags(),
+					base_mip_level: 0,
+					level_count: create_info.mip_levels,
+					base_array_layer: 0,
+					layer_count: create_info.array_layers,
+				},
+				create_info.name,
+			)
+		}
+	}
+
+	/// Creates an image view of `subresource_range` of `image`, or `None` if `usage` does not require an image view.
+	/// `usage` must be a subset of the usage `image` was created with, and is the usage of the view.
+	unsafe fn create_image_view_range<T: ImageType>(
+		&self,
+		image: ash::vk::Image,
+		usage: BindlessImageUsage,
+		format: Format,
+		subresource_range: ImageSubresourceRange,
+		name: &str,
 	) -> Result<Option<ash::vk::ImageView>, <Ash as BindlessPlatform>::AllocationError> {
 		unsafe {
 			let image_view_type = bindless_image_type_to_vk_image_view_type::<T>().expect("Unsupported ImageType");
-			Ok(if create_info.usage.has_image_view() {
+			Ok(if usage.has_image_view() {
+				// views of a different format may only support a subset of the usage of the image
+				let mut usage_info = ImageViewUsageCreateInfo::default().usage(usage.to_ash_image_usage_flags());
 				let image_view = self.device.create_image_view(
 					&ImageViewCreateInfo::default()
 						.image(image)
 						.view_type(image_view_type)
-						.format(create_info.format)
+						.format(format.to_ash_format())
 						.components(ComponentMapping::default()) // identity
-						.subresource_range(ImageSubresourceRange {
-							aspect_mask: create_info.format.aspect(),
-							base_mip_level: 0,
-							level_count: create_info.mip_levels,
-							base_array_layer: 0,
-							layer_count: create_info.array_layers,
-						}),
+						.subresource_range(subresource_range)
+						.push_next(&mut usage_info),
 					None,
 				)?;
-				self.set_debug_object_name(image_view, create_info.name)?;
+				self.set_debug_object_name(image_view, name)?;
 				Some(image_view)
 			} else {
 				None
@@ -283,6 +308,7 @@ unsafe impl BindlessPlatform for Ash {
 	type Sampler = ash::vk::Sampler;
 	type AllocationError = AshAllocationError;
 	type BindlessDescriptorSet = AshBindlessDescriptorSet;
+	type CustomBindingInfo = DescriptorType;
 	type PendingExecution = AshPendingExecution;
 
 	unsafe fn create_platform(
@@ -309,9 +335,13 @@ unsafe impl BindlessPlatform for Ash {
 		}
 	}
 
-	unsafe fn create_descriptor_set(&self, counts: DescriptorCounts) -> Self::BindlessDescriptorSet {
+	unsafe fn create_descriptor_set(
+		&self,
+		counts: DescriptorCounts,
+		custom_tables: &[CustomTableBinding<Self>],
+	) -> Self::BindlessDescriptorSet {
 		unsafe {
-			let bindings = [
+			let mut bindings = vec![
 				ash::vk::DescriptorSetLayoutBinding::default()
 					.binding(BINDING_BUFFER)
 					.descriptor_type(DescriptorType::STORAGE_BUFFER)
@@ -333,9 +363,19 @@ unsafe impl BindlessPlatform for Ash {
 					.descriptor_count(counts.samplers)
 					.stage_flags(self.shader_stages),
 			];
-			let binding_flags = [DescriptorBindingFlags::UPDATE_AFTER_BIND
-				| DescriptorBindingFlags::UPDATE_UNUSED_WHILE_PENDING
-				| DescriptorBindingFlags::PARTIALLY_BOUND; 4];
+			bindings.extend(custom_tables.iter().map(|custom| {
+				ash::vk::DescriptorSetLayoutBinding::default()
+					.binding(custom.binding)
+					.descriptor_type(custom.info)
+					.descriptor_count(custom.count)
+					.stage_flags(self.shader_stages)
+			}));
+			let binding_flags = vec![
+				DescriptorBindingFlags::UPDATE_AFTER_BIND
+					| DescriptorBindingFlags::UPDATE_UNUSED_WHILE_PENDING
+					| DescriptorBindingFlags::PARTIALLY_BOUND;
+				bindings.len()
+			];
 			assert_eq!(bindings.len(), binding_flags.len());
 
 			let set_layout = self
@@ -370,11 +410,16 @@ unsafe impl BindlessPlatform for Ash {
 				.create_descriptor_pool(
 					&DescriptorPoolCreateInfo::default()
 						.flags(DescriptorPoolCreateFlags::UPDATE_AFTER_BIND)
-						.pool_sizes(&bindings.map(|b| {
-							DescriptorPoolSize::default()
-								.ty(b.descriptor_type)
-								.descriptor_count(b.descriptor_count)
-						}))
+						.pool_sizes(
+							&bindings
+								.iter()
+								.map(|b| {
+									DescriptorPoolSize::default()
+										.ty(b.descriptor_type)
+										.descriptor_count(b.descriptor_count)
+								})
+								.collect::<Vec<_>>(),
+						)
 						.max_sets(1),
 					None,
 				)
@@ -431,7 +476,7 @@ unsafe impl BindlessPlatform for Ash {
 				.map(|(_, buffer)| {
 					DescriptorBufferInfo::default()
 						.buffer(buffer.buffer)
-						.offset(0)
+						.offset(buffer.offset)
 						.range(buffer.size)
 				})
 				.collect::<Vec<_>>();
@@ -582,6 +627,58 @@ unsafe impl BindlessPlatform for Ash {
 		}
 	}
 
+	unsafe fn min_buffer_offset_alignment(&self) -> u64 {
+		unsafe {
+			let limits = self
+				.instance
+				.get_physical_device_properties(self.physical_device)
+				.limits;
+			u64::max(
+				limits.min_storage_buffer_offset_alignment,
+				limits.min_uniform_buffer_offset_alignment,
+			)
+		}
+	}
+
+	unsafe fn supports_blit(&self, format: Format) -> bool {
+		unsafe {
+			self.instance
+				.get_physical_device_format_properties(self.physical_device, format.to_ash_format())
+				.optimal_tiling_features
+				.contains(FormatFeatureFlags::BLIT_SRC | FormatFeatureFlags::BLIT_DST)
+		}
+	}
+
+	unsafe fn supported_view_usage(&self, format: Format) -> BindlessImageUsage {
+		unsafe {
+			let features = self
+				.instance
+				.get_physical_device_format_properties(self.physical_device, format.to_ash_format())
+				.optimal_tiling_features;
+			let mut usage = BindlessImageUsage::empty();
+			if features.contains(FormatFeatureFlags::SAMPLED_IMAGE) {
+				usage |= BindlessImageUsage::SAMPLED;
+			}
+			if features.contains(FormatFeatureFlags::STORAGE_IMAGE) {
+				usage |= BindlessImageUsage::STORAGE;
+			}
+			if features.contains(FormatFeatureFlags::COLOR_ATTACHMENT) {
+				usage |= BindlessImageUsage::COLOR_ATTACHMENT;
+			}
+			if features.contains(FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT) {
+				usage |= BindlessImageUsage::DEPTH_STENCIL_ATTACHMENT;
+			}
+			usage
+		}
+	}
+
+	unsafe fn alias_buffer(&self, buffer: &Self::Buffer) -> Self::Buffer {
+		AshBuffer {
+			buffer: buffer.buffer,
+			allocation: AshMemoryAllocation::none(),
+		}
+	}
+
 	unsafe fn alloc_image<T: ImageType>(
 		&self,
 		create_info: &BindlessImageCreateInfo<T>,
@@ -590,9 +687,12 @@ unsafe impl BindlessPlatform for Ash {
 			let image_type = bindless_image_type_to_vk_image_type::<T>().expect("Unsupported ImageType");
 			let image = self.device.create_image(
 				&ash::vk::ImageCreateInfo::default()
-					.flags(ash::vk::ImageCreateFlags::empty())
+					.flags(
+						create_info.usage.to_ash_image_create_flags()
+							| bindless_image_type_to_vk_image_create_flags::<T>(),
+					)
 					.image_type(image_type)
-					.format(create_info.format)
+					.format(create_info.format.to_ash_format())
 					.extent(create_info.extent.into())
 					.mip_levels(create_info.mip_levels)
 					.array_layers(create_info.array_layers)
@@ -623,26 +723,83 @@ unsafe impl BindlessPlatform for Ash {
 		}
 	}
 
+	unsafe fn alloc_image_view<V: ImageType>(
+		&self,
+		image: &ImageSlot<Self>,
+		create_info: &BindlessImageViewCreateInfo,
+		usage: BindlessImageUsage,
+	) -> Result<Self::Image, Self::AllocationError> {
+		unsafe {
+			let format = create_info.format.unwrap_or(image.format);
+			let image_view = self.create_image_view_range::<V>(
+				image.image,
+				usage,
+				format,
+				ImageSubresourceRange {
+					aspect_mask: format.aspects().to_ash_image_aspect_flags(),
+					base_mip_level: image.base_mip + create_info.base_mip,
+					level_count: create_info.mip_count,
+					base_array_layer: image.base_layer + create_info.base_layer,
+					layer_count: create_info.layer_count,
+				},
+				create_info.name,
+			)?;
+			Ok(AshImage {
+				image: image.image,
+				image_view,
+				allocation: AshMemoryAllocation::none(),
+			})
+		}
+	}
+
+	unsafe fn max_sampler_lod_bias(&self) -> f32 {
+		unsafe {
+			self.instance
+				.get_physical_device_properties(self.physical_device)
+				.limits
+				.max_sampler_lod_bias
+		}
+	}
+
+	unsafe fn supports_sampler_reduction(&self) -> bool {
+		unsafe {
+			let mut features_vk12 = PhysicalDeviceVulkan12Features::default();
+			self.instance.get_physical_device_features2(
+				self.physical_device,
+				&mut PhysicalDeviceFeatures2::default().push_next(&mut features_vk12),
+			);
+			features_vk12.sampler_filter_minmax == TRUE
+		}
+	}
+
 	unsafe fn alloc_sampler(
 		&self,
 		create_info: &BindlessSamplerCreateInfo,
 	) -> Result<Self::Sampler, Self::AllocationError> {
 		unsafe {
-			Ok(self.device.create_sampler(
-				&SamplerCreateInfo::default()
-					.mag_filter(create_info.mag_filter.to_ash_filter())
-					.min_filter(create_info.min_filter.to_ash_filter())
-					.mipmap_mode(create_info.mipmap_mode.to_ash_mipmap_mode())
-					.address_mode_u(create_info.address_mode_u.to_ash_address_mode())
-					.address_mode_v(create_info.address_mode_v.to_ash_address_mode())
-					.address_mode_w(create_info.address_mode_w.to_ash_address_mode())
-					.anisotropy_enable(create_info.max_anisotropy.is_some())
-					.max_anisotropy(create_info.max_anisotropy.unwrap_or(1.0))
-					.min_lod(create_info.min_lod)
-					.max_lod(create_info.max_lod.unwrap_or(LOD_CLAMP_NONE))
-					.border_color(create_info.border_color.to_ash_border_color(false)),
-				None,
-			)?)
+			let mut reduction_mode = SamplerReductionModeCreateInfo::default()
+				.reduction_mode(create_info.reduction_mode.to_ash_reduction_mode());
+			let mut info = SamplerCreateInfo::default()
+				.mag_filter(create_info.mag_filter.to_ash_filter())
+				.min_filter(create_info.min_filter.to_ash_filter())
+				.mipmap_mode(create_info.mipmap_mode.to_ash_mipmap_mode())
+				.address_mode_u(create_info.address_mode_u.to_ash_address_mode())
+				.address_mode_v(create_info.address_mode_v.to_ash_address_mode())
+				.address_mode_w(create_info.address_mode_w.to_ash_address_mode())
+				.mip_lod_bias(create_info.mip_lod_bias)
+				.anisotropy_enable(create_info.max_anisotropy.is_some())
+				.max_anisotropy(create_info.max_anisotropy.unwrap_or(1.0))
+				.compare_enable(create_info.compare_op.is_some())
+				.compare_op(create_info.compare_op.unwrap_or_default().to_ash_compare_op())
+				.min_lod(create_info.min_lod)
+				.max_lod(create_info.max_lod.unwrap_or(LOD_CLAMP_NONE))
+				.border_color(create_info.border_color.to_ash_border_color(false))
+				.unnormalized_coordinates(create_info.unnormalized_coordinates);
+			// only chain the reduction mode if used, so devices without samplerFilterMinmax are fine by default
+			if create_info.reduction_mode != ReductionMode::WeightedAverage {
+				info = info.push_next(&mut reduction_mode);
+			}
+			Ok(self.device.create_sampler(&info, None)?)
 		}
 	}
 
@@ -686,8 +843,8 @@ unsafe impl BindlessPlatform for Ash {
 				if let Some(imageview) = image.image_view {
 					self.device.destroy_image_view(imageview, None);
 				}
-				// do not destroy swapchain images
-				if !image.usage.contains(BindlessImageUsage::SWAPCHAIN) {
+				// do not destroy swapchain images or the image of views
+				if !image.usage.contains(BindlessImageUsage::SWAPCHAIN) && !image.is_view() {
 					self.device.destroy_image(image.image, None);
 				}
 			}
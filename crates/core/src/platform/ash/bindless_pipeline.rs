@@ -1,8 +1,7 @@
-use crate::descriptor::Bindless;
+use crate::descriptor::{Bindless, Format, FormatAspects};
 use crate::pipeline::{
-	GraphicsPipelineCreateInfo, MeshGraphicsPipelineCreateInfo, PipelineColorBlendStateCreateInfo,
-	PipelineDepthStencilStateCreateInfo, PipelineInputAssemblyStateCreateInfo, Recording, RecordingError,
-	RenderPassFormat,
+	ColorBlendAttachment, ColorBlendState, DepthStencilState, GraphicsPipelineCreateInfo, InputAssemblyState,
+	MeshGraphicsPipelineCreateInfo, RasterizationState, Recording, RecordingError, RenderPassFormat, ResolveMode,
 };
 use crate::platform::BindlessPipelinePlatform;
 use crate::platform::ash::rendering::AshRenderingContext;
@@ -11,11 +10,11 @@ use crate::platform::ash::{
 };
 use ash::prelude::VkResult;
 use ash::vk::{
-	ComputePipelineCreateInfo, DynamicState, Extent2D, Offset2D, Pipeline, PipelineCache,
-	PipelineDynamicStateCreateInfo, PipelineMultisampleStateCreateInfo, PipelineRasterizationStateCreateInfo,
-	PipelineRenderingCreateInfo, PipelineShaderStageCreateInfo, PipelineTessellationStateCreateInfo,
-	PipelineVertexInputStateCreateInfo, PipelineViewportStateCreateInfo, Rect2D, SampleCountFlags, ShaderModule,
-	ShaderModuleCreateInfo, Viewport,
+	ComputePipelineCreateInfo, DynamicState, Extent2D, Offset2D, PhysicalDeviceDepthStencilResolveProperties,
+	PhysicalDeviceProperties2, Pipeline, PipelineCache, PipelineColorBlendStateCreateInfo,
+	PipelineDynamicStateCreateInfo, PipelineMultisampleStateCreateInfo, PipelineRenderingCreateInfo,
+	PipelineShaderStageCreateInfo, PipelineTessellationStateCreateInfo, PipelineVertexInputStateCreateInfo,
+	PipelineViewportStateCreateInfo, Rect2D, ShaderModule, ShaderModuleCreateInfo, Viewport,
 };
 use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
 use rust_gpu_bindless_shaders::shader::BindlessShader;
@@ -81,10 +80,10 @@ unsafe impl BindlessPipelinePlatform for Ash {
 			Ok(AshGraphicsPipeline(Self::ash_create_abstract_graphics_pipeline(
 				bindless,
 				render_pass,
-				create_info.input_assembly_state,
-				create_info.rasterization_state,
-				create_info.depth_stencil_state,
-				create_info.color_blend_state,
+				&create_info.input_assembly_state,
+				&create_info.rasterization_state,
+				&create_info.depth_stencil_state,
+				&create_info.color_blend_state,
 				&[
 					vertex.to_shader_stage_create_info(),
 					fragment.to_shader_stage_create_info(),
@@ -118,14 +117,34 @@ unsafe impl BindlessPipelinePlatform for Ash {
 			Ok(AshMeshGraphicsPipeline(Self::ash_create_abstract_graphics_pipeline(
 				bindless,
 				render_pass,
-				PipelineInputAssemblyStateCreateInfo::default(),
-				create_info.rasterization_state,
-				create_info.depth_stencil_state,
-				create_info.color_blend_state,
+				&InputAssemblyState::default(),
+				&create_info.rasterization_state,
+				&create_info.depth_stencil_state,
+				&create_info.color_blend_state,
 				&stages,
 			)?))
 		}
 	}
+
+	unsafe fn supports_depth_stencil_resolve(
+		bindless: &Bindless<Self>,
+		mode: ResolveMode,
+		aspect: FormatAspects,
+	) -> bool {
+		unsafe {
+			let mut resolve_properties = PhysicalDeviceDepthStencilResolveProperties::default();
+			let mut properties2 = PhysicalDeviceProperties2::default().push_next(&mut resolve_properties);
+			bindless
+				.instance
+				.get_physical_device_properties2(bindless.physical_device, &mut properties2);
+			let supported = if aspect == FormatAspects::STENCIL {
+				resolve_properties.supported_stencil_resolve_modes
+			} else {
+				resolve_properties.supported_depth_resolve_modes
+			};
+			supported.contains(mode.to_ash_resolve_mode())
+		}
+	}
 }
 
 impl Ash {
@@ -133,14 +152,24 @@ impl Ash {
 	unsafe fn ash_create_abstract_graphics_pipeline(
 		bindless: &Bindless<Self>,
 		render_pass: &RenderPassFormat,
-		input_assembly_state: PipelineInputAssemblyStateCreateInfo,
-		rasterization_state: PipelineRasterizationStateCreateInfo,
-		depth_stencil_state: PipelineDepthStencilStateCreateInfo,
-		color_blend_state: PipelineColorBlendStateCreateInfo,
+		input_assembly_state: &InputAssemblyState,
+		rasterization_state: &RasterizationState,
+		depth_stencil_state: &DepthStencilState,
+		color_blend_state: &ColorBlendState,
 		stages: &[PipelineShaderStageCreateInfo],
 	) -> VkResult<AshPipeline> {
 		unsafe {
 			let device = &bindless.device;
+			let color_blend_attachments = color_blend_state
+				.attachments
+				.iter()
+				.map(ColorBlendAttachment::to_ash_color_blend_attachment_state)
+				.collect::<SmallVec<[_; 5]>>();
+			let color_attachment_formats = render_pass
+				.color_attachments
+				.iter()
+				.map(Format::to_ash_format)
+				.collect::<SmallVec<[_; 5]>>();
 			let pipelines = device
 				.create_graphics_pipelines(
 					bindless.cache.unwrap_or(PipelineCache::null()),
@@ -148,7 +177,7 @@ impl Ash {
 						.layout(bindless.global_descriptor_set().pipeline_layout)
 						.stages(stages)
 						.vertex_input_state(&PipelineVertexInputStateCreateInfo::default())
-						.input_assembly_state(&input_assembly_state)
+						.input_assembly_state(&input_assembly_state.to_ash_input_assembly_state())
 						.tessellation_state(&PipelineTessellationStateCreateInfo::default())
 						.viewport_state(
 							&PipelineViewportStateCreateInfo::default()
@@ -161,13 +190,17 @@ impl Ash {
 									},
 								}]),
 						)
-						.rasterization_state(&rasterization_state.line_width(1.0))
+						.rasterization_state(&rasterization_state.to_ash_rasterization_state())
 						.multisample_state(
 							&PipelineMultisampleStateCreateInfo::default()
-								.rasterization_samples(SampleCountFlags::TYPE_1),
+								.rasterization_samples(render_pass.samples.to_ash_sample_count_flags()),
+						)
+						.depth_stencil_state(&depth_stencil_state.to_ash_depth_stencil_state())
+						.color_blend_state(
+							&PipelineColorBlendStateCreateInfo::default()
+								.attachments(&color_blend_attachments)
+								.blend_constants(color_blend_state.blend_constants),
 						)
-						.depth_stencil_state(&depth_stencil_state)
-						.color_blend_state(&color_blend_state)
 						.dynamic_state(
 							&PipelineDynamicStateCreateInfo::default()
 								.dynamic_states(&[DynamicState::VIEWPORT, DynamicState::SCISSOR]),
@@ -175,8 +208,10 @@ impl Ash {
 						.layout(bindless.global_descriptor_set().pipeline_layout)
 						.push_next(
 							&mut PipelineRenderingCreateInfo::default()
-								.color_attachment_formats(&render_pass.color_attachments)
-								.depth_attachment_format(render_pass.depth_attachment.unwrap_or_default()),
+								.color_attachment_formats(&color_attachment_formats)
+								.depth_attachment_format(
+									render_pass.depth_attachment.unwrap_or_default().to_ash_format(),
+								),
 						)],
 					None,
 				)
@@ -1,14 +1,24 @@
 use crate::descriptor::{
-	AddressMode, BindlessAllocationScheme, BindlessBufferUsage, BindlessImageUsage, BorderColor, Extent, Filter,
-	SampleCount,
+	AddressMode, BindlessAllocationScheme, BindlessBufferUsage, BindlessImageUsage, BorderColor, BufferSlot, Extent,
+	Filter, Format, FormatAspects, ImageSlot, ReductionMode, SampleCount, all_formats,
+};
+use crate::pipeline::{
+	BlendFactor, BlendOp, BufferImageCopyRegion, ClearValue, ColorBlendAttachment, ColorComponents, CompareOp,
+	CullMode, DepthStencilState, FrontFace, ImageAccessType, ImageRegion, IndexType, InputAssemblyState, LoadOp,
+	PolygonMode, PrimitiveTopology, RasterizationState, RenderingAttachment, RenderingImageType, ResolveMode,
+	StencilOp, StencilOpState, StoreOp,
 };
-use crate::pipeline::{ClearValue, ImageAccessType, IndexType, LoadOp, RenderingAttachment, StoreOp};
 use crate::platform::ash::Ash;
 use ash::vk::{
-	AttachmentLoadOp, AttachmentStoreOp, Extent2D, ImageLayout, ImageType as VkImageType, RenderingAttachmentInfo,
-	ShaderStageFlags,
+	AttachmentLoadOp, AttachmentStoreOp, BufferImageCopy2, ColorComponentFlags, Extent2D, ImageLayout,
+	ImageSubresourceLayers, ImageSubresourceRange, ImageType as VkImageType, Offset3D,
+	PipelineColorBlendAttachmentState, PipelineDepthStencilStateCreateInfo, PipelineInputAssemblyStateCreateInfo,
+	PipelineRasterizationStateCreateInfo, RenderingAttachmentInfo, ResolveModeFlags, ShaderStageFlags,
+};
+use ash::vk::{
+	BufferUsageFlags, Extent3D, ImageAspectFlags, ImageCreateFlags, ImageUsageFlags, ImageViewType, SampleCountFlags,
 };
-use ash::vk::{BufferUsageFlags, Extent3D, ImageUsageFlags, ImageViewType, SampleCountFlags};
+use glam::UVec3;
 use gpu_allocator::MemoryLocation;
 use gpu_allocator::vulkan::AllocationScheme;
 use rust_gpu_bindless_shaders::descriptor::ImageType;
@@ -88,6 +98,13 @@ pub fn bindless_image_type_to_vk_image_type<T: ImageType>() -> Option<VkImageTyp
 	}
 }
 
+pub fn bindless_image_type_to_vk_image_create_flags<T: ImageType>() -> ImageCreateFlags {
+	match T::dimensionality() {
+		Dimensionality::Cube => ImageCreateFlags::CUBE_COMPATIBLE,
+		_ => ImageCreateFlags::empty(),
+	}
+}
+
 pub fn bindless_image_type_to_vk_image_view_type<T: ImageType>() -> Option<ImageViewType> {
 	match (T::dimensionality(), T::arrayed()) {
 		(Dimensionality::OneD, Arrayed::False) => Some(ImageViewType::TYPE_1D),
@@ -105,6 +122,31 @@ pub fn bindless_image_type_to_vk_image_view_type<T: ImageType>() -> Option<Image
 	}
 }
 
+impl FormatAspects {
+	pub fn to_ash_image_aspect_flags(&self) -> ImageAspectFlags {
+		let mut out = ImageAspectFlags::empty();
+		if self.contains(FormatAspects::COLOR) {
+			out |= ImageAspectFlags::COLOR;
+		}
+		if self.contains(FormatAspects::DEPTH) {
+			out |= ImageAspectFlags::DEPTH;
+		}
+		if self.contains(FormatAspects::STENCIL) {
+			out |= ImageAspectFlags::STENCIL;
+		}
+		if self.contains(FormatAspects::PLANE_0) {
+			out |= ImageAspectFlags::PLANE_0;
+		}
+		if self.contains(FormatAspects::PLANE_1) {
+			out |= ImageAspectFlags::PLANE_1;
+		}
+		if self.contains(FormatAspects::PLANE_2) {
+			out |= ImageAspectFlags::PLANE_2;
+		}
+		out
+	}
+}
+
 impl SampleCount {
 	pub fn to_ash_sample_count_flags(&self) -> SampleCountFlags {
 		match self {
@@ -164,17 +206,20 @@ impl BindlessImageUsage {
 		out
 	}
 
+	pub fn to_ash_image_create_flags(&self) -> ImageCreateFlags {
+		let mut out = ImageCreateFlags::empty();
+		if self.contains(BindlessImageUsage::MUTABLE_FORMAT) {
+			out |= ImageCreateFlags::MUTABLE_FORMAT;
+		}
+		out
+	}
+
 	pub fn to_gpu_allocator_memory_location(&self) -> MemoryLocation {
 		MemoryLocation::GpuOnly
 	}
 
 	pub fn has_image_view(&self) -> bool {
-		self.intersects(
-			BindlessImageUsage::SAMPLED
-				| BindlessImageUsage::STORAGE
-				| BindlessImageUsage::COLOR_ATTACHMENT
-				| BindlessImageUsage::DEPTH_STENCIL_ATTACHMENT,
-		)
+		self.intersects(BindlessImageUsage::VIEW_USAGE)
 	}
 }
 
@@ -239,19 +284,102 @@ impl ClearValue {
 	}
 }
 
-impl<A: ImageAccessType> RenderingAttachment<'_, '_, Ash, A> {
+impl ResolveMode {
+	pub fn to_ash_resolve_mode(&self) -> ResolveModeFlags {
+		match self {
+			ResolveMode::Average => ResolveModeFlags::AVERAGE,
+			ResolveMode::SampleZero => ResolveModeFlags::SAMPLE_ZERO,
+			ResolveMode::Min => ResolveModeFlags::MIN,
+			ResolveMode::Max => ResolveModeFlags::MAX,
+		}
+	}
+}
+
+impl<A: ImageAccessType, T: RenderingImageType> RenderingAttachment<'_, '_, Ash, A, T> {
 	pub unsafe fn to_ash(&self, layout: ImageLayout) -> RenderingAttachmentInfo<'_> {
 		unsafe {
-			RenderingAttachmentInfo::default()
+			let info = RenderingAttachmentInfo::default()
 				.image_view(self.image.inner_slot().image_view.unwrap())
 				.image_layout(layout)
 				.load_op(self.load_op.to_ash())
 				.store_op(self.store_op.to_ash())
-				.clear_value(self.load_op.to_ash_clear_color())
+				.clear_value(self.load_op.to_ash_clear_color());
+			if let Some(resolve) = &self.resolve {
+				info.resolve_image_view(resolve.image.inner_slot().image_view.unwrap())
+					.resolve_image_layout(layout)
+					.resolve_mode(resolve.mode.to_ash_resolve_mode())
+			} else {
+				info
+			}
+		}
+	}
+}
+
+impl ImageSlot<Ash> {
+	/// The subresource range of all mips and layers of this image (view)
+	pub fn to_ash_subresource_range(&self) -> ImageSubresourceRange {
+		ImageSubresourceRange {
+			aspect_mask: self.format.aspects().to_ash_image_aspect_flags(),
+			base_mip_level: self.base_mip,
+			level_count: self.mip_levels,
+			base_array_layer: self.base_layer,
+			layer_count: self.array_layers,
 		}
 	}
 }
 
+impl ImageRegion {
+	/// The subresource of `image` covered by this region, with mips and layers made absolute for views
+	pub fn to_ash_subresource_layers(&self, image: &ImageSlot<Ash>) -> ImageSubresourceLayers {
+		ImageSubresourceLayers {
+			aspect_mask: image.format.aspects().to_ash_image_aspect_flags(),
+			mip_level: image.base_mip + self.mip_level,
+			base_array_layer: image.base_layer + self.base_layer,
+			layer_count: self.layer_count,
+		}
+	}
+
+	pub fn to_ash_offset(&self) -> Offset3D {
+		Offset3D {
+			x: self.offset.x as i32,
+			y: self.offset.y as i32,
+			z: self.offset.z as i32,
+		}
+	}
+
+	/// The two corners of this region, as required by blits
+	pub fn to_ash_offsets(&self) -> [Offset3D; 2] {
+		let end = self.offset + UVec3::from(self.extent);
+		[
+			self.to_ash_offset(),
+			Offset3D {
+				x: end.x as i32,
+				y: end.y as i32,
+				z: end.z as i32,
+			},
+		]
+	}
+}
+
+impl BufferImageCopyRegion {
+	pub fn to_ash_buffer_image_copy(
+		&self,
+		buffer: &BufferSlot<Ash>,
+		image: &ImageSlot<Ash>,
+	) -> BufferImageCopy2<'static> {
+		BufferImageCopy2::default()
+			.buffer_offset(buffer.offset + self.buffer_offset)
+			.buffer_row_length(self.buffer_row_length)
+			.buffer_image_height(self.buffer_image_height)
+			.image_subresource(ImageSubresourceLayers {
+				aspect_mask: self.image_aspect(image.format).to_ash_image_aspect_flags(),
+				..self.image.to_ash_subresource_layers(image)
+			})
+			.image_offset(self.image.to_ash_offset())
+			.image_extent(self.image.extent.into())
+	}
+}
+
 impl IndexType {
 	pub fn to_ash_index_type(&self) -> ash::vk::IndexType {
 		match self {
@@ -300,3 +428,241 @@ impl BorderColor {
 		}
 	}
 }
+
+impl ReductionMode {
+	pub fn to_ash_reduction_mode(&self) -> ash::vk::SamplerReductionMode {
+		match self {
+			ReductionMode::WeightedAverage => ash::vk::SamplerReductionMode::WEIGHTED_AVERAGE,
+			ReductionMode::Min => ash::vk::SamplerReductionMode::MIN,
+			ReductionMode::Max => ash::vk::SamplerReductionMode::MAX,
+		}
+	}
+}
+
+macro_rules! format_to_ash {
+	($($format:ident,)*) => {
+		impl Format {
+			pub fn to_ash_format(&self) -> ash::vk::Format {
+				match self {
+					$(Format::$format => ash::vk::Format::$format,)*
+				}
+			}
+
+			/// Returns `None` if the ash format has no [`Format`] equivalent
+			pub fn from_ash_format(format: ash::vk::Format) -> Option<Self> {
+				match format {
+					$(ash::vk::Format::$format => Some(Format::$format),)*
+					_ => None,
+				}
+			}
+		}
+	};
+}
+all_formats!(format_to_ash);
+
+impl InputAssemblyState {
+	pub fn to_ash_input_assembly_state(&self) -> PipelineInputAssemblyStateCreateInfo<'static> {
+		PipelineInputAssemblyStateCreateInfo::default()
+			.topology(self.topology.to_ash_primitive_topology())
+			.primitive_restart_enable(self.primitive_restart_enable)
+	}
+}
+
+impl PrimitiveTopology {
+	pub fn to_ash_primitive_topology(&self) -> ash::vk::PrimitiveTopology {
+		match self {
+			PrimitiveTopology::PointList => ash::vk::PrimitiveTopology::POINT_LIST,
+			PrimitiveTopology::LineList => ash::vk::PrimitiveTopology::LINE_LIST,
+			PrimitiveTopology::LineStrip => ash::vk::PrimitiveTopology::LINE_STRIP,
+			PrimitiveTopology::TriangleList => ash::vk::PrimitiveTopology::TRIANGLE_LIST,
+			PrimitiveTopology::TriangleStrip => ash::vk::PrimitiveTopology::TRIANGLE_STRIP,
+			PrimitiveTopology::TriangleFan => ash::vk::PrimitiveTopology::TRIANGLE_FAN,
+		}
+	}
+}
+
+impl RasterizationState {
+	pub fn to_ash_rasterization_state(&self) -> PipelineRasterizationStateCreateInfo<'static> {
+		let state = PipelineRasterizationStateCreateInfo::default()
+			.depth_clamp_enable(self.depth_clamp_enable)
+			.polygon_mode(self.polygon_mode.to_ash_polygon_mode())
+			.cull_mode(self.cull_mode.to_ash_cull_mode_flags())
+			.front_face(self.front_face.to_ash_front_face())
+			.line_width(1.0);
+		match self.depth_bias {
+			None => state,
+			Some(bias) => state
+				.depth_bias_enable(true)
+				.depth_bias_constant_factor(bias.constant_factor)
+				.depth_bias_clamp(bias.clamp)
+				.depth_bias_slope_factor(bias.slope_factor),
+		}
+	}
+}
+
+impl PolygonMode {
+	pub fn to_ash_polygon_mode(&self) -> ash::vk::PolygonMode {
+		match self {
+			PolygonMode::Fill => ash::vk::PolygonMode::FILL,
+			PolygonMode::Line => ash::vk::PolygonMode::LINE,
+			PolygonMode::Point => ash::vk::PolygonMode::POINT,
+		}
+	}
+}
+
+impl CullMode {
+	pub fn to_ash_cull_mode_flags(&self) -> ash::vk::CullModeFlags {
+		match self {
+			CullMode::None => ash::vk::CullModeFlags::NONE,
+			CullMode::Front => ash::vk::CullModeFlags::FRONT,
+			CullMode::Back => ash::vk::CullModeFlags::BACK,
+			CullMode::FrontAndBack => ash::vk::CullModeFlags::FRONT_AND_BACK,
+		}
+	}
+}
+
+impl FrontFace {
+	pub fn to_ash_front_face(&self) -> ash::vk::FrontFace {
+		match self {
+			FrontFace::CounterClockwise => ash::vk::FrontFace::COUNTER_CLOCKWISE,
+			FrontFace::Clockwise => ash::vk::FrontFace::CLOCKWISE,
+		}
+	}
+}
+
+impl DepthStencilState {
+	pub fn to_ash_depth_stencil_state(&self) -> PipelineDepthStencilStateCreateInfo<'static> {
+		let mut state = PipelineDepthStencilStateCreateInfo::default()
+			.depth_test_enable(self.depth_test_enable)
+			.depth_write_enable(self.depth_write_enable)
+			.depth_compare_op(self.depth_compare_op.to_ash_compare_op());
+		if let Some((min, max)) = self.depth_bounds {
+			state = state
+				.depth_bounds_test_enable(true)
+				.min_depth_bounds(min)
+				.max_depth_bounds(max);
+		}
+		if let Some(stencil) = self.stencil {
+			state = state
+				.stencil_test_enable(true)
+				.front(stencil.front.to_ash_stencil_op_state())
+				.back(stencil.back.to_ash_stencil_op_state());
+		}
+		state
+	}
+}
+
+impl CompareOp {
+	pub fn to_ash_compare_op(&self) -> ash::vk::CompareOp {
+		match self {
+			CompareOp::Never => ash::vk::CompareOp::NEVER,
+			CompareOp::Less => ash::vk::CompareOp::LESS,
+			CompareOp::Equal => ash::vk::CompareOp::EQUAL,
+			CompareOp::LessOrEqual => ash::vk::CompareOp::LESS_OR_EQUAL,
+			CompareOp::Greater => ash::vk::CompareOp::GREATER,
+			CompareOp::NotEqual => ash::vk::CompareOp::NOT_EQUAL,
+			CompareOp::GreaterOrEqual => ash::vk::CompareOp::GREATER_OR_EQUAL,
+			CompareOp::Always => ash::vk::CompareOp::ALWAYS,
+		}
+	}
+}
+
+impl StencilOpState {
+	pub fn to_ash_stencil_op_state(&self) -> ash::vk::StencilOpState {
+		ash::vk::StencilOpState {
+			fail_op: self.fail_op.to_ash_stencil_op(),
+			pass_op: self.pass_op.to_ash_stencil_op(),
+			depth_fail_op: self.depth_fail_op.to_ash_stencil_op(),
+			compare_op: self.compare_op.to_ash_compare_op(),
+			compare_mask: self.compare_mask,
+			write_mask: self.write_mask,
+			reference: self.reference,
+		}
+	}
+}
+
+impl StencilOp {
+	pub fn to_ash_stencil_op(&self) -> ash::vk::StencilOp {
+		match self {
+			StencilOp::Keep => ash::vk::StencilOp::KEEP,
+			StencilOp::Zero => ash::vk::StencilOp::ZERO,
+			StencilOp::Replace => ash::vk::StencilOp::REPLACE,
+			StencilOp::IncrementAndClamp => ash::vk::StencilOp::INCREMENT_AND_CLAMP,
+			StencilOp::DecrementAndClamp => ash::vk::StencilOp::DECREMENT_AND_CLAMP,
+			StencilOp::Invert => ash::vk::StencilOp::INVERT,
+			StencilOp::IncrementAndWrap => ash::vk::StencilOp::INCREMENT_AND_WRAP,
+			StencilOp::DecrementAndWrap => ash::vk::StencilOp::DECREMENT_AND_WRAP,
+		}
+	}
+}
+
+impl ColorBlendAttachment {
+	pub fn to_ash_color_blend_attachment_state(&self) -> PipelineColorBlendAttachmentState {
+		let state = PipelineColorBlendAttachmentState::default()
+			.color_write_mask(self.color_write_mask.to_ash_color_component_flags());
+		match self.blend {
+			None => state,
+			Some(blend) => state
+				.blend_enable(true)
+				.src_color_blend_factor(blend.color.src_factor.to_ash_blend_factor())
+				.dst_color_blend_factor(blend.color.dst_factor.to_ash_blend_factor())
+				.color_blend_op(blend.color.operation.to_ash_blend_op())
+				.src_alpha_blend_factor(blend.alpha.src_factor.to_ash_blend_factor())
+				.dst_alpha_blend_factor(blend.alpha.dst_factor.to_ash_blend_factor())
+				.alpha_blend_op(blend.alpha.operation.to_ash_blend_op()),
+		}
+	}
+}
+
+impl BlendFactor {
+	pub fn to_ash_blend_factor(&self) -> ash::vk::BlendFactor {
+		match self {
+			BlendFactor::Zero => ash::vk::BlendFactor::ZERO,
+			BlendFactor::One => ash::vk::BlendFactor::ONE,
+			BlendFactor::SrcColor => ash::vk::BlendFactor::SRC_COLOR,
+			BlendFactor::OneMinusSrcColor => ash::vk::BlendFactor::ONE_MINUS_SRC_COLOR,
+			BlendFactor::DstColor => ash::vk::BlendFactor::DST_COLOR,
+			BlendFactor::OneMinusDstColor => ash::vk::BlendFactor::ONE_MINUS_DST_COLOR,
+			BlendFactor::SrcAlpha => ash::vk::BlendFactor::SRC_ALPHA,
+			BlendFactor::OneMinusSrcAlpha => ash::vk::BlendFactor::ONE_MINUS_SRC_ALPHA,
+			BlendFactor::DstAlpha => ash::vk::BlendFactor::DST_ALPHA,
+			BlendFactor::OneMinusDstAlpha => ash::vk::BlendFactor::ONE_MINUS_DST_ALPHA,
+			BlendFactor::ConstantColor => ash::vk::BlendFactor::CONSTANT_COLOR,
+			BlendFactor::OneMinusConstantColor => ash::vk::BlendFactor::ONE_MINUS_CONSTANT_COLOR,
+			BlendFactor::ConstantAlpha => ash::vk::BlendFactor::CONSTANT_ALPHA,
+			BlendFactor::OneMinusConstantAlpha => ash::vk::BlendFactor::ONE_MINUS_CONSTANT_ALPHA,
+			BlendFactor::SrcAlphaSaturate => ash::vk::BlendFactor::SRC_ALPHA_SATURATE,
+		}
+	}
+}
+
+impl BlendOp {
+	pub fn to_ash_blend_op(&self) -> ash::vk::BlendOp {
+		match self {
+			BlendOp::Add => ash::vk::BlendOp::ADD,
+			BlendOp::Subtract => ash::vk::BlendOp::SUBTRACT,
+			BlendOp::ReverseSubtract => ash::vk::BlendOp::REVERSE_SUBTRACT,
+			BlendOp::Min => ash::vk::BlendOp::MIN,
+			BlendOp::Max => ash::vk::BlendOp::MAX,
+		}
+	}
+}
+
+impl ColorComponents {
+	pub fn to_ash_color_component_flags(&self) -> ColorComponentFlags {
+		let mut out = ColorComponentFlags::empty();
+		if self.contains(ColorComponents::R) {
+			out |= ColorComponentFlags::R;
+		}
+		if self.contains(ColorComponents::G) {
+			out |= ColorComponentFlags::G;
+		}
+		if self.contains(ColorComponents::B) {
+			out |= ColorComponentFlags::B;
+		}
+		if self.contains(ColorComponents::A) {
+			out |= ColorComponentFlags::A;
+		}
+		out
+	}
+}
@@ -1,6 +1,7 @@
-use crate::descriptor::{Bindless, BindlessFrame, WeakBindless};
+use crate::descriptor::{Bindless, BindlessFrame, MutDescExt, WeakBindless};
 use crate::platform::PendingExecution;
-use crate::platform::ash::{Ash, AshCreateInfo, DeviceExt};
+use crate::platform::ash::param_arena::{ASH_PARAM_BLOCK_POOL_SIZE, alloc_param_block, recycle_param_block};
+use crate::platform::ash::{Ash, AshCreateInfo, AshParamArena, AshParamBlock, DeviceExt};
 use ash::Device;
 use ash::prelude::VkResult;
 use ash::vk::{
@@ -94,6 +95,8 @@ pub struct AshExecution {
 pub struct MutexedAshExecution {
 	frame: Option<BindlessFrame<Ash>>,
 	wakers: SmallVec<[Waker; 1]>,
+	/// params of this execution, recycled once it completes
+	params: AshParamArena,
 }
 
 impl AshExecution {
@@ -115,6 +118,7 @@ impl AshExecution {
 			mutex: Mutex::new(MutexedAshExecution {
 				frame: Some(frame),
 				wakers: SmallVec::new(),
+				params: AshParamArena::default(),
 			}),
 		}
 	}
@@ -127,6 +131,7 @@ impl AshExecution {
 			mutex: Mutex::new(MutexedAshExecution {
 				frame: None,
 				wakers: SmallVec::new(),
+				params: AshParamArena::default(),
 			}),
 		}
 	}
@@ -135,17 +140,26 @@ impl AshExecution {
 		self.completed.load(Relaxed)
 	}
 
+	/// Hand over the params used by this execution, to be recycled once it completes.
+	pub fn set_params(&self, params: AshParamArena) {
+		self.mutex.lock().params = params;
+	}
+
 	fn check_completion(&self, device: &Device) -> bool {
 		let value = unsafe { device.get_semaphore_counter_value(self.resource.semaphore).unwrap() };
 		if value == self.resource.timeline_value {
-			let wakers = {
+			let (wakers, params) = {
 				let mut guard = self.mutex.lock();
 				// must be set while holding `wakers` to prevent races
 				self.completed.store(true, Relaxed);
 				// frame has finished, drop frame to start resource reclamation
 				drop(guard.frame.take());
-				mem::replace(&mut guard.wakers, SmallVec::new())
+				(
+					mem::replace(&mut guard.wakers, SmallVec::new()),
+					mem::take(&mut guard.params),
+				)
 			};
+			self.bindless.execution_manager.recycle_params(params);
 			for x in wakers {
 				x.wake();
 			}
@@ -192,6 +206,7 @@ impl Drop for AshExecution {
 pub struct AshExecutionManager {
 	bindless: WeakBindless<Ash>,
 	free_pool: SegQueue<AshExecutionResource>,
+	param_blocks: SegQueue<AshParamBlock>,
 	submit_for_waiting: SegQueue<Arc<AshExecution>>,
 	wait_thread: Mutex<(Option<thread::ThreadId>, Option<thread::JoinHandle<()>>)>,
 	wait_thread_shutdown: AtomicBool,
@@ -208,6 +223,7 @@ impl AshExecutionManager {
 		Ok(Self {
 			bindless: bindless.clone(),
 			free_pool: SegQueue::new(),
+			param_blocks: SegQueue::new(),
 			submit_for_waiting: SegQueue::new(),
 			wait_thread: Mutex::new((None, None)),
 			wait_thread_shutdown: AtomicBool::new(false),
@@ -251,6 +267,33 @@ impl AshExecutionManager {
 		self.free_pool.push(resource);
 	}
 
+	/// Get a param block of at least `min_size` bytes for an [`AshParamArena`](super::AshParamArena), reusing blocks
+	/// of completed executions if possible.
+	pub fn pop_param_block(&self, bindless: &Bindless<Ash>, min_size: u64) -> AshParamBlock {
+		match self.param_blocks.pop() {
+			Some(block) if min_size <= block.inner_slot().size => block,
+			block => {
+				if let Some(block) = block {
+					self.param_blocks.push(block);
+				}
+				alloc_param_block(bindless, min_size)
+			}
+		}
+	}
+
+	/// Return the blocks of a completed execution's [`AshParamArena`] to be reused, up to a total of
+	/// [`ASH_PARAM_BLOCK_POOL_SIZE`] blocks. Any other blocks are freed.
+	pub fn recycle_params(&self, params: AshParamArena) {
+		for block in params.into_blocks() {
+			if self.param_blocks.len() >= ASH_PARAM_BLOCK_POOL_SIZE {
+				break;
+			}
+			if let Some(block) = recycle_param_block(block) {
+				self.param_blocks.push(block);
+			}
+		}
+	}
+
 	/// # Safety
 	/// must only submit an execution acquired from [`Self::new_execution`] exactly once
 	pub unsafe fn submit_for_waiting(&self, execution: Arc<AshExecution>) -> VkResult<()> {
@@ -387,6 +430,8 @@ impl AshExecutionManager {
 			self.notify_wait_semaphore_thread()?;
 			join_handle.join().unwrap();
 		}
+		// all executions have completed, free param blocks so they aren't reported as leaked
+		while self.param_blocks.pop().is_some() {}
 		Ok(())
 	}
 
@@ -6,9 +6,9 @@ use ash::khr::{surface, swapchain};
 use ash::vk::{
 	ApplicationInfo, Bool32, DebugUtilsMessageSeverityFlagsEXT, DebugUtilsMessageTypeFlagsEXT,
 	DebugUtilsMessengerCallbackDataEXT, DebugUtilsMessengerCreateInfoEXT, DeviceCreateInfo, DeviceQueueCreateInfo,
-	ExtendsDeviceCreateInfo, InstanceCreateInfo, PhysicalDeviceFeatures, PhysicalDeviceType,
+	ExtendsDeviceCreateInfo, InstanceCreateInfo, PhysicalDeviceFeatures, PhysicalDeviceFeatures2, PhysicalDeviceType,
 	PhysicalDeviceVulkan11Features, PhysicalDeviceVulkan12Features, PhysicalDeviceVulkan13Features,
-	PipelineCacheCreateInfo, QueueFlags, ShaderStageFlags, ValidationFeatureEnableEXT, ValidationFeaturesEXT,
+	PipelineCacheCreateInfo, QueueFlags, ShaderStageFlags, TRUE, ValidationFeatureEnableEXT, ValidationFeaturesEXT,
 };
 use gpu_allocator::vulkan::{Allocator, AllocatorCreateDesc};
 use gpu_allocator::{AllocationSizes, AllocatorDebugSettings};
@@ -24,6 +24,7 @@ pub fn required_features() -> PhysicalDeviceFeatures {
 		.shader_uniform_buffer_array_dynamic_indexing(true)
 		.shader_storage_image_array_dynamic_indexing(true)
 		.shader_sampled_image_array_dynamic_indexing(true)
+		.image_cube_array(true)
 }
 
 pub fn required_features_vk11() -> PhysicalDeviceVulkan11Features<'static> {
@@ -248,6 +249,16 @@ pub fn ash_init_single_graphics_queue_with_push_next(
 				.0 as u32
 		};
 
+		// optional features, enabled if supported
+		let mut supported_vk12 = PhysicalDeviceVulkan12Features::default();
+		instance.get_physical_device_features2(
+			physical_device,
+			&mut PhysicalDeviceFeatures2::default().push_next(&mut supported_vk12),
+		);
+		if supported_vk12.sampler_filter_minmax == TRUE {
+			create_info.features_vk12 = create_info.features_vk12.sampler_filter_minmax(true);
+		}
+
 		let device = {
 			let extensions = create_info.extensions.iter().map(|x| x.as_ptr()).collect::<Vec<_>>();
 			let mut device_create_info = DeviceCreateInfo::default();
@@ -11,10 +11,10 @@ mod bindless_pipeline;
 mod convert;
 /// Execution tracking with timeline semaphores
 mod executing;
-/// Image format enum tables
-mod image_format;
 /// Simple init function to create a device with a single graphics queue
 mod init;
+/// Linear allocator packing dispatch and draw params into shared buffers
+mod param_arena;
 /// CommandBuffer recording
 mod recording;
 /// CommandBuffer recording of rendering cmds
@@ -28,4 +28,5 @@ pub use bindless::*;
 pub use convert::*;
 pub use executing::*;
 pub use init::*;
+pub use param_arena::*;
 pub use recording::*;
@@ -0,0 +1,119 @@
+use crate::descriptor::{
+	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, MutDesc, MutDescExt,
+	StrongBackingRefs, StrongMetadataCpu,
+};
+use crate::platform::BindlessPlatform;
+use crate::platform::ash::Ash;
+use rust_gpu_bindless_shaders::buffer_content::{BufferStruct, Metadata};
+use rust_gpu_bindless_shaders::descriptor::{BindlessPushConstant, MutBuffer, is_param_inline};
+use std::mem::{align_of, size_of};
+
+/// The size of a param block in bytes. Params larger than this get a dedicated block, which is not recycled.
+pub const ASH_PARAM_BLOCK_SIZE: u64 = 64 * 1024;
+
+/// The maximum amount of param blocks kept for reuse by the [`AshExecutionManager`](super::AshExecutionManager),
+/// additional blocks of completed executions are freed.
+pub const ASH_PARAM_BLOCK_POOL_SIZE: usize = 16;
+
+/// A block of a [`AshParamArena`]
+pub type AshParamBlock = MutDesc<Ash, MutBuffer<[u8]>>;
+
+/// A linear allocator packing the params of all dispatches and draws of an execution into shared param blocks,
+/// which are addressed using [`BindlessPushConstant::param_offset`]. Once the execution completes, its blocks are
+/// returned to the [`AshExecutionManager`](super::AshExecutionManager) to be reused by following executions.
+///
+/// Small params are embedded inline in the [`BindlessPushConstant`] instead, see [`is_param_inline`].
+#[derive(Default)]
+pub struct AshParamArena {
+	blocks: Vec<AshParamBlock>,
+	/// the offset into the last block where the next param may be written
+	offset: u64,
+	/// keeps [`StrongDesc`] referenced by inline params alive until the execution completes
+	///
+	/// [`StrongDesc`]: rust_gpu_bindless_shaders::descriptor::StrongDesc
+	inline_refs: StrongBackingRefs<Ash>,
+}
+
+impl AshParamArena {
+	/// Writes `param` into this arena and returns the [`BindlessPushConstant`] referencing it.
+	///
+	/// # Safety
+	/// The returned push constant may only be used by the execution owning this arena
+	pub unsafe fn push<T: BufferStruct>(&mut self, bindless: &Bindless<Ash>, param: T) -> BindlessPushConstant {
+		unsafe {
+			if is_param_inline::<T>() {
+				let mut meta = StrongMetadataCpu::new(&bindless.table_sync, Metadata {});
+				let push_constant = BindlessPushConstant::new_inline::<T>(T::write_cpu(param, &mut meta));
+				self.inline_refs.merge(meta.into_backing_refs());
+				return push_constant;
+			}
+
+			let size = size_of::<T::Transfer>() as u64;
+			// shaders load params as u32s
+			let align = align_of::<T::Transfer>().max(4) as u64;
+			let mut offset = self.offset.next_multiple_of(align);
+			let fits = self
+				.blocks
+				.last()
+				.is_some_and(|block| offset + size <= block.inner_slot().size);
+			if !fits {
+				self.blocks
+					.push(bindless.execution_manager.pop_param_block(bindless, size));
+				offset = 0;
+			}
+
+			let block = self.blocks.last().unwrap();
+			let slot = block.inner_slot();
+			let mut meta = StrongMetadataCpu::new(&bindless.table_sync, Metadata {});
+			let slab = Ash::mapped_buffer_to_slab(slot);
+			let record =
+				presser::copy_from_slice_to_offset(&[T::write_cpu(param, &mut meta)], slab, offset as usize).unwrap();
+			assert_eq!(
+				record.copy_start_offset, offset as usize,
+				"presser must not add padding"
+			);
+			slot.strong_refs.lock().merge(meta.into_backing_refs());
+			self.offset = offset + size;
+			BindlessPushConstant::new(block.id(), offset as u32)
+		}
+	}
+
+	/// The blocks params have been written to, in order of allocation
+	pub fn blocks(&self) -> &[AshParamBlock] {
+		&self.blocks
+	}
+
+	/// Take all blocks out of this arena, releasing all [`StrongDesc`] referenced by inline params
+	///
+	/// [`StrongDesc`]: rust_gpu_bindless_shaders::descriptor::StrongDesc
+	pub fn into_blocks(self) -> Vec<AshParamBlock> {
+		self.blocks
+	}
+}
+
+/// Allocates a new param block of at least `min_size` bytes
+pub(super) fn alloc_param_block(bindless: &Bindless<Ash>, min_size: u64) -> AshParamBlock {
+	bindless
+		.buffer()
+		.alloc_slice::<u8>(
+			&BindlessBufferCreateInfo {
+				usage: BindlessBufferUsage::MAP_WRITE | BindlessBufferUsage::STORAGE_BUFFER,
+				name: "param block",
+				allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+			},
+			min_size.max(ASH_PARAM_BLOCK_SIZE) as usize,
+		)
+		.unwrap()
+}
+
+/// Prepares a block of a completed execution to be reused, returns `None` if it should be freed instead.
+pub(super) fn recycle_param_block(block: AshParamBlock) -> Option<AshParamBlock> {
+	let slot = block.inner_slot();
+	if slot.size == ASH_PARAM_BLOCK_SIZE {
+		// release the StrongDesc referenced by the params of the completed execution
+		*slot.strong_refs.lock() = StrongBackingRefs::default();
+		Some(block)
+	} else {
+		None
+	}
+}
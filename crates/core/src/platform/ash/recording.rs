@@ -1,26 +1,24 @@
-use crate::descriptor::MutDescExt;
-use crate::descriptor::{
-	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BufferSlot, ImageSlot,
-};
+use crate::descriptor::{Bindless, BufferSlot, Filter, Format, ImageSlot};
 use crate::pipeline::{
-	BindlessComputePipeline, BufferAccess, BufferAccessType, HasResourceContext, ImageAccess, ImageAccessType,
-	IndirectCommandReadable, MutBufferAccess, MutImageAccess, MutOrSharedBuffer, Recording, RecordingError,
-	TransferReadable, TransferWriteable,
+	BindlessComputePipeline, BufferAccess, BufferAccessType, BufferCopyRegion, BufferImageCopyRegion, ClearValue,
+	HasResourceContext, ImageAccess, ImageAccessType, ImageCopyRegion, IndirectCommandReadable, MutBufferAccess,
+	MutImageAccess, MutOrSharedBuffer, Recording, RecordingError, TransferReadable, TransferWriteable,
 };
-use crate::platform::ash::image_format::FormatExt;
-use crate::platform::ash::{Ash, AshExecution, AshPendingExecution};
+use crate::platform::ash::{Ash, AshExecution, AshParamArena, AshPendingExecution};
 use crate::platform::{BindlessPipelinePlatform, RecordingContext, RecordingResourceContext};
 use ash::vk::{
-	BufferCopy, BufferImageCopy2, BufferMemoryBarrier2, CommandBuffer, CommandBufferBeginInfo, CommandBufferUsageFlags,
-	CopyBufferToImageInfo2, CopyImageToBufferInfo2, DependencyInfo, Fence, ImageMemoryBarrier2, ImageSubresourceLayers,
-	ImageSubresourceRange, MemoryBarrier2, Offset3D, PipelineBindPoint, PipelineStageFlags, QUEUE_FAMILY_IGNORED,
-	REMAINING_ARRAY_LAYERS, REMAINING_MIP_LEVELS, SubmitInfo, TimelineSemaphoreSubmitInfo, WHOLE_SIZE,
+	AccessFlags2, BlitImageInfo2, BufferCopy, BufferMemoryBarrier2, ClearDepthStencilValue, CommandBuffer,
+	CommandBufferBeginInfo, CommandBufferUsageFlags, CopyBufferToImageInfo2, CopyImageInfo2, CopyImageToBufferInfo2,
+	DependencyInfo, Fence, FormatFeatureFlags, ImageBlit2, ImageCopy2, ImageMemoryBarrier2, ImageSubresourceLayers,
+	ImageSubresourceRange, MemoryBarrier2, Offset3D, PipelineBindPoint, PipelineStageFlags, PipelineStageFlags2,
+	QUEUE_FAMILY_IGNORED, REMAINING_ARRAY_LAYERS, REMAINING_MIP_LEVELS, SubmitInfo, TimelineSemaphoreSubmitInfo,
 };
 use rust_gpu_bindless_shaders::buffer_content::{BufferContent, BufferStruct};
 use rust_gpu_bindless_shaders::descriptor::{BindlessPushConstant, ImageType, TransientAccess};
 use smallvec::SmallVec;
 use std::cell::RefCell;
 use std::fmt::Debug;
+use std::ops::Range;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -28,6 +26,7 @@ pub struct AshRecordingResourceContext {
 	inner: RefCell<AshBarrierCollector>,
 	pub(super) execution: Arc<AshExecution>,
 	dependencies: RefCell<SmallVec<[AshPendingExecution; 4]>>,
+	params: RefCell<AshParamArena>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -49,6 +48,7 @@ impl AshRecordingResourceContext {
 			inner: RefCell::new(AshBarrierCollector::default()),
 			execution,
 			dependencies: RefCell::new(SmallVec::new()),
+			params: RefCell::new(AshParamArena::default()),
 		}
 	}
 
@@ -92,8 +92,8 @@ unsafe impl RecordingResourceContext<Ash> for AshRecordingResourceContext {
 		self.push_buffer_barrier(
 			BufferMemoryBarrier2::default()
 				.buffer(buffer.buffer)
-				.offset(0)
-				.size(WHOLE_SIZE)
+				.offset(buffer.offset)
+				.size(buffer.size)
 				.src_access_mask(src.access_mask)
 				.src_stage_mask(src.stage_mask)
 				.dst_access_mask(dst.access_mask)
@@ -110,10 +110,11 @@ unsafe impl RecordingResourceContext<Ash> for AshRecordingResourceContext {
 			ImageMemoryBarrier2::default()
 				.image(image.image)
 				.subresource_range(
+					// the access lock is shared by all views of an image, so always transition the entire image
 					ImageSubresourceRange::default()
 						// I'm unsure if it's valid to specify it like this or if the aspect has to match the format of
 						// the image, I guess we'll find out later!
-						.aspect_mask(image.format.aspect())
+						.aspect_mask(image.format.aspects().to_ash_image_aspect_flags())
 						.base_array_layer(0)
 						.layer_count(REMAINING_ARRAY_LAYERS)
 						.base_mip_level(0)
@@ -200,9 +201,9 @@ pub unsafe fn ash_submit(
 			)?;
 		}
 
-		bindless
-			.execution_manager
-			.submit_for_waiting(resource_context.execution)?;
+		let execution = resource_context.execution;
+		execution.set_params(resource_context.params.into_inner());
+		bindless.execution_manager.submit_for_waiting(execution)?;
 		Ok(())
 	}
 }
@@ -317,29 +318,18 @@ impl<'a> AshRecordingContext<'a> {
 		}
 	}
 
-	/// A BumpAllocator would be nice to have, but this will do for now
+	/// Writes `param` into the [`AshParamArena`] of this execution and pushes the [`BindlessPushConstant`]
+	/// referencing it. Only the bytes actually used by the param are pushed.
 	pub unsafe fn ash_push_param<T: BufferStruct>(&mut self, param: T) {
 		unsafe {
 			let device = &self.bindless.platform.device;
-			let desc = self
-				.bindless
-				.buffer()
-				.alloc_from_data(
-					&BindlessBufferCreateInfo {
-						usage: BindlessBufferUsage::MAP_WRITE | BindlessBufferUsage::STORAGE_BUFFER,
-						name: "param",
-						allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
-					},
-					param,
-				)
-				.unwrap();
-			let push_constant = BindlessPushConstant::new(desc.id(), 0);
+			let push_constant = self.resource_context.params.borrow_mut().push(&self.bindless, param);
 			device.cmd_push_constants(
 				self.cmd,
 				self.bindless.global_descriptor_set().pipeline_layout,
 				self.bindless.shader_stages,
 				0,
-				bytemuck::cast_slice(&[push_constant]),
+				&bytemuck::bytes_of(&push_constant)[..BindlessPushConstant::push_size::<T>()],
 			);
 		}
 	}
@@ -388,8 +378,8 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 				src.buffer,
 				dst.buffer,
 				&[BufferCopy {
-					src_offset: 0,
-					dst_offset: 0,
+					src_offset: src.offset,
+					dst_offset: dst.offset,
 					size: src.size,
 				}],
 			);
@@ -416,8 +406,8 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 				src.buffer,
 				dst.buffer,
 				&[BufferCopy {
-					src_offset: 0,
-					dst_offset: 0,
+					src_offset: src.offset,
+					dst_offset: dst.offset,
 					size: src.size,
 				}],
 			);
@@ -425,6 +415,36 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 		}
 	}
 
+	unsafe fn copy_buffer_to_buffer_region<
+		T: BufferStruct,
+		SA: BufferAccessType + TransferReadable,
+		DA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: impl MutOrSharedBuffer<Ash, [T], SA>,
+		dst: &MutBufferAccess<Ash, [T], DA>,
+		region: &BufferCopyRegion,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			self.ash_flush();
+			let device = &self.bindless.platform.device;
+			let src = src.inner_slot();
+			let dst = dst.inner_slot();
+			let stride = size_of::<T::Transfer>() as u64;
+			device.cmd_copy_buffer(
+				self.cmd,
+				src.buffer,
+				dst.buffer,
+				&[BufferCopy {
+					src_offset: src.offset + region.src_offset as u64 * stride,
+					dst_offset: dst.offset + region.dst_offset as u64 * stride,
+					size: region.len as u64 * stride,
+				}],
+			);
+			Ok(())
+		}
+	}
+
 	unsafe fn copy_buffer_to_image<
 		BT: BufferContent + ?Sized,
 		BA: BufferAccessType + TransferReadable,
@@ -434,6 +454,7 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 		&mut self,
 		src_buffer: &MutBufferAccess<Ash, BT, BA>,
 		dst_image: &MutImageAccess<Ash, IT, IA>,
+		region: &BufferImageCopyRegion,
 	) -> Result<(), AshRecordingError> {
 		unsafe {
 			self.ash_flush();
@@ -446,20 +467,7 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 					.src_buffer(buffer.buffer)
 					.dst_image(image.image)
 					.dst_image_layout(IA::IMAGE_ACCESS.to_ash_image_access().image_layout)
-					.regions(&[BufferImageCopy2 {
-						buffer_offset: 0,
-						buffer_row_length: 0,
-						buffer_image_height: 0,
-						image_subresource: ImageSubresourceLayers {
-							aspect_mask: image.format.aspect(),
-							mip_level: 0,
-							base_array_layer: 0,
-							layer_count: image.array_layers,
-						},
-						image_offset: Offset3D::default(),
-						image_extent: image.extent.into(),
-						..Default::default()
-					}]),
+					.regions(&[region.to_ash_buffer_image_copy(buffer, image)]),
 			);
 			Ok(())
 		}
@@ -474,6 +482,7 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 		&mut self,
 		src_image: &MutImageAccess<Ash, IT, IA>,
 		dst_buffer: &MutBufferAccess<Ash, BT, BA>,
+		region: &BufferImageCopyRegion,
 	) -> Result<(), AshRecordingError> {
 		unsafe {
 			self.ash_flush();
@@ -486,20 +495,259 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 					.src_image(image.image)
 					.src_image_layout(IA::IMAGE_ACCESS.to_ash_image_access().image_layout)
 					.dst_buffer(buffer.buffer)
-					.regions(&[BufferImageCopy2 {
-						buffer_offset: 0,
-						buffer_row_length: 0,
-						buffer_image_height: 0,
-						image_subresource: ImageSubresourceLayers {
-							aspect_mask: image.format.aspect(),
-							mip_level: 0,
-							base_array_layer: 0,
-							layer_count: image.array_layers,
-						},
-						image_offset: Offset3D::default(),
-						image_extent: image.extent.into(),
-						..Default::default()
-					}]),
+					.regions(&[region.to_ash_buffer_image_copy(buffer, image)]),
+			);
+			Ok(())
+		}
+	}
+
+	unsafe fn copy_image_to_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: &MutImageAccess<Ash, ST, SA>,
+		dst: &MutImageAccess<Ash, DT, DA>,
+		region: &ImageCopyRegion,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			self.ash_flush();
+			let device = &self.bindless.platform.device;
+			let src = src.inner_slot();
+			let dst = dst.inner_slot();
+			device.cmd_copy_image2(
+				self.cmd,
+				&CopyImageInfo2::default()
+					.src_image(src.image)
+					.src_image_layout(SA::IMAGE_ACCESS.to_ash_image_access().image_layout)
+					.dst_image(dst.image)
+					.dst_image_layout(DA::IMAGE_ACCESS.to_ash_image_access().image_layout)
+					.regions(&[ImageCopy2::default()
+						.src_subresource(region.src.to_ash_subresource_layers(src))
+						.src_offset(region.src.to_ash_offset())
+						.dst_subresource(region.dst.to_ash_subresource_layers(dst))
+						.dst_offset(region.dst.to_ash_offset())
+						.extent(region.src.extent.into())]),
+			);
+			Ok(())
+		}
+	}
+
+	unsafe fn blit_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: &MutImageAccess<Ash, ST, SA>,
+		dst: &MutImageAccess<Ash, DT, DA>,
+		region: &ImageCopyRegion,
+		filter: Filter,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			let src = src.inner_slot();
+			let dst = dst.inner_slot();
+			let bindless = &self.bindless.platform;
+			for (image, feature) in [(src, FormatFeatureFlags::BLIT_SRC), (dst, FormatFeatureFlags::BLIT_DST)] {
+				let features = bindless
+					.instance
+					.get_physical_device_format_properties(bindless.physical_device, image.format.to_ash_format())
+					.optimal_tiling_features;
+				let feature = match filter {
+					Filter::Nearest => feature,
+					Filter::Linear => feature | FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR,
+				};
+				if !features.contains(feature) {
+					return Err(AshRecordingError::BlitUnsupported {
+						name: image.debug_name.clone(),
+						format: image.format,
+					});
+				}
+			}
+
+			self.ash_flush();
+			self.bindless.platform.device.cmd_blit_image2(
+				self.cmd,
+				&BlitImageInfo2::default()
+					.src_image(src.image)
+					.src_image_layout(SA::IMAGE_ACCESS.to_ash_image_access().image_layout)
+					.dst_image(dst.image)
+					.dst_image_layout(DA::IMAGE_ACCESS.to_ash_image_access().image_layout)
+					.filter(filter.to_ash_filter())
+					.regions(&[ImageBlit2::default()
+						.src_subresource(region.src.to_ash_subresource_layers(src))
+						.src_offsets(region.src.to_ash_offsets())
+						.dst_subresource(region.dst.to_ash_subresource_layers(dst))
+						.dst_offsets(region.dst.to_ash_offsets())]),
+			);
+			Ok(())
+		}
+	}
+
+	unsafe fn generate_mips<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<Ash, T, A>,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			let image = image.inner_slot();
+			let bindless = &self.bindless.platform;
+			// blit support was already checked by Recording::generate_mips
+			let features = bindless
+				.instance
+				.get_physical_device_format_properties(bindless.physical_device, image.format.to_ash_format())
+				.optimal_tiling_features;
+			// depth/stencil formats must be blitted with nearest filtering, even if they report linear filtering
+			let filter = if !image.format.is_depth_stencil()
+				&& features.contains(FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR)
+			{
+				Filter::Linear
+			} else {
+				Filter::Nearest
+			};
+
+			let access = A::IMAGE_ACCESS.to_ash_image_access();
+			let src = ImageAccess::TransferRead.to_ash_image_access();
+			let aspect_mask = image.format.aspects().to_ash_image_aspect_flags();
+			let subresource = |mip: u32| ImageSubresourceLayers {
+				aspect_mask,
+				mip_level: image.base_mip + mip,
+				base_array_layer: image.base_layer,
+				layer_count: image.array_layers,
+			};
+			let barrier = |mips: Range<u32>| {
+				ImageMemoryBarrier2::default()
+					.image(image.image)
+					.subresource_range(
+						ImageSubresourceRange::default()
+							.aspect_mask(aspect_mask)
+							.base_array_layer(image.base_layer)
+							.layer_count(image.array_layers)
+							.base_mip_level(image.base_mip + mips.start)
+							.level_count(mips.end - mips.start),
+					)
+					.src_queue_family_index(QUEUE_FAMILY_IGNORED)
+					.dst_queue_family_index(QUEUE_FAMILY_IGNORED)
+			};
+			let corner = |mip: u32| {
+				let extent = image.extent.mip(mip);
+				Offset3D {
+					x: extent.width as i32,
+					y: extent.height as i32,
+					z: extent.depth as i32,
+				}
+			};
+
+			for mip in 1..image.mip_levels {
+				// the previous mip was just written by a copy or blit, transition it to be read by the next blit
+				self.resource_context.push_image_barrier(
+					barrier(mip - 1..mip)
+						.src_access_mask(access.access_mask | AccessFlags2::TRANSFER_WRITE)
+						.src_stage_mask(access.stage_mask | PipelineStageFlags2::TRANSFER)
+						.old_layout(access.image_layout)
+						.dst_access_mask(src.access_mask)
+						.dst_stage_mask(src.stage_mask)
+						.new_layout(src.image_layout),
+				);
+				self.ash_flush();
+				self.bindless.platform.device.cmd_blit_image2(
+					self.cmd,
+					&BlitImageInfo2::default()
+						.src_image(image.image)
+						.src_image_layout(src.image_layout)
+						.dst_image(image.image)
+						.dst_image_layout(access.image_layout)
+						.filter(filter.to_ash_filter())
+						.regions(&[ImageBlit2::default()
+							.src_subresource(subresource(mip - 1))
+							.src_offsets([Offset3D::default(), corner(mip - 1)])
+							.dst_subresource(subresource(mip))
+							.dst_offsets([Offset3D::default(), corner(mip)])]),
+				);
+			}
+
+			// return all mips but the last, which was never read, back to the layout of `A`
+			self.resource_context.push_image_barrier(
+				barrier(0..image.mip_levels - 1)
+					.src_access_mask(src.access_mask)
+					.src_stage_mask(src.stage_mask)
+					.old_layout(src.image_layout)
+					.dst_access_mask(access.access_mask)
+					.dst_stage_mask(access.stage_mask)
+					.new_layout(access.image_layout),
+			);
+			Ok(())
+		}
+	}
+
+	unsafe fn fill_buffer<T: BufferContent + ?Sized, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<Ash, T, A>,
+		data: u32,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			self.ash_flush();
+			let device = &self.bindless.platform.device;
+			let buffer = buffer.inner_slot();
+			// never WHOLE_SIZE, sub-allocations only cover a part of their buffer
+			device.cmd_fill_buffer(self.cmd, buffer.buffer, buffer.offset, buffer.size, data);
+			Ok(())
+		}
+	}
+
+	unsafe fn update_buffer<T: BufferStruct, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<Ash, [T], A>,
+		offset: u64,
+		data: &[u8],
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			self.ash_flush();
+			let device = &self.bindless.platform.device;
+			let buffer = buffer.inner_slot();
+			device.cmd_update_buffer(self.cmd, buffer.buffer, buffer.offset + offset, data);
+			Ok(())
+		}
+	}
+
+	unsafe fn clear_color_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<Ash, T, A>,
+		value: ClearValue,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			self.ash_flush();
+			let device = &self.bindless.platform.device;
+			let image = image.inner_slot();
+			device.cmd_clear_color_image(
+				self.cmd,
+				image.image,
+				A::IMAGE_ACCESS.to_ash_image_access().image_layout,
+				&value.to_ash().color,
+				&[image.to_ash_subresource_range()],
+			);
+			Ok(())
+		}
+	}
+
+	unsafe fn clear_depth_stencil_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<Ash, T, A>,
+		depth: f32,
+		stencil: u32,
+	) -> Result<(), AshRecordingError> {
+		unsafe {
+			self.ash_flush();
+			let device = &self.bindless.platform.device;
+			let image = image.inner_slot();
+			device.cmd_clear_depth_stencil_image(
+				self.cmd,
+				image.image,
+				A::IMAGE_ACCESS.to_ash_image_access().image_layout,
+				&ClearDepthStencilValue { depth, stencil },
+				&[image.to_ash_subresource_range()],
 			);
 			Ok(())
 		}
@@ -528,7 +776,8 @@ unsafe impl<'a> RecordingContext<'a, Ash> for AshRecordingContext<'a> {
 		unsafe {
 			self.ash_bind_compute(pipeline, param);
 			let device = &self.bindless.platform.device;
-			device.cmd_dispatch_indirect(self.cmd, indirect.inner_slot().buffer, 0);
+			let indirect = indirect.inner_slot();
+			device.cmd_dispatch_indirect(self.cmd, indirect.buffer, indirect.offset);
 			Ok(())
 		}
 	}
@@ -540,6 +789,8 @@ pub enum AshRecordingError {
 	Vk(#[from] ash::vk::Result),
 	#[error("No barriers must be inserted while rendering: {collector:?}")]
 	BarrierWhileRendering { collector: Box<AshBarrierCollector> },
+	#[error("Image \"{name}\" has format {format:?}, which does not support the required blits")]
+	BlitUnsupported { name: String, format: Format },
 }
 
 impl Debug for AshRecordingError {
@@ -3,6 +3,7 @@ use crate::pipeline::{
 	BindlessGraphicsPipeline, BindlessMeshGraphicsPipeline, ColorAttachment, DepthStencilAttachment,
 	DrawIndexedIndirectCommand, DrawIndirectCommand, HasResourceContext, IndexReadable, IndexTypeTrait,
 	IndirectCommandReadable, MutOrSharedBuffer, RecordingError, RenderPassFormat, RenderingAttachment,
+	RenderingImageType,
 };
 use crate::platform::RenderingContext;
 use crate::platform::ash::bindless_pipeline::AshPipeline;
@@ -199,12 +200,12 @@ unsafe impl<'a> HasResourceContext<'a, Ash> for AshRenderingContext<'a, '_> {
 }
 
 unsafe impl<'a, 'b> RenderingContext<'a, 'b, Ash> for AshRenderingContext<'a, 'b> {
-	unsafe fn begin_rendering(
+	unsafe fn begin_rendering<T: RenderingImageType>(
 		recording: &'b mut AshRecordingContext<'a>,
 		_format: RenderPassFormat,
 		render_area: UVec2,
-		color_attachments: &[RenderingAttachment<Ash, ColorAttachment>],
-		depth_attachment: Option<RenderingAttachment<Ash, DepthStencilAttachment>>,
+		color_attachments: &[RenderingAttachment<Ash, ColorAttachment, T>],
+		depth_attachment: Option<RenderingAttachment<Ash, DepthStencilAttachment, T>>,
 	) -> Result<Self, AshRecordingError> {
 		unsafe {
 			recording.ash_flush();
@@ -284,10 +285,11 @@ unsafe impl<'a, 'b> RenderingContext<'a, 'b, Ash> for AshRenderingContext<'a, 'b
 		unsafe {
 			self.ash_bind_graphics(pipeline, param)?;
 			let device = &self.bindless.platform.device;
+			let index_buffer = index_buffer.inner_slot();
 			device.cmd_bind_index_buffer(
 				self.cmd,
-				index_buffer.inner_slot().buffer,
-				0,
+				index_buffer.buffer,
+				index_buffer.offset,
 				IT::INDEX_TYPE.to_ash_index_type(),
 			);
 			device.cmd_draw_indexed(
@@ -312,7 +314,13 @@ unsafe impl<'a, 'b> RenderingContext<'a, 'b, Ash> for AshRenderingContext<'a, 'b
 			self.ash_bind_graphics(pipeline, param)?;
 			let device = &self.bindless.platform.device;
 			let indirect = indirect.inner_slot();
-			device.cmd_draw_indirect(self.cmd, indirect.buffer, 0, 1, size_of::<DrawIndirectCommand>() as u32);
+			device.cmd_draw_indirect(
+				self.cmd,
+				indirect.buffer,
+				indirect.offset,
+				1,
+				size_of::<DrawIndirectCommand>() as u32,
+			);
 			Ok(())
 		}
 	}
@@ -333,16 +341,17 @@ unsafe impl<'a, 'b> RenderingContext<'a, 'b, Ash> for AshRenderingContext<'a, 'b
 			self.ash_bind_graphics(pipeline, param)?;
 			let device = &self.bindless.platform.device;
 			let indirect = indirect.inner_slot();
+			let index_buffer = index_buffer.inner_slot();
 			device.cmd_bind_index_buffer(
 				self.cmd,
-				index_buffer.inner_slot().buffer,
-				0,
+				index_buffer.buffer,
+				index_buffer.offset,
 				IT::INDEX_TYPE.to_ash_index_type(),
 			);
 			device.cmd_draw_indexed_indirect(
 				self.cmd,
 				indirect.buffer,
-				0,
+				indirect.offset,
 				1,
 				size_of::<DrawIndexedIndirectCommand>() as u32,
 			);
@@ -374,7 +383,13 @@ unsafe impl<'a, 'b> RenderingContext<'a, 'b, Ash> for AshRenderingContext<'a, 'b
 			self.ash_bind_mesh_graphics(pipeline, param)?;
 			let device = &self.bindless.platform.extensions.mesh_shader();
 			let indirect = indirect.inner_slot();
-			device.cmd_draw_mesh_tasks_indirect(self.cmd, indirect.buffer, 0, 1, size_of::<[u32; 3]>() as u32);
+			device.cmd_draw_mesh_tasks_indirect(
+				self.cmd,
+				indirect.buffer,
+				indirect.offset,
+				1,
+				size_of::<[u32; 3]>() as u32,
+			);
 			Ok(())
 		}
 	}
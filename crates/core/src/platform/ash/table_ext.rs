@@ -83,7 +83,10 @@ impl BufferTableAccess<'_, Ash> {
 				},
 				len,
 				size: ash_create_info.size,
+				offset: 0,
+				sub_allocation: None,
 				usage,
+				allocation_scheme,
 				strong_refs: Default::default(),
 				access_lock: AccessLock::new(prev_access_type),
 				debug_name: name.to_string(),
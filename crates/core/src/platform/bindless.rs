@@ -1,12 +1,14 @@
 use crate::backing::range_set::DescriptorIndexIterator;
 use crate::backing::table::DrainFlushQueue;
 use crate::descriptor::{
-	Bindless, BindlessBufferCreateInfo, BindlessImageCreateInfo, BindlessSamplerCreateInfo, BufferAllocationError,
-	BufferInterface, BufferSlot, DescriptorCounts, ImageAllocationError, ImageInterface, SamplerAllocationError,
+	Bindless, BindlessBufferCreateInfo, BindlessImageCreateInfo, BindlessImageUsage, BindlessImageViewCreateInfo,
+	BindlessSamplerCreateInfo, BufferAllocationError, BufferInterface, BufferSlot, CustomTableBinding,
+	DescriptorCounts, Format, ImageAllocationError, ImageInterface, ImageSlot, SamplerAllocationError,
 	SamplerInterface, WeakBindless,
 };
 use rust_gpu_bindless_shaders::descriptor::ImageType;
 use std::error::Error;
+use std::fmt::Debug;
 use std::future::Future;
 
 /// Internal interface for bindless API calls, may change at any time!
@@ -24,6 +26,8 @@ pub unsafe trait BindlessPlatform: Sized + Send + Sync + 'static {
 		+ Into<ImageAllocationError<Self>>
 		+ Into<SamplerAllocationError<Self>>;
 	type BindlessDescriptorSet: 'static + Send + Sync;
+	/// Platform-specific information about the descriptor binding of a custom table, see [`CustomTableBinding`]
+	type CustomBindingInfo: 'static + Copy + Debug + Send + Sync;
 	type PendingExecution: PendingExecution<Self>;
 
 	/// Create an [`Self::Platform`] from the supplied [`Self::PlatformCreateInfo`]. Typically, [`Self::PlatformCreateInfo`] wrap the
@@ -37,7 +41,13 @@ pub unsafe trait BindlessPlatform: Sized + Send + Sync + 'static {
 
 	unsafe fn update_after_bind_descriptor_limits(&self) -> DescriptorCounts;
 
-	unsafe fn create_descriptor_set(&self, counts: DescriptorCounts) -> Self::BindlessDescriptorSet;
+	/// Create the global descriptor set containing the buffer, image and sampler bindings sized by `counts`, as well as
+	/// the bindings of all `custom_tables`.
+	unsafe fn create_descriptor_set(
+		&self,
+		counts: DescriptorCounts,
+		custom_tables: &[CustomTableBinding<Self>],
+	) -> Self::BindlessDescriptorSet;
 
 	/// Bindless has been fully initialized but not yet returned to the end user. Feel free to do any required
 	/// modifications or buffer allocations here.
@@ -69,11 +79,56 @@ pub unsafe trait BindlessPlatform: Sized + Send + Sync + 'static {
 		size: u64,
 	) -> Result<Self::Buffer, Self::AllocationError>;
 
+	/// The alignment in bytes required for the offset of sub-allocated buffers, see [`BufferPool`].
+	///
+	/// [`BufferPool`]: crate::descriptor::BufferPool
+	unsafe fn min_buffer_offset_alignment(&self) -> u64;
+
+	/// Create a non-owning alias of `buffer` for a sub-allocation of a [`BufferPool`]. The alias is never passed to
+	/// [`Self::destroy_buffers`] and must not free any resources when dropped, `buffer` is kept alive for as long as
+	/// the alias exists. Mapping a sub-allocation maps `buffer` instead of the alias.
+	///
+	/// [`BufferPool`]: crate::descriptor::BufferPool
+	unsafe fn alias_buffer(&self, buffer: &Self::Buffer) -> Self::Buffer;
+
+	/// Whether images of `format` can be both the source and destination of blits, as required by
+	/// [`Recording::generate_mips`].
+	///
+	/// [`Recording::generate_mips`]: crate::pipeline::Recording::generate_mips
+	unsafe fn supports_blit(&self, format: Format) -> bool;
+
+	/// Which of the [`BindlessImageUsage::VIEW_USAGE`] views of `format` support, used to restrict the usage of
+	/// [`ImageTableAccess::alloc_view`] reinterpreting the format of an image.
+	///
+	/// [`ImageTableAccess::alloc_view`]: crate::descriptor::ImageTableAccess::alloc_view
+	unsafe fn supported_view_usage(&self, format: Format) -> BindlessImageUsage;
+
 	unsafe fn alloc_image<T: ImageType>(
 		&self,
 		create_info: &BindlessImageCreateInfo<T>,
 	) -> Result<Self::Image, Self::AllocationError>;
 
+	/// Create a view of the mips and layers of `image` described by `create_info`, whose format has already been
+	/// resolved and validated. `create_info` is relative to the mips and layers of `image`, which may itself be a view.
+	/// `usage` is the subset of the usage of `image` supported by the format of the view, see
+	/// [`Self::supported_view_usage`]. The returned view must not own the memory of `image`, and is destroyed in
+	/// [`Self::destroy_images`] with [`ImageSlot::parent`] set.
+	unsafe fn alloc_image_view<V: ImageType>(
+		&self,
+		image: &ImageSlot<Self>,
+		create_info: &BindlessImageViewCreateInfo,
+		usage: BindlessImageUsage,
+	) -> Result<Self::Image, Self::AllocationError>;
+
+	/// The maximum absolute [`BindlessSamplerCreateInfo::mip_lod_bias`] of samplers
+	unsafe fn max_sampler_lod_bias(&self) -> f32;
+
+	/// Whether samplers may use a [`BindlessSamplerCreateInfo::reduction_mode`] other than
+	/// [`ReductionMode::WeightedAverage`]
+	///
+	/// [`ReductionMode::WeightedAverage`]: crate::descriptor::ReductionMode::WeightedAverage
+	unsafe fn supports_sampler_reduction(&self) -> bool;
+
 	unsafe fn alloc_sampler(
 		&self,
 		create_info: &BindlessSamplerCreateInfo,
@@ -1,10 +1,11 @@
-use crate::descriptor::{Bindless, BufferSlot, ImageSlot};
+use crate::descriptor::{Bindless, BufferSlot, Filter, FormatAspects, ImageSlot};
 use crate::pipeline::{
 	BindlessComputePipeline, BindlessGraphicsPipeline, BindlessMeshGraphicsPipeline, BufferAccess, BufferAccessType,
-	ColorAttachment, DepthStencilAttachment, DrawIndexedIndirectCommand, DrawIndirectCommand,
-	GraphicsPipelineCreateInfo, HasResourceContext, ImageAccess, ImageAccessType, IndexReadable, IndexTypeTrait,
-	IndirectCommandReadable, MeshGraphicsPipelineCreateInfo, MutBufferAccess, MutImageAccess, MutOrSharedBuffer,
-	Recording, RecordingError, RenderPassFormat, RenderingAttachment, TransferReadable, TransferWriteable,
+	BufferCopyRegion, BufferImageCopyRegion, ClearValue, ColorAttachment, DepthStencilAttachment,
+	DrawIndexedIndirectCommand, DrawIndirectCommand, GraphicsPipelineCreateInfo, HasResourceContext, ImageAccess,
+	ImageAccessType, ImageCopyRegion, IndexReadable, IndexTypeTrait, IndirectCommandReadable,
+	MeshGraphicsPipelineCreateInfo, MutBufferAccess, MutImageAccess, MutOrSharedBuffer, Recording, RecordingError,
+	RenderPassFormat, RenderingAttachment, RenderingImageType, ResolveMode, TransferReadable, TransferWriteable,
 };
 use crate::platform::BindlessPlatform;
 use glam::UVec2;
@@ -54,6 +55,16 @@ pub unsafe trait BindlessPipelinePlatform: BindlessPlatform {
 		mesh_shader: &impl BindlessShader<ShaderType = MeshShader, ParamConstant = T>,
 		fragment_shader: &impl BindlessShader<ShaderType = FragmentShader, ParamConstant = T>,
 	) -> Result<Self::MeshGraphicsPipeline, Self::PipelineCreationError>;
+
+	/// Whether the `aspect` of multisampled depth stencil attachments can be resolved with `mode`, see
+	/// [`ResolveAttachment`].
+	///
+	/// [`ResolveAttachment`]: crate::pipeline::ResolveAttachment
+	unsafe fn supports_depth_stencil_resolve(
+		bindless: &Bindless<Self>,
+		mode: ResolveMode,
+		aspect: FormatAspects,
+	) -> bool;
 }
 
 pub unsafe trait RecordingContext<'a, P: BindlessPipelinePlatform>: HasResourceContext<'a, P> {
@@ -79,8 +90,20 @@ pub unsafe trait RecordingContext<'a, P: BindlessPipelinePlatform>: HasResourceC
 		dst: &MutBufferAccess<P, [T], DA>,
 	) -> Result<(), P::RecordingError>;
 
-	/// Copy data from a buffer to an image. It is assumed that the image data is tightly packed within the buffer.
-	/// Partial copies and copying to mips other than mip 0 is not yet possible.
+	/// Copy a range of elements of one buffer of a slice to another buffer of the same slice. `region` has been
+	/// bounds checked.
+	unsafe fn copy_buffer_to_buffer_region<
+		T: BufferStruct,
+		SA: BufferAccessType + TransferReadable,
+		DA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: impl MutOrSharedBuffer<P, [T], SA>,
+		dst: &MutBufferAccess<P, [T], DA>,
+		region: &BufferCopyRegion,
+	) -> Result<(), P::RecordingError>;
+
+	/// Copy data from a region of a buffer to a region of an image. `region` has been bounds checked.
 	unsafe fn copy_buffer_to_image<
 		BT: BufferContent + ?Sized,
 		BA: BufferAccessType + TransferReadable,
@@ -90,10 +113,10 @@ pub unsafe trait RecordingContext<'a, P: BindlessPipelinePlatform>: HasResourceC
 		&mut self,
 		src: &MutBufferAccess<P, BT, BA>,
 		dst: &MutImageAccess<P, IT, IA>,
+		region: &BufferImageCopyRegion,
 	) -> Result<(), P::RecordingError>;
 
-	/// Copy data from an image to a buffer. It is assumed that the image data is tightly packed within the buffer.
-	/// Partial copies and copying to mips other than mip 0 is not yet possible.
+	/// Copy data from a region of an image to a region of a buffer. `region` has been bounds checked.
 	///
 	/// # Safety
 	/// This allows any data to be written to the buffer, without checking the buffer's type, potentially transmuting
@@ -107,6 +130,77 @@ pub unsafe trait RecordingContext<'a, P: BindlessPipelinePlatform>: HasResourceC
 		&mut self,
 		src: &MutImageAccess<P, IT, IA>,
 		dst: &MutBufferAccess<P, BT, BA>,
+		region: &BufferImageCopyRegion,
+	) -> Result<(), P::RecordingError>;
+
+	/// Copy a region of one image to another image. `region` has been validated.
+	unsafe fn copy_image_to_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: &MutImageAccess<P, ST, SA>,
+		dst: &MutImageAccess<P, DT, DA>,
+		region: &ImageCopyRegion,
+	) -> Result<(), P::RecordingError>;
+
+	/// Blit a region of one image to another image. `region` has been validated.
+	unsafe fn blit_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: &MutImageAccess<P, ST, SA>,
+		dst: &MutImageAccess<P, DT, DA>,
+		region: &ImageCopyRegion,
+		filter: Filter,
+	) -> Result<(), P::RecordingError>;
+
+	/// Generate all mips of an image from its mip 0, see [`Recording::generate_mips`]. `image` has at least 2 mips.
+	unsafe fn generate_mips<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<P, T, A>,
+	) -> Result<(), P::RecordingError>;
+
+	/// Fill the entire buffer with `data`. The offset and size of the buffer are a multiple of 4.
+	///
+	/// # Safety
+	/// This allows any data to be written to the buffer, without checking the buffer's type, potentially transmuting
+	/// data.
+	unsafe fn fill_buffer<T: BufferContent + ?Sized, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<P, T, A>,
+		data: u32,
+	) -> Result<(), P::RecordingError>;
+
+	/// Write `data` into the buffer at `offset` bytes. The range has been bounds checked, `offset` and the size of
+	/// `data` are a multiple of 4 and `data` is at most [`UPDATE_BUFFER_MAX_SIZE`] bytes large.
+	///
+	/// [`UPDATE_BUFFER_MAX_SIZE`]: crate::pipeline::UPDATE_BUFFER_MAX_SIZE
+	unsafe fn update_buffer<T: BufferStruct, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<P, [T], A>,
+		offset: u64,
+		data: &[u8],
+	) -> Result<(), P::RecordingError>;
+
+	/// Clear all mips and layers of a color image. `value` is not [`ClearValue::DepthStencil`].
+	unsafe fn clear_color_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<P, T, A>,
+		value: ClearValue,
+	) -> Result<(), P::RecordingError>;
+
+	/// Clear all mips and layers of a depth and / or stencil image.
+	unsafe fn clear_depth_stencil_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<P, T, A>,
+		depth: f32,
+		stencil: u32,
 	) -> Result<(), P::RecordingError>;
 
 	/// Dispatch a bindless compute shader
@@ -137,12 +231,12 @@ pub unsafe trait RecordingResourceContext<P: BindlessPipelinePlatform>: 'static
 }
 
 pub unsafe trait RenderingContext<'a, 'b, P: BindlessPipelinePlatform>: HasResourceContext<'a, P> {
-	unsafe fn begin_rendering(
+	unsafe fn begin_rendering<T: RenderingImageType>(
 		recording: &'b mut P::RecordingContext<'a>,
 		format: RenderPassFormat,
 		render_area: UVec2,
-		color_attachments: &[RenderingAttachment<P, ColorAttachment>],
-		depth_attachment: Option<RenderingAttachment<P, DepthStencilAttachment>>,
+		color_attachments: &[RenderingAttachment<P, ColorAttachment, T>],
+		depth_attachment: Option<RenderingAttachment<P, DepthStencilAttachment, T>>,
 	) -> Result<Self, P::RecordingError>;
 
 	unsafe fn end_rendering(&mut self) -> Result<(), P::RecordingError>;
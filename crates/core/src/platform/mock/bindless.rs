@@ -0,0 +1,415 @@
+use crate::backing::range_set::DescriptorIndexIterator;
+use crate::backing::table::DrainFlushQueue;
+use crate::descriptor::{
+	Bindless, BindlessBufferCreateInfo, BindlessImageCreateInfo, BindlessImageUsage, BindlessImageViewCreateInfo,
+	BindlessSamplerCreateInfo, BufferAllocationError, BufferInterface, BufferSlot, CustomTableBinding,
+	DescriptorCounts, Format, FormatAspects, ImageAllocationError, ImageInterface, ImageSlot, SamplerAllocationError,
+	SamplerInterface, WeakBindless,
+};
+use crate::platform::BindlessPlatform;
+use crate::platform::mock::{MockExecution, MockPendingExecution};
+use parking_lot::Mutex;
+use presser::Slab;
+use rust_gpu_bindless_shaders::descriptor::ImageType;
+use static_assertions::assert_impl_all;
+use std::cell::UnsafeCell;
+use std::convert::Infallible;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+use thiserror::Error;
+
+/// A platform that does not talk to any GPU. Buffers are allocated in host memory, images and samplers are only
+/// handles and all recorded commands are written into an inspectable log, see [`Mock::take_executions`].
+pub struct Mock {
+	pub create_info: MockCreateInfo,
+	next_handle: AtomicU64,
+	allocated_bytes: AtomicU64,
+	executions: Mutex<Vec<MockExecution>>,
+}
+assert_impl_all!(Bindless<Mock>: Send, Sync);
+
+impl Mock {
+	pub fn new(create_info: MockCreateInfo) -> Self {
+		Self {
+			create_info,
+			next_handle: AtomicU64::new(0),
+			allocated_bytes: AtomicU64::new(0),
+			executions: Mutex::new(Vec::new()),
+		}
+	}
+
+	/// Creates a new unique [`MockHandle`]
+	pub fn next_handle(&self) -> MockHandle {
+		MockHandle(self.next_handle.fetch_add(1, Relaxed))
+	}
+
+	/// The total size in bytes of all buffers that are currently alive
+	pub fn allocated_bytes(&self) -> u64 {
+		self.allocated_bytes.load(Relaxed)
+	}
+
+	/// Take all executions that have been submitted so far, in the order they were submitted.
+	pub fn take_executions(&self) -> Vec<MockExecution> {
+		mem::take(&mut *self.executions.lock())
+	}
+
+	pub(super) fn push_execution(&self, execution: MockExecution) {
+		self.executions.lock().push(execution);
+	}
+}
+
+impl Deref for Mock {
+	type Target = MockCreateInfo;
+
+	fn deref(&self) -> &Self::Target {
+		&self.create_info
+	}
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct MockCreateInfo {
+	/// The limits reported by [`BindlessPlatform::update_after_bind_descriptor_limits`]
+	pub limits: DescriptorCounts,
+	/// Buffer allocations fail with [`MockAllocationError::OutOfMemory`] if they would exceed this many bytes
+	pub memory_budget: Option<u64>,
+	/// Whether the mock device supports the feature `samplerFilterMinmax`, see
+	/// [`BindlessPlatform::supports_sampler_reduction`]
+	pub sampler_filter_minmax: bool,
+}
+
+impl Default for MockCreateInfo {
+	fn default() -> Self {
+		Self {
+			limits: DescriptorCounts::REASONABLE_DEFAULTS,
+			memory_budget: None,
+			sampler_filter_minmax: true,
+		}
+	}
+}
+
+/// Unique identifier of a mock resource or pipeline
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct MockHandle(pub u64);
+
+/// Host memory backing a [`MockBuffer`]
+#[derive(Debug)]
+pub struct MockMemory(Box<[u8]>);
+
+impl MockMemory {
+	pub fn new(size: usize) -> Self {
+		Self(vec![0; size].into_boxed_slice())
+	}
+}
+
+impl Deref for MockMemory {
+	type Target = [u8];
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl DerefMut for MockMemory {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+unsafe impl Slab for MockMemory {
+	fn base_ptr(&self) -> *const u8 {
+		self.0.as_ptr()
+	}
+
+	fn base_ptr_mut(&mut self) -> *mut u8 {
+		self.0.as_mut_ptr()
+	}
+
+	fn size(&self) -> usize {
+		self.0.len()
+	}
+}
+
+pub struct MockBuffer {
+	pub handle: MockHandle,
+	memory: UnsafeCell<MockMemory>,
+}
+
+/// Safety: the memory is only accessed mutably by whoever has exclusive access to the buffer, see
+/// [`MockBuffer::memory_mut`]
+unsafe impl Send for MockBuffer {}
+unsafe impl Sync for MockBuffer {}
+
+impl MockBuffer {
+	/// Get shared access to the host memory backing this buffer
+	///
+	/// # Safety
+	/// You must ensure nobody has mutable access to the memory
+	pub unsafe fn memory(&self) -> &MockMemory {
+		unsafe { &*self.memory.get() }
+	}
+
+	/// Get exclusive mutable access to the host memory backing this buffer
+	///
+	/// # Safety
+	/// You must ensure you have exclusive mutable access to the memory
+	#[allow(clippy::mut_from_ref)]
+	pub unsafe fn memory_mut(&self) -> &mut MockMemory {
+		unsafe { &mut *self.memory.get() }
+	}
+}
+
+#[derive(Debug)]
+pub struct MockImage {
+	pub handle: MockHandle,
+}
+
+#[derive(Debug)]
+pub struct MockSampler {
+	pub handle: MockHandle,
+	pub create_info: BindlessSamplerCreateInfo,
+}
+
+#[derive(Clone, Debug)]
+pub struct MockBindlessDescriptorSet {
+	pub counts: DescriptorCounts,
+	pub custom_tables: Vec<CustomTableBinding<Mock>>,
+}
+
+#[derive(Error)]
+pub enum MockAllocationError {
+	#[error("Out of mock memory: requested {size} bytes with {allocated} of {budget} bytes already allocated")]
+	OutOfMemory { size: u64, allocated: u64, budget: u64 },
+}
+
+impl core::fmt::Debug for MockAllocationError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		core::fmt::Display::fmt(self, f)
+	}
+}
+
+impl From<MockAllocationError> for BufferAllocationError<Mock> {
+	fn from(value: MockAllocationError) -> Self {
+		BufferAllocationError::Platform(value)
+	}
+}
+
+impl From<MockAllocationError> for ImageAllocationError<Mock> {
+	fn from(value: MockAllocationError) -> Self {
+		ImageAllocationError::Platform(value)
+	}
+}
+
+impl From<MockAllocationError> for SamplerAllocationError<Mock> {
+	fn from(value: MockAllocationError) -> Self {
+		SamplerAllocationError::Platform(value)
+	}
+}
+
+unsafe impl BindlessPlatform for Mock {
+	type PlatformCreateInfo = MockCreateInfo;
+	type PlatformCreateError = Infallible;
+	type Buffer = MockBuffer;
+	type Image = MockImage;
+	type Sampler = MockSampler;
+	type AllocationError = MockAllocationError;
+	type BindlessDescriptorSet = MockBindlessDescriptorSet;
+	type CustomBindingInfo = ();
+	type PendingExecution = MockPendingExecution;
+
+	unsafe fn create_platform(
+		create_info: Self::PlatformCreateInfo,
+		_bindless_cyclic: &WeakBindless<Self>,
+	) -> Result<Self, Self::PlatformCreateError> {
+		Ok(Mock::new(create_info))
+	}
+
+	unsafe fn update_after_bind_descriptor_limits(&self) -> DescriptorCounts {
+		self.limits
+	}
+
+	unsafe fn create_descriptor_set(
+		&self,
+		counts: DescriptorCounts,
+		custom_tables: &[CustomTableBinding<Self>],
+	) -> Self::BindlessDescriptorSet {
+		MockBindlessDescriptorSet {
+			counts,
+			custom_tables: custom_tables.to_vec(),
+		}
+	}
+
+	unsafe fn bindless_initialized(&self, _bindless: &Bindless<Self>) {}
+
+	unsafe fn bindless_shutdown(&self, _bindless: &Bindless<Self>) {}
+
+	unsafe fn update_descriptor_set(
+		&self,
+		_set: &Self::BindlessDescriptorSet,
+		buffers: DrainFlushQueue<BufferInterface<Self>>,
+		images: DrainFlushQueue<ImageInterface<Self>>,
+		samplers: DrainFlushQueue<SamplerInterface<Self>>,
+	) {
+		// there is no descriptor set to write, but the queues must still be drained
+		for _ in buffers {}
+		for _ in images {}
+		for _ in samplers {}
+	}
+
+	unsafe fn destroy_descriptor_set(&self, _set: Self::BindlessDescriptorSet) {}
+
+	unsafe fn alloc_buffer(
+		&self,
+		_create_info: &BindlessBufferCreateInfo,
+		size: u64,
+	) -> Result<Self::Buffer, Self::AllocationError> {
+		if let Some(budget) = self.memory_budget {
+			// only add if it stays within budget, so concurrent allocations can't fail spuriously
+			self.allocated_bytes
+				.fetch_update(Relaxed, Relaxed, |allocated| {
+					(allocated + size <= budget).then_some(allocated + size)
+				})
+				.map_err(|allocated| MockAllocationError::OutOfMemory {
+					size,
+					allocated,
+					budget,
+				})?;
+		} else {
+			self.allocated_bytes.fetch_add(size, Relaxed);
+		}
+		Ok(MockBuffer {
+			handle: self.next_handle(),
+			memory: UnsafeCell::new(MockMemory::new(size as usize)),
+		})
+	}
+
+	unsafe fn min_buffer_offset_alignment(&self) -> u64 {
+		16
+	}
+
+	unsafe fn supports_blit(&self, format: Format) -> bool {
+		// like most devices, block-compressed and multi-planar formats can't be blitted
+		format.block_extent() == [1, 1] && !format.aspects().contains(FormatAspects::PLANE_0)
+	}
+
+	unsafe fn supported_view_usage(&self, format: Format) -> BindlessImageUsage {
+		// like most devices, sRGB formats can't be used as storage images
+		let mut usage = BindlessImageUsage::SAMPLED;
+		if format.is_depth_stencil() {
+			usage |= BindlessImageUsage::DEPTH_STENCIL_ATTACHMENT;
+		} else if format.block_extent() == [1, 1] {
+			usage |= BindlessImageUsage::COLOR_ATTACHMENT;
+			if !format.is_srgb() {
+				usage |= BindlessImageUsage::STORAGE;
+			}
+		}
+		usage
+	}
+
+	unsafe fn alias_buffer(&self, buffer: &Self::Buffer) -> Self::Buffer {
+		// sub-allocations are mapped and copied using the backing buffer, so the alias needs no memory
+		MockBuffer {
+			handle: buffer.handle,
+			memory: UnsafeCell::new(MockMemory::new(0)),
+		}
+	}
+
+	unsafe fn alloc_image<T: ImageType>(
+		&self,
+		_create_info: &BindlessImageCreateInfo<T>,
+	) -> Result<Self::Image, Self::AllocationError> {
+		Ok(MockImage {
+			handle: self.next_handle(),
+		})
+	}
+
+	unsafe fn alloc_image_view<V: ImageType>(
+		&self,
+		_image: &ImageSlot<Self>,
+		_create_info: &BindlessImageViewCreateInfo,
+		_usage: BindlessImageUsage,
+	) -> Result<Self::Image, Self::AllocationError> {
+		Ok(MockImage {
+			handle: self.next_handle(),
+		})
+	}
+
+	unsafe fn max_sampler_lod_bias(&self) -> f32 {
+		16.
+	}
+
+	unsafe fn supports_sampler_reduction(&self) -> bool {
+		self.sampler_filter_minmax
+	}
+
+	unsafe fn alloc_sampler(
+		&self,
+		create_info: &BindlessSamplerCreateInfo,
+	) -> Result<Self::Sampler, Self::AllocationError> {
+		Ok(MockSampler {
+			handle: self.next_handle(),
+			create_info: *create_info,
+		})
+	}
+
+	unsafe fn mapped_buffer_to_slab(buffer: &BufferSlot<Self>) -> &mut (impl Slab + '_) {
+		unsafe { buffer.memory_mut() }
+	}
+
+	unsafe fn destroy_buffers<'a>(
+		&self,
+		_global_descriptor_set: &Self::BindlessDescriptorSet,
+		buffers: impl DescriptorIndexIterator<'a, BufferInterface<Self>>,
+	) {
+		for (_, buffer) in buffers.into_iter() {
+			self.allocated_bytes.fetch_sub(buffer.size, Relaxed);
+		}
+	}
+
+	unsafe fn destroy_images<'a>(
+		&self,
+		_global_descriptor_set: &Self::BindlessDescriptorSet,
+		_images: impl DescriptorIndexIterator<'a, ImageInterface<Self>>,
+	) {
+	}
+
+	unsafe fn destroy_samplers<'a>(
+		&self,
+		_global_descriptor_set: &Self::BindlessDescriptorSet,
+		_samplers: impl DescriptorIndexIterator<'a, SamplerInterface<Self>>,
+	) {
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{BindlessInstance, BufferAllocationError, DescriptorCounts};
+	use crate::platform::mock::test_utils::buffer_ci;
+
+	#[test]
+	fn test_memory_budget() -> anyhow::Result<()> {
+		unsafe {
+			let bindless = BindlessInstance::<Mock>::new(
+				MockCreateInfo {
+					memory_budget: Some(256),
+					..MockCreateInfo::default()
+				},
+				DescriptorCounts::REASONABLE_DEFAULTS,
+			);
+			let _first = bindless.buffer().alloc_slice::<u32>(&buffer_ci("first"), 48)?;
+			let second = bindless.buffer().alloc_slice::<u32>(&buffer_ci("second"), 32);
+			assert!(matches!(
+				second,
+				Err(BufferAllocationError::Platform(MockAllocationError::OutOfMemory {
+					size: 128,
+					allocated: 192,
+					budget: 256,
+				}))
+			));
+			assert_eq!(bindless.allocated_bytes(), 192);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,110 @@
+use crate::descriptor::{Bindless, FormatAspects};
+use crate::pipeline::{
+	GraphicsPipelineCreateInfo, MeshGraphicsPipelineCreateInfo, Recording, RecordingError, RenderPassFormat,
+	ResolveMode,
+};
+use crate::platform::BindlessPipelinePlatform;
+use crate::platform::mock::{
+	Mock, MockHandle, MockRecordingContext, MockRecordingError, MockRecordingResourceContext, MockRenderingContext,
+	mock_record_and_execute,
+};
+use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
+use rust_gpu_bindless_shaders::shader::BindlessShader;
+use rust_gpu_bindless_shaders::shader_type::{ComputeShader, FragmentShader, MeshShader, TaskShader, VertexShader};
+use std::convert::Infallible;
+
+unsafe impl BindlessPipelinePlatform for Mock {
+	type PipelineCreationError = Infallible;
+	type ComputePipeline = MockPipeline;
+	type RecordingResourceContext = MockRecordingResourceContext;
+	type RecordingContext<'a> = MockRecordingContext<'a>;
+	type RecordingError = MockRecordingError;
+
+	unsafe fn create_compute_pipeline<T: BufferStruct>(
+		bindless: &Bindless<Self>,
+		compute_shader: &impl BindlessShader<ShaderType = ComputeShader, ParamConstant = T>,
+	) -> Result<Self::ComputePipeline, Self::PipelineCreationError> {
+		Ok(MockPipeline::new(
+			bindless,
+			vec![MockPipeline::entry_point(compute_shader)],
+		))
+	}
+
+	unsafe fn record_and_execute<R: Send + Sync>(
+		bindless: &Bindless<Self>,
+		f: impl FnOnce(&mut Recording<'_, Self>) -> Result<R, RecordingError<Self>>,
+	) -> Result<R, RecordingError<Self>> {
+		unsafe { mock_record_and_execute(bindless, f) }
+	}
+
+	type GraphicsPipeline = MockPipeline;
+	type MeshGraphicsPipeline = MockPipeline;
+	type RenderingContext<'a: 'b, 'b> = MockRenderingContext<'a, 'b>;
+
+	unsafe fn create_graphics_pipeline<T: BufferStruct>(
+		bindless: &Bindless<Self>,
+		_render_pass: &RenderPassFormat,
+		_create_info: &GraphicsPipelineCreateInfo,
+		vertex_shader: &impl BindlessShader<ShaderType = VertexShader, ParamConstant = T>,
+		fragment_shader: &impl BindlessShader<ShaderType = FragmentShader, ParamConstant = T>,
+	) -> Result<Self::GraphicsPipeline, Self::PipelineCreationError> {
+		Ok(MockPipeline::new(
+			bindless,
+			vec![
+				MockPipeline::entry_point(vertex_shader),
+				MockPipeline::entry_point(fragment_shader),
+			],
+		))
+	}
+
+	unsafe fn create_mesh_graphics_pipeline<T: BufferStruct>(
+		bindless: &Bindless<Self>,
+		_render_pass: &RenderPassFormat,
+		_create_info: &MeshGraphicsPipelineCreateInfo,
+		task_shader: Option<&impl BindlessShader<ShaderType = TaskShader, ParamConstant = T>>,
+		mesh_shader: &impl BindlessShader<ShaderType = MeshShader, ParamConstant = T>,
+		fragment_shader: &impl BindlessShader<ShaderType = FragmentShader, ParamConstant = T>,
+	) -> Result<Self::MeshGraphicsPipeline, Self::PipelineCreationError> {
+		let entry_points = task_shader
+			.map(MockPipeline::entry_point)
+			.into_iter()
+			.chain([
+				MockPipeline::entry_point(mesh_shader),
+				MockPipeline::entry_point(fragment_shader),
+			])
+			.collect::<Vec<_>>();
+		Ok(MockPipeline::new(bindless, entry_points))
+	}
+
+	unsafe fn supports_depth_stencil_resolve(
+		_bindless: &Bindless<Self>,
+		mode: ResolveMode,
+		aspect: FormatAspects,
+	) -> bool {
+		// stencil only supports the one mode required by Vulkan, depth all but averaging
+		match aspect {
+			FormatAspects::STENCIL => mode == ResolveMode::SampleZero,
+			_ => mode != ResolveMode::Average,
+		}
+	}
+}
+
+/// Any mock pipeline, remembering the entry point names of its shaders in the order they were supplied.
+#[derive(Debug)]
+pub struct MockPipeline {
+	pub handle: MockHandle,
+	pub entry_points: Vec<String>,
+}
+
+impl MockPipeline {
+	fn new(bindless: &Bindless<Mock>, entry_points: Vec<String>) -> Self {
+		Self {
+			handle: bindless.next_handle(),
+			entry_points,
+		}
+	}
+
+	fn entry_point(shader: &impl BindlessShader) -> String {
+		shader.spirv_binary().entry_point_name.to_string_lossy().into_owned()
+	}
+}
@@ -0,0 +1,35 @@
+use crate::platform::PendingExecution;
+use crate::platform::mock::{Mock, MockCommand};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The log of all commands recorded by a single [`Bindless::execute`] call.
+///
+/// [`Bindless::execute`]: crate::descriptor::Bindless::execute
+#[derive(Debug, Clone, Default)]
+pub struct MockExecution {
+	pub commands: Vec<MockCommand>,
+}
+
+/// Mock executions run on the host while they are being recorded, so they are always completed.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MockPendingExecution;
+
+impl Future for MockPendingExecution {
+	type Output = ();
+
+	fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+		Poll::Ready(())
+	}
+}
+
+unsafe impl PendingExecution<Mock> for MockPendingExecution {
+	fn new_completed() -> Self {
+		Self
+	}
+
+	fn completed(&self) -> bool {
+		true
+	}
+}
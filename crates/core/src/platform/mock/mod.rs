@@ -0,0 +1,19 @@
+/// main BindlessPlatform trait impl
+mod bindless;
+/// BindlessPipelinePlatform impl
+mod bindless_pipeline;
+/// Always completed executions and their command logs
+mod executing;
+/// Recording of commands into a log, buffer copies are executed immediately on the host
+mod recording;
+/// Recording of rendering cmds
+mod rendering;
+/// Shared setup of tests using the mock platform
+#[cfg(test)]
+pub(crate) mod test_utils;
+
+pub use bindless::*;
+pub use bindless_pipeline::*;
+pub use executing::*;
+pub use recording::*;
+pub use rendering::*;
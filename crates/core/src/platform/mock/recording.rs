@@ -0,0 +1,779 @@
+use crate::descriptor::{Bindless, BindlessFrame, BufferSlot, Filter, ImageSlot};
+use crate::pipeline::{
+	BindlessComputePipeline, BufferAccess, BufferAccessType, BufferCopyRegion, BufferImageCopyRegion, ClearValue,
+	HasResourceContext, ImageAccess, ImageAccessType, ImageCopyRegion, IndirectCommandReadable, MutBufferAccess,
+	MutImageAccess, MutOrSharedBuffer, Recording, RecordingError, TransferReadable, TransferWriteable,
+};
+use crate::platform::mock::{Mock, MockExecution, MockHandle, MockPendingExecution};
+use crate::platform::{BindlessPipelinePlatform, RecordingContext, RecordingResourceContext};
+use glam::UVec2;
+use rust_gpu_bindless_shaders::buffer_content::{BufferContent, BufferStruct};
+use rust_gpu_bindless_shaders::descriptor::{ImageType, TransientAccess};
+use rust_gpu_bindless_shaders::utils::rect::IRect2;
+use smallvec::SmallVec;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use thiserror::Error;
+
+/// A single command recorded by the [`Mock`] platform
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockCommand {
+	TransitionBuffer {
+		buffer: MockHandle,
+		src: BufferAccess,
+		dst: BufferAccess,
+	},
+	TransitionImage {
+		image: MockHandle,
+		src: ImageAccess,
+		dst: ImageAccess,
+	},
+	CopyBufferToBuffer {
+		src: MockHandle,
+		dst: MockHandle,
+		size: u64,
+	},
+	CopyBufferRegion {
+		src: MockHandle,
+		src_offset: u64,
+		dst: MockHandle,
+		dst_offset: u64,
+		size: u64,
+	},
+	CopyBufferToImage {
+		src: MockHandle,
+		dst: MockHandle,
+		region: BufferImageCopyRegion,
+	},
+	CopyImageToBuffer {
+		src: MockHandle,
+		dst: MockHandle,
+		region: BufferImageCopyRegion,
+	},
+	CopyImageToImage {
+		src: MockHandle,
+		dst: MockHandle,
+		region: ImageCopyRegion,
+	},
+	BlitImage {
+		src: MockHandle,
+		dst: MockHandle,
+		region: ImageCopyRegion,
+		filter: Filter,
+	},
+	GenerateMips {
+		image: MockHandle,
+		mip_levels: u32,
+	},
+	FillBuffer {
+		buffer: MockHandle,
+		data: u32,
+	},
+	UpdateBuffer {
+		buffer: MockHandle,
+		offset: u64,
+		size: u64,
+	},
+	ClearColorImage {
+		image: MockHandle,
+		value: ClearValue,
+	},
+	ClearDepthStencilImage {
+		image: MockHandle,
+		depth: f32,
+		stencil: u32,
+	},
+	Dispatch {
+		pipeline: MockHandle,
+		group_counts: [u32; 3],
+	},
+	DispatchIndirect {
+		pipeline: MockHandle,
+		indirect: MockHandle,
+	},
+	BeginRendering {
+		render_area: UVec2,
+		color_attachments: SmallVec<[MockHandle; 5]>,
+		depth_attachment: Option<MockHandle>,
+		/// the resolve images of the color attachments followed by the depth attachment, if any
+		resolve_attachments: SmallVec<[MockHandle; 5]>,
+	},
+	EndRendering,
+	SetViewport {
+		x: f32,
+		y: f32,
+		width: f32,
+		height: f32,
+		min_depth: f32,
+		max_depth: f32,
+	},
+	SetScissor(IRect2),
+	Draw {
+		pipeline: MockHandle,
+		vertex_count: u32,
+		instance_count: u32,
+		first_vertex: u32,
+		first_instance: u32,
+	},
+	DrawIndexed {
+		pipeline: MockHandle,
+		index_buffer: MockHandle,
+		index_count: u32,
+		instance_count: u32,
+		first_index: u32,
+		vertex_offset: i32,
+		first_instance: u32,
+	},
+	DrawIndirect {
+		pipeline: MockHandle,
+		indirect: MockHandle,
+	},
+	DrawIndexedIndirect {
+		pipeline: MockHandle,
+		index_buffer: MockHandle,
+		indirect: MockHandle,
+	},
+	DrawMeshTasks {
+		pipeline: MockHandle,
+		group_counts: [u32; 3],
+	},
+	DrawMeshTasksIndirect {
+		pipeline: MockHandle,
+		indirect: MockHandle,
+	},
+}
+
+impl MockCommand {
+	pub fn is_transition(&self) -> bool {
+		matches!(
+			self,
+			MockCommand::TransitionBuffer { .. } | MockCommand::TransitionImage { .. }
+		)
+	}
+}
+
+pub struct MockRecordingResourceContext {
+	frame: BindlessFrame<Mock>,
+	pub(super) commands: RefCell<Vec<MockCommand>>,
+}
+
+impl MockRecordingResourceContext {
+	pub fn new(frame: BindlessFrame<Mock>) -> Self {
+		Self {
+			frame,
+			commands: RefCell::new(Vec::new()),
+		}
+	}
+
+	pub fn push_command(&self, command: MockCommand) {
+		self.commands.borrow_mut().push(command);
+	}
+
+	/// The amount of commands recorded so far
+	pub fn command_count(&self) -> usize {
+		self.commands.borrow().len()
+	}
+
+	pub fn into_execution(self) -> MockExecution {
+		MockExecution {
+			commands: self.commands.into_inner(),
+		}
+	}
+}
+
+unsafe impl<'a> TransientAccess<'a> for &'a MockRecordingResourceContext {}
+
+unsafe impl RecordingResourceContext<Mock> for MockRecordingResourceContext {
+	fn to_transient_access(&self) -> impl TransientAccess<'_> {
+		self
+	}
+
+	fn add_dependency(&self, _pending: MockPendingExecution) {
+		// mock executions always complete immediately, nothing to wait on
+	}
+
+	fn to_pending_execution(&self) -> MockPendingExecution {
+		MockPendingExecution
+	}
+
+	unsafe fn transition_buffer(&self, buffer: &BufferSlot<Mock>, src: BufferAccess, dst: BufferAccess) {
+		self.push_command(MockCommand::TransitionBuffer {
+			buffer: buffer.handle,
+			src,
+			dst,
+		});
+	}
+
+	unsafe fn transition_image(&self, image: &ImageSlot<Mock>, src: ImageAccess, dst: ImageAccess) {
+		self.push_command(MockCommand::TransitionImage {
+			image: image.handle,
+			src,
+			dst,
+		});
+	}
+}
+
+pub unsafe fn mock_record_and_execute<R>(
+	bindless: &Bindless<Mock>,
+	f: impl FnOnce(&mut Recording<'_, Mock>) -> Result<R, RecordingError<Mock>>,
+) -> Result<R, RecordingError<Mock>> {
+	unsafe {
+		let resource = MockRecordingResourceContext::new(bindless.frame());
+		let r = {
+			let mut recording = Recording::new(MockRecordingContext::new(&resource));
+			f(&mut recording)?
+		};
+		bindless.flush();
+		bindless.push_execution(resource.into_execution());
+		Ok(r)
+	}
+}
+
+pub struct MockRecordingContext<'a> {
+	pub(super) bindless: Bindless<Mock>,
+	pub(super) resource_context: &'a MockRecordingResourceContext,
+}
+
+impl<'a> MockRecordingContext<'a> {
+	pub fn new(resource_context: &'a MockRecordingResourceContext) -> Self {
+		Self {
+			bindless: resource_context.frame.bindless.clone(),
+			resource_context,
+		}
+	}
+
+	/// Copies `src` into `dst` on the host, as commands are executed immediately.
+	///
+	/// # Safety
+	/// Must have shared access to `src` and exclusive access to `dst`, and they must not be the same buffer.
+	pub unsafe fn mock_copy_buffer(
+		&mut self,
+		src: &BufferSlot<Mock>,
+		dst: &BufferSlot<Mock>,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			if src.size > dst.size {
+				return Err(MockRecordingError::BufferCopyOutOfBounds {
+					src: src.debug_name.clone(),
+					src_size: src.size,
+					dst: dst.debug_name.clone(),
+					dst_size: dst.size,
+				});
+			}
+			Self::mock_copy_memory(src, 0, dst, 0, src.size);
+			self.resource_context.push_command(MockCommand::CopyBufferToBuffer {
+				src: src.handle,
+				dst: dst.handle,
+				size: src.size,
+			});
+			Ok(())
+		}
+	}
+
+	/// Copies `size` bytes at `src_offset` of `src` to `dst_offset` of `dst` on the host, the range must be in bounds.
+	///
+	/// # Safety
+	/// Must have shared access to `src` and exclusive access to `dst`, and they must not be the same buffer.
+	pub unsafe fn mock_copy_buffer_region(
+		&mut self,
+		src: &BufferSlot<Mock>,
+		src_offset: u64,
+		dst: &BufferSlot<Mock>,
+		dst_offset: u64,
+		size: u64,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			Self::mock_copy_memory(src, src_offset, dst, dst_offset, size);
+			self.resource_context.push_command(MockCommand::CopyBufferRegion {
+				src: src.handle,
+				src_offset,
+				dst: dst.handle,
+				dst_offset,
+				size,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn mock_copy_memory(
+		src: &BufferSlot<Mock>,
+		src_offset: u64,
+		dst: &BufferSlot<Mock>,
+		dst_offset: u64,
+		size: u64,
+	) {
+		unsafe {
+			// sub-allocations share the memory of their pool's backing buffer
+			let size = size as usize;
+			let src_offset = (src.offset + src_offset) as usize;
+			let dst_offset = (dst.offset + dst_offset) as usize;
+			if src.handle == dst.handle {
+				dst.backing()
+					.memory_mut()
+					.copy_within(src_offset..src_offset + size, dst_offset);
+			} else {
+				dst.backing().memory_mut()[dst_offset..dst_offset + size]
+					.copy_from_slice(&src.backing().memory()[src_offset..src_offset + size]);
+			}
+		}
+	}
+}
+
+unsafe impl<'a> TransientAccess<'a> for MockRecordingContext<'a> {}
+
+unsafe impl<'a> HasResourceContext<'a, Mock> for MockRecordingContext<'a> {
+	#[inline]
+	fn bindless(&self) -> &Bindless<Mock> {
+		&self.bindless
+	}
+
+	#[inline]
+	fn resource_context(&self) -> &'a <Mock as BindlessPipelinePlatform>::RecordingResourceContext {
+		self.resource_context
+	}
+}
+
+unsafe impl<'a> RecordingContext<'a, Mock> for MockRecordingContext<'a> {
+	unsafe fn copy_buffer_to_buffer<
+		T: BufferStruct,
+		SA: BufferAccessType + TransferReadable,
+		DA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: impl MutOrSharedBuffer<Mock, T, SA>,
+		dst: &MutBufferAccess<Mock, T, DA>,
+	) -> Result<(), MockRecordingError> {
+		unsafe { self.mock_copy_buffer(src.inner_slot(), dst.inner_slot()) }
+	}
+
+	unsafe fn copy_buffer_to_buffer_slice<
+		T: BufferStruct,
+		SA: BufferAccessType + TransferReadable,
+		DA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: impl MutOrSharedBuffer<Mock, [T], SA>,
+		dst: &MutBufferAccess<Mock, [T], DA>,
+	) -> Result<(), MockRecordingError> {
+		unsafe { self.mock_copy_buffer(src.inner_slot(), dst.inner_slot()) }
+	}
+
+	unsafe fn copy_buffer_to_buffer_region<
+		T: BufferStruct,
+		SA: BufferAccessType + TransferReadable,
+		DA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: impl MutOrSharedBuffer<Mock, [T], SA>,
+		dst: &MutBufferAccess<Mock, [T], DA>,
+		region: &BufferCopyRegion,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			let stride = size_of::<T::Transfer>() as u64;
+			self.mock_copy_buffer_region(
+				src.inner_slot(),
+				region.src_offset as u64 * stride,
+				dst.inner_slot(),
+				region.dst_offset as u64 * stride,
+				region.len as u64 * stride,
+			)
+		}
+	}
+
+	unsafe fn copy_buffer_to_image<
+		BT: BufferContent + ?Sized,
+		BA: BufferAccessType + TransferReadable,
+		IT: ImageType,
+		IA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src_buffer: &MutBufferAccess<Mock, BT, BA>,
+		dst_image: &MutImageAccess<Mock, IT, IA>,
+		region: &BufferImageCopyRegion,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::CopyBufferToImage {
+				src: src_buffer.inner_slot().handle,
+				dst: dst_image.inner_slot().handle,
+				region: *region,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn copy_image_to_buffer<
+		IT: ImageType,
+		IA: ImageAccessType + TransferReadable,
+		BT: BufferContent + ?Sized,
+		BA: BufferAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src_image: &MutImageAccess<Mock, IT, IA>,
+		dst_buffer: &MutBufferAccess<Mock, BT, BA>,
+		region: &BufferImageCopyRegion,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::CopyImageToBuffer {
+				src: src_image.inner_slot().handle,
+				dst: dst_buffer.inner_slot().handle,
+				region: *region,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn copy_image_to_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: &MutImageAccess<Mock, ST, SA>,
+		dst: &MutImageAccess<Mock, DT, DA>,
+		region: &ImageCopyRegion,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::CopyImageToImage {
+				src: src.inner_slot().handle,
+				dst: dst.inner_slot().handle,
+				region: *region,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn blit_image<
+		ST: ImageType,
+		SA: ImageAccessType + TransferReadable,
+		DT: ImageType,
+		DA: ImageAccessType + TransferWriteable,
+	>(
+		&mut self,
+		src: &MutImageAccess<Mock, ST, SA>,
+		dst: &MutImageAccess<Mock, DT, DA>,
+		region: &ImageCopyRegion,
+		filter: Filter,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::BlitImage {
+				src: src.inner_slot().handle,
+				dst: dst.inner_slot().handle,
+				region: *region,
+				filter,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn generate_mips<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<Mock, T, A>,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			let image = image.inner_slot();
+			self.resource_context.push_command(MockCommand::GenerateMips {
+				image: image.handle,
+				mip_levels: image.mip_levels,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn fill_buffer<T: BufferContent + ?Sized, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<Mock, T, A>,
+		data: u32,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			let slot = buffer.inner_slot();
+			let offset = slot.offset as usize;
+			for chunk in slot.backing().memory_mut()[offset..offset + slot.size as usize].chunks_exact_mut(4) {
+				chunk.copy_from_slice(&data.to_ne_bytes());
+			}
+			self.resource_context.push_command(MockCommand::FillBuffer {
+				buffer: slot.handle,
+				data,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn update_buffer<T: BufferStruct, A: BufferAccessType + TransferWriteable>(
+		&mut self,
+		buffer: &MutBufferAccess<Mock, [T], A>,
+		offset: u64,
+		data: &[u8],
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			let slot = buffer.inner_slot();
+			let start = (slot.offset + offset) as usize;
+			slot.backing().memory_mut()[start..start + data.len()].copy_from_slice(data);
+			self.resource_context.push_command(MockCommand::UpdateBuffer {
+				buffer: slot.handle,
+				offset,
+				size: data.len() as u64,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn clear_color_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<Mock, T, A>,
+		value: ClearValue,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::ClearColorImage {
+				image: image.inner_slot().handle,
+				value,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn clear_depth_stencil_image<T: ImageType, A: ImageAccessType + TransferWriteable>(
+		&mut self,
+		image: &MutImageAccess<Mock, T, A>,
+		depth: f32,
+		stencil: u32,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::ClearDepthStencilImage {
+				image: image.inner_slot().handle,
+				depth,
+				stencil,
+			});
+			Ok(())
+		}
+	}
+
+	unsafe fn dispatch<T: BufferStruct>(
+		&mut self,
+		pipeline: &BindlessComputePipeline<Mock, T>,
+		group_counts: [u32; 3],
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		self.resource_context.push_command(MockCommand::Dispatch {
+			pipeline: pipeline.inner().handle,
+			group_counts,
+		});
+		Ok(())
+	}
+
+	unsafe fn dispatch_indirect<T: BufferStruct, A: BufferAccessType + IndirectCommandReadable>(
+		&mut self,
+		pipeline: &BindlessComputePipeline<Mock, T>,
+		indirect: impl MutOrSharedBuffer<Mock, [u32; 3], A>,
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.resource_context.push_command(MockCommand::DispatchIndirect {
+				pipeline: pipeline.inner().handle,
+				indirect: indirect.inner_slot().handle,
+			});
+			Ok(())
+		}
+	}
+}
+
+#[derive(Error)]
+pub enum MockRecordingError {
+	#[error("Buffer copy out of bounds: copying {src_size} bytes from \"{src}\" into {dst_size} bytes of \"{dst}\"")]
+	BufferCopyOutOfBounds {
+		src: String,
+		src_size: u64,
+		dst: String,
+		dst_size: u64,
+	},
+	#[error("No barriers must be inserted while rendering: {command:?}")]
+	BarrierWhileRendering { command: MockCommand },
+}
+
+impl Debug for MockRecordingError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		core::fmt::Display::fmt(self, f)
+	}
+}
+
+impl From<MockRecordingError> for RecordingError<Mock> {
+	fn from(value: MockRecordingError) -> Self {
+		RecordingError::Platform(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::descriptor::{
+		BindlessImageCreateInfo, BindlessImageUsage, Format, FormatAspects, Image2d, Image2dArray, MutDescBufferExt,
+		MutDescExt,
+	};
+	use crate::pipeline::{
+		BufferCopyRegion, BufferImageCopyRegion, ClearValue, HostAccess, ImageRegion, MutBufferAccessExt,
+		MutImageAccessExt, TransferRead, TransferWrite,
+	};
+	use crate::platform::mock::test_utils::{buffer_ci, mock_bindless};
+	use glam::UVec3;
+
+	#[test]
+	fn test_mock_buffer_roundtrip() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let data = (0..64).collect::<Vec<u32>>();
+		let buffer = bindless
+			.buffer()
+			.alloc_from_iter(&buffer_ci("buffer"), data.iter().copied())?;
+		assert_eq!(bindless.allocated_bytes(), 64 * 4);
+		let result = buffer.mapped_immediate()?.read_iter().collect::<Vec<_>>();
+		assert_eq!(result, data);
+		Ok(())
+	}
+
+	#[test]
+	fn test_mock_copy_buffer() -> anyhow::Result<()> {
+		unsafe {
+			let bindless = mock_bindless();
+			let data = (0..64).collect::<Vec<u32>>();
+			let src = bindless
+				.buffer()
+				.alloc_from_iter(&buffer_ci("src"), data.iter().copied())?;
+			let dst = bindless.buffer().alloc_slice::<u32>(&buffer_ci("dst"), data.len())?;
+			let src_handle = src.inner_slot().handle;
+			let dst_handle = dst.inner_slot().handle;
+
+			let dst = bindless.execute(|cmd| {
+				let src = src.access::<TransferRead>(cmd)?;
+				let dst = dst.access_as_undefined::<TransferWrite>(cmd)?;
+				cmd.copy_buffer_to_buffer_slice(&src, &dst)?;
+				Ok(dst.transition::<HostAccess>()?.into_desc())
+			})?;
+
+			let result = dst.mapped_immediate()?.read_iter().collect::<Vec<_>>();
+			assert_eq!(result, data);
+
+			let executions = bindless.take_executions();
+			assert_eq!(executions.len(), 1);
+			assert_eq!(
+				executions[0].commands,
+				[
+					MockCommand::TransitionBuffer {
+						buffer: src_handle,
+						src: BufferAccess::General,
+						dst: BufferAccess::TransferRead,
+					},
+					MockCommand::TransitionBuffer {
+						buffer: dst_handle,
+						src: BufferAccess::Undefined,
+						dst: BufferAccess::TransferWrite,
+					},
+					MockCommand::CopyBufferToBuffer {
+						src: src_handle,
+						dst: dst_handle,
+						size: 64 * 4,
+					},
+					MockCommand::TransitionBuffer {
+						buffer: dst_handle,
+						src: BufferAccess::TransferWrite,
+						dst: BufferAccess::HostAccess,
+					},
+				]
+			);
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_mock_copy_regions() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let src = bindless.buffer().alloc_from_iter(&buffer_ci("src"), 0..16u32)?;
+		let dst = bindless.buffer().alloc_from_iter(&buffer_ci("dst"), [0u32; 8])?;
+		let image = bindless.image().alloc::<Image2dArray>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [8, 8].into(),
+			mip_levels: 2,
+			array_layers: 2,
+			usage: BindlessImageUsage::TRANSFER_SRC | BindlessImageUsage::TRANSFER_DST,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let src_handle = src.inner_slot().handle;
+		let image_handle = image.inner_slot().handle;
+
+		// a 2x2 region of mip 1 of layer 1, with rows 4 texels apart in the buffer
+		let region = BufferImageCopyRegion {
+			buffer_offset: 4,
+			buffer_row_length: 4,
+			buffer_image_height: 0,
+			image: ImageRegion {
+				mip_level: 1,
+				base_layer: 1,
+				layer_count: 1,
+				offset: UVec3::new(2, 2, 0),
+				extent: [2, 2].into(),
+			},
+			aspect: FormatAspects::empty(),
+		};
+		let dst = bindless.execute(|cmd| {
+			let src = src.access::<TransferRead>(cmd)?;
+			let dst = dst.access::<TransferWrite>(cmd)?;
+			let image = image.access::<TransferWrite>(cmd)?;
+			cmd.copy_buffer_to_buffer_region(
+				&src,
+				&dst,
+				&BufferCopyRegion {
+					src_offset: 10,
+					dst_offset: 2,
+					len: 4,
+				},
+			)?;
+			cmd.copy_buffer_to_image_region(&src, &image, &region)?;
+			Ok(dst.transition::<HostAccess>()?.into_desc())
+		})?;
+		let result = dst.mapped_immediate()?.read_iter().collect::<Vec<_>>();
+		assert_eq!(result, [0, 0, 10, 11, 12, 13, 0, 0]);
+		let executions = bindless.take_executions();
+		assert!(executions[0].commands.contains(&MockCommand::CopyBufferToImage {
+			src: src_handle,
+			dst: image_handle,
+			region,
+		}));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_mock_fill_update_clear() -> anyhow::Result<()> {
+		let bindless = mock_bindless();
+		let buffer = bindless.buffer().alloc_slice::<u32>(&buffer_ci("buffer"), 8)?;
+		let image = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+			format: Format::R8G8B8A8_UNORM,
+			extent: [8, 8].into(),
+			usage: BindlessImageUsage::TRANSFER_DST,
+			name: "image",
+			..BindlessImageCreateInfo::default()
+		})?;
+		let image_handle = image.inner_slot().handle;
+
+		let buffer = bindless.execute(|cmd| {
+			let buffer = unsafe { buffer.access_as_undefined::<TransferWrite>(cmd)? };
+			let image = image.access::<TransferWrite>(cmd)?;
+			unsafe { cmd.fill_buffer(&buffer, 7)? };
+			cmd.update_buffer(&buffer, 2, &[1, 2, 3])?;
+			cmd.clear_color_image(&image, ClearValue::ColorF([1.; 4]))?;
+			Ok(buffer.transition::<HostAccess>()?.into_desc())
+		})?;
+		let result = buffer.mapped_immediate()?.read_iter().collect::<Vec<_>>();
+		assert_eq!(result, [7, 7, 1, 2, 3, 7, 7, 7]);
+		let executions = bindless.take_executions();
+		assert!(executions[0].commands.contains(&MockCommand::UpdateBuffer {
+			buffer: buffer.inner_slot().handle,
+			offset: 8,
+			size: 12,
+		}));
+		assert!(executions[0].commands.contains(&MockCommand::ClearColorImage {
+			image: image_handle,
+			value: ClearValue::ColorF([1.; 4]),
+		}));
+		Ok(())
+	}
+}
@@ -0,0 +1,218 @@
+use crate::descriptor::Bindless;
+use crate::pipeline::{
+	BindlessGraphicsPipeline, BindlessMeshGraphicsPipeline, ColorAttachment, DepthStencilAttachment,
+	DrawIndexedIndirectCommand, DrawIndirectCommand, HasResourceContext, IndexReadable, IndexTypeTrait,
+	IndirectCommandReadable, MutOrSharedBuffer, RecordingError, RenderPassFormat, RenderingAttachment,
+	RenderingImageType,
+};
+use crate::platform::RenderingContext;
+use crate::platform::mock::{
+	Mock, MockCommand, MockRecordingContext, MockRecordingError, MockRecordingResourceContext,
+};
+use glam::UVec2;
+use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
+use rust_gpu_bindless_shaders::descriptor::TransientAccess;
+use rust_gpu_bindless_shaders::utils::rect::IRect2;
+use rust_gpu_bindless_shaders::utils::viewport::Viewport;
+use std::ops::{Deref, DerefMut};
+
+pub struct MockRenderingContext<'a, 'b> {
+	recording: &'b mut MockRecordingContext<'a>,
+	/// index of the first command recorded within this render pass
+	first_command: usize,
+}
+
+impl<'a> Deref for MockRenderingContext<'a, '_> {
+	type Target = MockRecordingContext<'a>;
+
+	fn deref(&self) -> &Self::Target {
+		self.recording
+	}
+}
+
+impl DerefMut for MockRenderingContext<'_, '_> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.recording
+	}
+}
+
+impl MockRenderingContext<'_, '_> {
+	/// Return an Error if any transitions were recorded since rendering began, as barriers are illegal inside render
+	/// passes.
+	pub fn mock_must_not_transition(&self) -> Result<(), MockRecordingError> {
+		let commands = self.resource_context.commands.borrow();
+		match commands[self.first_command..].iter().find(|c| c.is_transition()) {
+			None => Ok(()),
+			Some(command) => Err(MockRecordingError::BarrierWhileRendering {
+				command: command.clone(),
+			}),
+		}
+	}
+
+	fn mock_push_draw(&self, command: MockCommand) -> Result<(), MockRecordingError> {
+		self.mock_must_not_transition()?;
+		self.resource_context.push_command(command);
+		Ok(())
+	}
+}
+
+unsafe impl<'a> TransientAccess<'a> for MockRenderingContext<'a, '_> {}
+
+unsafe impl<'a> HasResourceContext<'a, Mock> for MockRenderingContext<'a, '_> {
+	#[inline]
+	fn bindless(&self) -> &Bindless<Mock> {
+		self.recording.bindless()
+	}
+
+	#[inline]
+	fn resource_context(&self) -> &'a MockRecordingResourceContext {
+		self.recording.resource_context()
+	}
+}
+
+unsafe impl<'a, 'b> RenderingContext<'a, 'b, Mock> for MockRenderingContext<'a, 'b> {
+	unsafe fn begin_rendering<T: RenderingImageType>(
+		recording: &'b mut MockRecordingContext<'a>,
+		_format: RenderPassFormat,
+		render_area: UVec2,
+		color_attachments: &[RenderingAttachment<Mock, ColorAttachment, T>],
+		depth_attachment: Option<RenderingAttachment<Mock, DepthStencilAttachment, T>>,
+	) -> Result<Self, MockRecordingError> {
+		unsafe {
+			recording.resource_context.push_command(MockCommand::BeginRendering {
+				render_area,
+				color_attachments: color_attachments.iter().map(|c| c.image.inner_slot().handle).collect(),
+				depth_attachment: depth_attachment.as_ref().map(|d| d.image.inner_slot().handle),
+				resolve_attachments: color_attachments
+					.iter()
+					.filter_map(|c| c.resolve.as_ref().map(|r| r.image.inner_slot().handle))
+					.chain(
+						depth_attachment
+							.as_ref()
+							.and_then(|d| d.resolve.as_ref().map(|r| r.image.inner_slot().handle)),
+					)
+					.collect(),
+			});
+			let first_command = recording.resource_context.command_count();
+			Ok(Self {
+				recording,
+				first_command,
+			})
+		}
+	}
+
+	unsafe fn end_rendering(&mut self) -> Result<(), MockRecordingError> {
+		self.mock_push_draw(MockCommand::EndRendering)
+	}
+
+	unsafe fn set_viewport(&mut self, viewport: Viewport) {
+		self.resource_context.push_command(MockCommand::SetViewport {
+			x: viewport.x,
+			y: viewport.y,
+			width: viewport.width,
+			height: viewport.height,
+			min_depth: viewport.min_depth,
+			max_depth: viewport.max_depth,
+		});
+	}
+
+	unsafe fn set_scissor(&mut self, scissor: IRect2) {
+		self.resource_context.push_command(MockCommand::SetScissor(scissor));
+	}
+
+	unsafe fn draw<T: BufferStruct>(
+		&mut self,
+		pipeline: &BindlessGraphicsPipeline<Mock, T>,
+		count: DrawIndirectCommand,
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		self.mock_push_draw(MockCommand::Draw {
+			pipeline: pipeline.inner().handle,
+			vertex_count: count.vertex_count,
+			instance_count: count.instance_count,
+			first_vertex: count.first_vertex,
+			first_instance: count.first_instance,
+		})
+	}
+
+	unsafe fn draw_indexed<T: BufferStruct, IT: IndexTypeTrait, AIR: IndexReadable>(
+		&mut self,
+		pipeline: &BindlessGraphicsPipeline<Mock, T>,
+		index_buffer: impl MutOrSharedBuffer<Mock, [IT], AIR>,
+		count: DrawIndexedIndirectCommand,
+		_param: T,
+	) -> Result<(), RecordingError<Mock>> {
+		unsafe {
+			Ok(self.mock_push_draw(MockCommand::DrawIndexed {
+				pipeline: pipeline.inner().handle,
+				index_buffer: index_buffer.inner_slot().handle,
+				index_count: count.index_count,
+				instance_count: count.instance_count,
+				first_index: count.first_index,
+				vertex_offset: count.vertex_offset,
+				first_instance: count.first_instance,
+			})?)
+		}
+	}
+
+	unsafe fn draw_indirect<T: BufferStruct, AIC: IndirectCommandReadable>(
+		&mut self,
+		pipeline: &BindlessGraphicsPipeline<Mock, T>,
+		indirect: impl MutOrSharedBuffer<Mock, DrawIndirectCommand, AIC>,
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.mock_push_draw(MockCommand::DrawIndirect {
+				pipeline: pipeline.inner().handle,
+				indirect: indirect.inner_slot().handle,
+			})
+		}
+	}
+
+	unsafe fn draw_indexed_indirect<
+		T: BufferStruct,
+		IT: IndexTypeTrait,
+		AIR: IndexReadable,
+		AIC: IndirectCommandReadable,
+	>(
+		&mut self,
+		pipeline: &BindlessGraphicsPipeline<Mock, T>,
+		index_buffer: impl MutOrSharedBuffer<Mock, [IT], AIR>,
+		indirect: impl MutOrSharedBuffer<Mock, DrawIndexedIndirectCommand, AIC>,
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.mock_push_draw(MockCommand::DrawIndexedIndirect {
+				pipeline: pipeline.inner().handle,
+				index_buffer: index_buffer.inner_slot().handle,
+				indirect: indirect.inner_slot().handle,
+			})
+		}
+	}
+
+	unsafe fn draw_mesh_tasks<T: BufferStruct>(
+		&mut self,
+		pipeline: &BindlessMeshGraphicsPipeline<Mock, T>,
+		group_counts: [u32; 3],
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		self.mock_push_draw(MockCommand::DrawMeshTasks {
+			pipeline: pipeline.inner().handle,
+			group_counts,
+		})
+	}
+
+	unsafe fn draw_mesh_tasks_indirect<T: BufferStruct, AIC: IndirectCommandReadable>(
+		&mut self,
+		pipeline: &BindlessMeshGraphicsPipeline<Mock, T>,
+		indirect: impl MutOrSharedBuffer<Mock, [u32; 3], AIC>,
+		_param: T,
+	) -> Result<(), MockRecordingError> {
+		unsafe {
+			self.mock_push_draw(MockCommand::DrawMeshTasksIndirect {
+				pipeline: pipeline.inner().handle,
+				indirect: indirect.inner_slot().handle,
+			})
+		}
+	}
+}
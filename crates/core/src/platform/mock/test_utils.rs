@@ -0,0 +1,21 @@
+use crate::descriptor::{
+	BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessInstance, DescriptorCounts,
+};
+use crate::platform::mock::{Mock, MockCreateInfo};
+
+/// Creates a new mock [`BindlessInstance`] with [`DescriptorCounts::REASONABLE_DEFAULTS`]
+pub fn mock_bindless() -> BindlessInstance<Mock> {
+	unsafe { BindlessInstance::new(MockCreateInfo::default(), DescriptorCounts::REASONABLE_DEFAULTS) }
+}
+
+/// A host accessible buffer usable as source and destination of transfers
+pub fn buffer_ci(name: &'static str) -> BindlessBufferCreateInfo<'static> {
+	BindlessBufferCreateInfo {
+		name,
+		usage: BindlessBufferUsage::MAP_WRITE
+			| BindlessBufferUsage::MAP_READ
+			| BindlessBufferUsage::TRANSFER_SRC
+			| BindlessBufferUsage::TRANSFER_DST,
+		allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+	}
+}
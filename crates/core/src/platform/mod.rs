@@ -1,6 +1,8 @@
+#[cfg(feature = "ash")]
 pub mod ash;
 mod bindless;
 mod bindless_pipeline;
+pub mod mock;
 
 pub use bindless::*;
 pub use bindless_pipeline::*;
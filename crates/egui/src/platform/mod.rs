@@ -1,5 +1,6 @@
 use rust_gpu_bindless_core::platform::BindlessPipelinePlatform;
 
+#[cfg(feature = "ash")]
 pub mod ash;
 
 pub unsafe trait EguiBindlessPlatform: BindlessPipelinePlatform {
@@ -1,14 +1,7 @@
 use crate::convert::Egui2Bindless;
 use crate::platform::EguiBindlessPlatform;
-use ash::vk::{
-	BlendFactor, BlendOp, ColorComponentFlags, PipelineColorBlendAttachmentState, PipelineColorBlendStateCreateInfo,
-	PipelineDepthStencilStateCreateInfo, PipelineInputAssemblyStateCreateInfo, PipelineRasterizationStateCreateInfo,
-	PrimitiveTopology,
-};
 use egui::epaint::Primitive;
-use egui::{
-	Context, FullOutput, ImageData, PlatformOutput, RawInput, Rect, TextureId, TextureOptions, TexturesDelta, epaint,
-};
+use egui::{Context, FullOutput, ImageData, PlatformOutput, RawInput, Rect, TextureId, TexturesDelta, epaint};
 use glam::{IVec2, UVec2};
 use parking_lot::Mutex;
 use rust_gpu_bindless_core::descriptor::{
@@ -18,9 +11,11 @@ use rust_gpu_bindless_core::descriptor::{
 	SamplerAllocationError,
 };
 use rust_gpu_bindless_core::pipeline::{
-	BindlessGraphicsPipeline, ColorAttachment, DepthStencilAttachment, GraphicsPipelineCreateInfo, HasResourceContext,
-	ImageAccessType, LoadOp, MutBufferAccessExt, MutImageAccess, MutImageAccessExt, Recording, RecordingError,
-	RenderPassFormat, RenderingAttachment, StoreOp, TransferRead, TransferWrite,
+	BindlessGraphicsPipeline, BlendState, ColorAttachment, ColorBlendAttachment, ColorBlendState, ColorComponents,
+	DepthStencilAttachment, DepthStencilState, GraphicsPipelineCreateInfo, HasResourceContext, ImageAccessType,
+	InputAssemblyState, LoadOp, MutBufferAccessExt, MutImageAccess, MutImageAccessExt, PrimitiveTopology,
+	RasterizationState, Recording, RecordingError, RenderPassFormat, RenderingAttachment, StoreOp, TransferRead,
+	TransferWrite,
 };
 use rust_gpu_bindless_core::platform::RecordingResourceContext;
 use rust_gpu_bindless_egui_shaders::{Param, ParamFlags, Vertex};
@@ -52,16 +47,11 @@ impl<P: EguiBindlessPlatform> Deref for EguiRenderer<P> {
 
 pub struct EguiRendererInner<P: EguiBindlessPlatform> {
 	bindless: Bindless<P>,
-	/// deduplicate samplers, they will never be freed if unused
-	samplers: Mutex<FxHashMap<TextureOptions, RCDesc<P, Sampler>>>,
 }
 
 impl<P: EguiBindlessPlatform> EguiRenderer<P> {
 	pub fn new(bindless: Bindless<P>) -> Self {
-		EguiRenderer(Arc::new(EguiRendererInner {
-			bindless,
-			samplers: Mutex::new(FxHashMap::default()),
-		}))
+		EguiRenderer(Arc::new(EguiRendererInner { bindless }))
 	}
 
 	pub fn bindless(&self) -> &Bindless<P> {
@@ -104,23 +94,23 @@ impl<P: EguiBindlessPlatform> EguiRenderPipeline<P> {
 			.create_graphics_pipeline(
 				&format,
 				&GraphicsPipelineCreateInfo {
-					input_assembly_state: PipelineInputAssemblyStateCreateInfo::default()
-						.topology(PrimitiveTopology::TRIANGLE_LIST),
-					rasterization_state: PipelineRasterizationStateCreateInfo::default().line_width(1.0),
-					depth_stencil_state: PipelineDepthStencilStateCreateInfo::default()
-						.depth_test_enable(false)
-						.depth_write_enable(true),
-					color_blend_state: PipelineColorBlendStateCreateInfo::default().attachments(&[
-						PipelineColorBlendAttachmentState::default()
-							.blend_enable(true)
-							.src_color_blend_factor(BlendFactor::ONE)
-							.color_blend_op(BlendOp::ADD)
-							.dst_color_blend_factor(BlendFactor::ONE_MINUS_SRC_ALPHA)
-							.src_alpha_blend_factor(BlendFactor::ONE)
-							.alpha_blend_op(BlendOp::ADD)
-							.dst_alpha_blend_factor(BlendFactor::ONE_MINUS_SRC_ALPHA)
-							.color_write_mask(ColorComponentFlags::RGBA),
-					]),
+					input_assembly_state: InputAssemblyState {
+						topology: PrimitiveTopology::TriangleList,
+						..InputAssemblyState::default()
+					},
+					rasterization_state: RasterizationState::default(),
+					depth_stencil_state: DepthStencilState {
+						depth_test_enable: false,
+						depth_write_enable: true,
+						..DepthStencilState::default()
+					},
+					color_blend_state: ColorBlendState {
+						attachments: &[ColorBlendAttachment {
+							blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+							color_write_mask: ColorComponents::RGBA,
+						}],
+						..ColorBlendState::default()
+					},
 				},
 				crate::shaders::egui_vertex::new(),
 				crate::shaders::egui_fragment::new(),
@@ -168,7 +158,7 @@ pub enum EguiTextureType {
 }
 
 impl EguiTextureType {
-	pub fn to_ash_format(&self) -> Format {
+	pub fn to_format(&self) -> Format {
 		match self {
 			EguiTextureType::Color => Format::R8G8B8A8_UNORM,
 			EguiTextureType::Font => Format::R32_SFLOAT,
@@ -284,7 +274,7 @@ impl<P: EguiBindlessPlatform> EguiRenderContext<P> {
 						let image = bindless
 							.image()
 							.alloc(&BindlessImageCreateInfo {
-								format: texture_type.to_ash_format(),
+								format: texture_type.to_format(),
 								extent,
 								mip_levels: 1,
 								array_layers: 1,
@@ -301,28 +291,18 @@ impl<P: EguiBindlessPlatform> EguiRenderContext<P> {
 						cmd.copy_buffer_to_image(&staging, &image)?;
 						let image = image.into_desc();
 
-						let sampler = {
-							// I would not hold a lock across the loop, we will rarely allocate a new texture
-							let mut samplers = self.renderer.samplers.lock();
-							let sampler = samplers.entry(delta.options).or_insert_with(|| {
-								bindless
-									.sampler()
-									.alloc(&BindlessSamplerCreateInfo {
-										min_filter: delta.options.minification.to_bindless(),
-										mag_filter: delta.options.magnification.to_bindless(),
-										mipmap_mode: delta
-											.options
-											.mipmap_mode
-											.map_or(Filter::Nearest, |f| f.to_bindless()),
-										address_mode_v: delta.options.wrap_mode.to_bindless(),
-										address_mode_u: delta.options.wrap_mode.to_bindless(),
-										address_mode_w: delta.options.wrap_mode.to_bindless(),
-										..BindlessSamplerCreateInfo::default()
-									})
-									.unwrap()
-							});
-							sampler.clone()
-						};
+						let sampler = bindless
+							.sampler()
+							.get_or_alloc(&BindlessSamplerCreateInfo {
+								min_filter: delta.options.minification.to_bindless(),
+								mag_filter: delta.options.magnification.to_bindless(),
+								mipmap_mode: delta.options.mipmap_mode.map_or(Filter::Nearest, |f| f.to_bindless()),
+								address_mode_v: delta.options.wrap_mode.to_bindless(),
+								address_mode_u: delta.options.wrap_mode.to_bindless(),
+								address_mode_w: delta.options.wrap_mode.to_bindless(),
+								..BindlessSamplerCreateInfo::default()
+							})
+							.unwrap();
 
 						self.textures.insert(
 							id,
@@ -549,12 +529,14 @@ impl<P: EguiBindlessPlatform> EguiRenderOutput<'_, P> {
 					image: color,
 					load_op: options.image_rt_load_op,
 					store_op: StoreOp::Store,
+					resolve: None,
 				})
 				.as_slice(),
 			depth.map(|(_, depth)| RenderingAttachment {
 				image: depth,
 				load_op: options.depth_rt_load_op,
 				store_op: StoreOp::Store,
+				resolve: None,
 			}),
 			|rp| {
 				for draw in &self.draw_cmds {
@@ -4,7 +4,10 @@ use crate::symbols::Symbols;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, format_ident, quote};
 use syn::spanned::Spanned;
-use syn::{Error, FnArg, ItemFn, MetaList, PatType, Result, ReturnType, Type, TypeReference};
+use syn::{
+	Error, FnArg, GenericArgument, ItemFn, LitInt, MetaList, PatType, PathArguments, Result, ReturnType, Type,
+	TypePath, TypeReference,
+};
 
 pub struct BindlessContext<'a> {
 	symbols: &'a Symbols,
@@ -25,6 +28,7 @@ pub fn bindless(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) ->
 
 	let mut arg_param = None;
 	let mut arg_descriptors = None;
+	let mut arg_custom = Vec::new();
 	let mut forward = Vec::new();
 	for arg in item.sig.inputs.iter() {
 		let arg = match arg {
@@ -45,6 +49,24 @@ pub fn bindless(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) ->
 				));
 			}
 			let bindless_list = bindless.meta.require_list()?;
+			if let Some(custom) = parse_custom_descriptors(bindless_list, arg)? {
+				if let Some(old) = arg_custom
+					.iter()
+					.find(|old: &&SymCustomDescriptors| old.binding == custom.binding)
+				{
+					let mut error = Error::new(
+						old.arg.span(),
+						format!(
+							"Function must only have one argument with #[bindless(custom({}))] attribute...",
+							custom.binding
+						),
+					);
+					error.combine(Error::new(arg.span(), "... but two were declared!"));
+					return Err(error);
+				}
+				arg_custom.push(custom);
+				continue;
+			}
 			let bindless_list_str = bindless_list.tokens.to_string();
 			let slot = match &*bindless_list_str {
 				"param" => &mut arg_param,
@@ -80,6 +102,7 @@ pub fn bindless(attr: proc_macro::TokenStream, item: proc_macro::TokenStream) ->
 		&descriptors,
 		arg_param,
 		arg_descriptors,
+		&arg_custom,
 		&forward,
 	)?;
 	let entry_shader_type = get_entry_shader_type(&mut context)?;
@@ -169,16 +192,16 @@ fn gen_bindless_descriptors(context: &mut BindlessContext) -> Result<SymDescript
 	let image_args;
 	let image_values;
 	macro_rules! make_image_args {
-		($($image:ident: $sampled:ident $storage:ident,)*) => {
-			$(let $storage = format_ident!("__bindless_{}", stringify!($storage));)*
+		($($image:ident: $sampled:ident $($storage:ident)?,)*) => {
+			$($(let $storage = format_ident!("__bindless_{}", stringify!($storage));)?)*
 			$(let $sampled = format_ident!("__bindless_{}", stringify!($sampled));)*
 
 			image_args = quote! {
-				$(#[spirv(descriptor_set = 0, binding = 1)] #$storage: &#crate_shaders::spirv_std::RuntimeArray<<#crate_shaders::descriptor::$image as #crate_shaders::descriptor::ImageType>::StorageSpvImage>,)*
+				$($(#[spirv(descriptor_set = 0, binding = 1)] #$storage: &#crate_shaders::spirv_std::RuntimeArray<<#crate_shaders::descriptor::$image as #crate_shaders::descriptor::ImageType>::StorageSpvImage>,)?)*
 				$(#[spirv(descriptor_set = 0, binding = 2)] #$sampled: &#crate_shaders::spirv_std::RuntimeArray<<#crate_shaders::descriptor::$image as #crate_shaders::descriptor::ImageType>::SampledSpvImage>,)*
 			};
 			image_values = quote! {
-				$($storage: #$storage,)*
+				$($($storage: #$storage,)?)*
 				$($sampled: #$sampled,)*
 			};
 		};
@@ -206,6 +229,51 @@ fn gen_bindless_descriptors(context: &mut BindlessContext) -> Result<SymDescript
 	})
 }
 
+struct SymCustomDescriptors<'a> {
+	arg: &'a PatType,
+	binding: u32,
+	binding_lit: LitInt,
+	content: Type,
+}
+
+/// Parses `#[bindless(custom(BINDING))] arg: CustomDescriptors<C>`, returns `None` for any other bindless attribute
+fn parse_custom_descriptors<'a>(
+	bindless_list: &MetaList,
+	arg: &'a PatType,
+) -> Result<Option<SymCustomDescriptors<'a>>> {
+	let custom = match syn::parse2::<MetaList>(bindless_list.tokens.clone()) {
+		Ok(custom) if custom.path.is_ident("custom") => custom,
+		_ => return Ok(None),
+	};
+	let binding_lit = custom.parse_args::<LitInt>()?;
+	let binding = binding_lit.base10_parse::<u32>()?;
+
+	let content = match &*arg.ty {
+		Type::Path(TypePath { qself: None, path }) => {
+			path.segments.last().and_then(|segment| match &segment.arguments {
+				PathArguments::AngleBracketed(generics) => generics.args.iter().find_map(|generic| match generic {
+					GenericArgument::Type(ty) => Some(ty.clone()),
+					_ => None,
+				}),
+				_ => None,
+			})
+		}
+		_ => None,
+	}
+	.ok_or_else(|| {
+		Error::new(
+			arg.ty.span(),
+			"#[bindless(custom(BINDING))] must be of type `CustomDescriptors<C>`!",
+		)
+	})?;
+	Ok(Some(SymCustomDescriptors {
+		arg,
+		binding,
+		binding_lit,
+		content,
+	}))
+}
+
 struct SymInnerCall {
 	params: TokenStream,
 	args: TokenStream,
@@ -217,8 +285,10 @@ fn gen_bindless_inner_call(
 	descriptors: &SymDescriptors,
 	arg_param: Option<&PatType>,
 	arg_descriptors: Option<&PatType>,
+	arg_custom: &[SymCustomDescriptors],
 	forward: &[&PatType],
 ) -> Result<SymInnerCall> {
+	let crate_shaders = &context.symbols.crate_shaders()?;
 	let mut params = TokenStream::new();
 	let mut args = TokenStream::new();
 
@@ -238,6 +308,29 @@ fn gen_bindless_inner_call(
 		params.append_tokens(quote!(&#param,));
 		args.append_tokens(strip_attr(arg));
 	}
+	for custom in arg_custom {
+		let SymCustomDescriptors {
+			arg,
+			binding_lit,
+			content,
+			..
+		} = custom;
+		let table = format_ident!("__bindless_custom_{}", custom.binding);
+		// these "plain" spirv here are correct, as they are non-macro attributes to function arguments, not proc macros!
+		context.entry_args.append_tokens(quote! {
+			#[spirv(descriptor_set = 0, binding = #binding_lit)] #table: &#crate_shaders::spirv_std::RuntimeArray<<#content as #crate_shaders::descriptor::CustomTableContent>::Descriptor>,
+		});
+		context.entry_content.append_tokens(quote! {
+			const _: () = assert!(
+				<#content as #crate_shaders::descriptor::CustomTableContent>::BINDING == #binding_lit,
+				concat!("#[bindless(custom(", stringify!(#binding_lit), "))] does not match the `CustomTableContent::BINDING` of `", stringify!(#content), "`")
+			);
+			// Safety: the const assertion above ensures that this is the binding of the custom table
+			let #table = unsafe { #crate_shaders::descriptor::CustomDescriptors::<#content>::new(#table) };
+		});
+		params.append_tokens(quote!(#table,));
+		args.append_tokens(strip_attr(arg));
+	}
 	for arg in forward {
 		let var_name = &arg.pat;
 		quote!(#arg,).to_tokens(&mut context.entry_args);
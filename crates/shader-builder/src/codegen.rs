@@ -1,3 +1,4 @@
+use crate::IdLayout;
 use crate::symbols::find_rust_gpu_bindless;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -11,15 +12,18 @@ use syn::Path;
 
 pub struct CodegenOptions {
 	pub shader_symbols_path: String,
+	/// The [`IdLayout`] the shaders were compiled with
+	pub id_layout: IdLayout,
 }
 
 pub fn codegen_shader_symbols<'a>(
 	shaders: impl Iterator<Item = (&'a str, &'a PathBuf)>,
 	crate_name: &String,
 	out_path: &PathBuf,
-	_options: &CodegenOptions,
+	options: &CodegenOptions,
 ) -> anyhow::Result<()> {
 	let crate_name = format_ident!("{}", crate_name);
+	let id_layout = options.id_layout.bits();
 	let rust_gpu_bindless = find_rust_gpu_bindless()?;
 
 	let mut root = ModNode::root();
@@ -50,6 +54,7 @@ pub fn codegen_shader_symbols<'a>(
 					&#rust_gpu_bindless::__private::shader::SpirvBinary {
 						binary: &[#(#spv_binary),*],
 						entry_point_name: #entry_point_name,
+						id_layout: [#(#id_layout),*],
 					}
 				}
 			}
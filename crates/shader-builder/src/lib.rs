@@ -14,6 +14,43 @@ pub use anyhow;
 use anyhow::Context;
 pub use cargo_gpu_install::spirv_builder;
 
+/// The bit layout of `DescriptorId` as index-type-version bits, must match the `id-layout-*` feature enabled on the CPU
+/// side.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum IdLayout {
+	/// 18 index bits, 2 type bits, 12 version bits
+	#[default]
+	Bits18_2_12,
+	/// 20 index bits, 2 type bits, 10 version bits, feature `id-layout-20-2-10`
+	Bits20_2_10,
+	/// 17 index bits, 3 type bits, 12 version bits, feature `id-layout-17-3-12`
+	Bits17_3_12,
+	/// 16 index bits, 2 type bits, 14 version bits, feature `id-layout-16-2-14`
+	Bits16_2_14,
+}
+
+impl IdLayout {
+	/// The amount of index, type and version bits
+	pub fn bits(&self) -> [u32; 3] {
+		match self {
+			IdLayout::Bits18_2_12 => [18, 2, 12],
+			IdLayout::Bits20_2_10 => [20, 2, 10],
+			IdLayout::Bits17_3_12 => [17, 3, 12],
+			IdLayout::Bits16_2_14 => [16, 2, 14],
+		}
+	}
+
+	/// The feature of `rust-gpu-bindless-shaders` selecting this layout, if it's not the default
+	pub fn feature(&self) -> Option<&'static str> {
+		match self {
+			IdLayout::Bits18_2_12 => None,
+			IdLayout::Bits20_2_10 => Some("id-layout-20-2-10"),
+			IdLayout::Bits17_3_12 => Some("id-layout-17-3-12"),
+			IdLayout::Bits16_2_14 => Some("id-layout-16-2-14"),
+		}
+	}
+}
+
 pub struct ShaderSymbolsBuilder {
 	spirv_builder: SpirvBuilder,
 	pub codegen: Option<CodegenOptions>,
@@ -70,6 +107,10 @@ impl ShaderSymbolsBuilder {
 			Capability::StorageImageExtendedFormats,
 			Capability::StorageImageReadWithoutFormat,
 			Capability::StorageImageWriteWithoutFormat,
+			Capability::Sampled1D,
+			Capability::Image1D,
+			Capability::SampledCubeArray,
+			Capability::ImageCubeArray,
 		]);
 		// maximum debug-ability by default: enable all debug info by default
 		b.spirv_metadata = SpirvMetadata::Full;
@@ -82,6 +123,7 @@ impl ShaderSymbolsBuilder {
 			spirv_builder: b,
 			codegen: Some(CodegenOptions {
 				shader_symbols_path: String::from("shader_symbols.rs"),
+				id_layout: IdLayout::default(),
 			}),
 			crate_name: String::from(crate_ident),
 		})
@@ -138,6 +180,23 @@ impl ShaderSymbolsBuilder {
 		}
 	}
 
+	/// Select the [`IdLayout`] to compile the shaders with, which must match the `id-layout-*` feature enabled on the CPU
+	/// side. The shader crate must depend on `rust-gpu-bindless-shaders` directly, as the layout is selected by enabling
+	/// its feature.
+	pub fn id_layout(self, id_layout: IdLayout) -> Self {
+		let spirv_builder = match id_layout.feature() {
+			Some(feature) => self
+				.spirv_builder
+				.shader_crate_features([format!("rust-gpu-bindless-shaders/{feature}")]),
+			None => self.spirv_builder,
+		};
+		Self {
+			spirv_builder,
+			codegen: self.codegen.map(|codegen| CodegenOptions { id_layout, ..codegen }),
+			..self
+		}
+	}
+
 	pub fn set_codegen_options(self, codegen: Option<CodegenOptions>) -> Self {
 		Self { codegen, ..self }
 	}
@@ -0,0 +1,55 @@
+use crate::descriptor::descriptor_content::DescContent;
+use crate::descriptor::descriptors::DescriptorAccess;
+use crate::descriptor::reference::{AliveDescRef, Desc};
+use core::marker::PhantomData;
+use spirv_std::RuntimeArray;
+
+/// The [`DescContent`] of a custom table, declaring where and how its descriptors are bound on the GPU.
+pub trait CustomTableContent: DescContent {
+	/// The binding of the custom table within the global descriptor set, must be at least
+	/// [`BINDING_CUSTOM_BASE`](crate::descriptor::BINDING_CUSTOM_BASE) and unique across all custom tables.
+	const BINDING: u32;
+	/// The type of a single descriptor on the GPU, eg. an `AccelerationStructure`
+	type Descriptor;
+}
+
+/// The descriptors of a custom table with content `C`.
+///
+/// Declare the table as an additional entry point argument with `#[bindless(custom(BINDING))]`, where `BINDING` must
+/// be the [`CustomTableContent::BINDING`] of `C`, which is verified at compile time:
+/// ```ignore
+/// #[bindless(compute(threads(64)))]
+/// pub fn main(
+///     #[bindless(descriptors)] descriptors: Descriptors,
+///     #[bindless(param)] param: &Param,
+///     #[bindless(custom(4))] accels: CustomDescriptors<MyAccel>,
+/// ) {
+///     let accel = param.accel.access(&accels);
+/// }
+/// ```
+pub struct CustomDescriptors<'a, C: CustomTableContent> {
+	table: &'a RuntimeArray<C::Descriptor>,
+	_phantom: PhantomData<C>,
+}
+
+impl<'a, C: CustomTableContent> CustomDescriptors<'a, C> {
+	/// Used by the `#[bindless(custom(BINDING))]` macro, you should not need to call this yourself.
+	///
+	/// # Safety
+	/// `table` must be [`CustomTableContent::BINDING`] of the global descriptor set
+	#[inline]
+	pub unsafe fn new(table: &'a RuntimeArray<C::Descriptor>) -> Self {
+		Self {
+			table,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<'a, C: CustomTableContent> DescriptorAccess<'a, C> for &'a CustomDescriptors<'_, C> {
+	type AccessType = &'a C::Descriptor;
+
+	fn access(self, desc: &Desc<impl AliveDescRef, C>) -> Self::AccessType {
+		unsafe { self.table.index(desc.id().index().to_usize()) }
+	}
+}
@@ -2,4 +2,5 @@
 /// * [`crate::descriptor::buffer::Buffer`]
 /// * [`crate::descriptor::image::Image`]
 /// * [`crate::descriptor::sampler::Sampler`]
+/// * contents of custom tables, see [`crate::descriptor::CustomDescriptors`]
 pub trait DescContent: Sized + Send + Sync {}
@@ -6,7 +6,9 @@ use crate::descriptor::{
 	TransientAccess, UnsafeDesc,
 };
 use bytemuck_derive::{Pod, Zeroable};
-use spirv_std::{RuntimeArray, Sampler, TypedBuffer};
+use core::mem::size_of;
+use spirv_std::{ByteAddressableBuffer, RuntimeArray, Sampler, TypedBuffer};
+use static_assertions::const_assert_eq;
 
 /// Some struct that facilitates access to a [`AliveDescRef`] pointing to some [`DescContent`]
 pub trait DescriptorAccess<'a, C: DescContent> {
@@ -16,25 +18,27 @@ pub trait DescriptorAccess<'a, C: DescContent> {
 }
 
 macro_rules! decl_descriptors {
-    ($($image:ident: $sampled:ident $storage:ident,)*) => {
+    ($($image:ident: $sampled:ident $($storage:ident)?,)*) => {
 		pub struct Descriptors<'a> {
 			pub buffers: &'a RuntimeArray<TypedBuffer<[u32]>>,
 			pub buffers_mut: &'a mut RuntimeArray<TypedBuffer<[u32]>>,
 			$(
-				pub $storage: &'a RuntimeArray<<crate::descriptor::$image as ImageType>::StorageSpvImage>,
+				$(pub $storage: &'a RuntimeArray<<crate::descriptor::$image as ImageType>::StorageSpvImage>,)?
 				pub $sampled: &'a RuntimeArray<<crate::descriptor::$image as ImageType>::SampledSpvImage>,
 			)*
 			pub samplers: &'a RuntimeArray<Sampler>,
 			pub meta: Metadata,
 		}
 		$(
-			impl<'a> DescriptorAccess<'a, MutImage<crate::descriptor::$image>> for &'a Descriptors<'_> {
-				type AccessType = &'a <crate::descriptor::$image as ImageType>::StorageSpvImage;
+			$(
+				impl<'a> DescriptorAccess<'a, MutImage<crate::descriptor::$image>> for &'a Descriptors<'_> {
+					type AccessType = &'a <crate::descriptor::$image as ImageType>::StorageSpvImage;
 
-				fn access(self, desc: &Desc<impl AliveDescRef, MutImage<crate::descriptor::$image>>) -> Self::AccessType {
-					unsafe { self.$storage.index(desc.id().index().to_usize()) }
+					fn access(self, desc: &Desc<impl AliveDescRef, MutImage<crate::descriptor::$image>>) -> Self::AccessType {
+						unsafe { self.$storage.index(desc.id().index().to_usize()) }
+					}
 				}
-			}
+			)?
 
 			impl<'a> DescriptorAccess<'a, Image<crate::descriptor::$image>> for &'a Descriptors<'_> {
 				type AccessType = &'a <crate::descriptor::$image as ImageType>::SampledSpvImage;
@@ -74,16 +78,37 @@ impl<'a> DescriptorAccess<'a, Sampler> for &'a Descriptors<'_> {
 
 unsafe impl<'a> TransientAccess<'a> for Descriptors<'a> {}
 
+/// The amount of u32 words in [`BindlessPushConstant`] available to embed params inline. Together with the header,
+/// the push constant is 128 bytes, which is the minimum push constant size every Vulkan device must support.
+pub const PUSH_CONSTANT_INLINE_WORDS: usize = 30;
+
+/// The max size in bytes of a param's `Transfer` to be embedded inline in the [`BindlessPushConstant`]
+pub const PUSH_CONSTANT_INLINE_SIZE: usize = PUSH_CONSTANT_INLINE_WORDS * 4;
+
+/// The size in bytes of the [`BindlessPushConstant`] without the inline param
+pub const PUSH_CONSTANT_HEADER_SIZE: usize = size_of::<BindlessPushConstant>() - PUSH_CONSTANT_INLINE_SIZE;
+
+/// Whether a param of type `T` is embedded inline in the [`BindlessPushConstant`] instead of being loaded from a
+/// buffer, selected at compile time from the size of its `Transfer`.
+pub const fn is_param_inline<T: BufferStruct>() -> bool {
+	size_of::<T::Transfer>() <= PUSH_CONSTANT_INLINE_SIZE
+}
+
 /// All bindless push constants are this particular struct, with T being the declared push_param.
 ///
 /// Must not derive `DescStruct`, as to [`DescStruct::from_transfer`] Self you'd need the Metadata, which this struct
 /// contains. To break the loop, it just stores Metadata flat and params directly as `T::TransferDescStruct`.
+///
+/// Small params are embedded in `inline` directly, see [`is_param_inline`]. Larger params are loaded from the buffer
+/// `param_desc` at offset `param_offset`.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Zeroable, Pod)]
 pub struct BindlessPushConstant {
 	param_desc: DescriptorId,
 	param_offset: u32,
+	inline: [u32; PUSH_CONSTANT_INLINE_WORDS],
 }
+const_assert_eq!(size_of::<BindlessPushConstant>(), 128);
 
 impl BindlessPushConstant {
 	/// Construct the `PushConstant` struct.
@@ -94,15 +119,49 @@ impl BindlessPushConstant {
 		Self {
 			param_desc,
 			param_offset,
+			inline: [0; PUSH_CONSTANT_INLINE_WORDS],
+		}
+	}
+
+	/// Construct the `PushConstant` struct with the param embedded inline.
+	///
+	/// # Safety
+	/// `T` must be the param of the shader.
+	pub unsafe fn new_inline<T: BufferStruct>(transfer: T::Transfer) -> Self {
+		assert!(is_param_inline::<T>());
+		let mut this = <Self as bytemuck::Zeroable>::zeroed();
+		unsafe {
+			core::ptr::copy_nonoverlapping(
+				(&transfer as *const T::Transfer).cast::<u8>(),
+				this.inline.as_mut_ptr().cast::<u8>(),
+				size_of::<T::Transfer>(),
+			);
+		}
+		this
+	}
+
+	/// The amount of bytes that need to be pushed for a param of type `T`, always a multiple of 4.
+	pub const fn push_size<T: BufferStruct>() -> usize {
+		if is_param_inline::<T>() {
+			PUSH_CONSTANT_HEADER_SIZE + size_of::<T::Transfer>().next_multiple_of(4)
+		} else {
+			PUSH_CONSTANT_HEADER_SIZE
 		}
 	}
 
 	pub fn load_param<T: BufferStruct>(&self, descriptors: &Descriptors) -> T {
 		unsafe {
-			UnsafeDesc::<Buffer<[u8]>>::new(self.param_desc)
-				.to_transient_unchecked(descriptors)
-				.access(descriptors)
-				.load_at_arbitrary_offset_unchecked::<T>(self.param_offset as usize)
+			if is_param_inline::<T>() {
+				T::read(
+					ByteAddressableBuffer::from_slice(&self.inline).load_unchecked(0),
+					descriptors.meta,
+				)
+			} else {
+				UnsafeDesc::<Buffer<[u8]>>::new(self.param_desc)
+					.to_transient_unchecked(descriptors)
+					.access(descriptors)
+					.load_at_arbitrary_offset_unchecked::<T>(self.param_offset as usize)
+			}
 		}
 	}
 }
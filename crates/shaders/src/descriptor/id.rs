@@ -8,9 +8,68 @@ use core::ops::Sub;
 use rust_gpu_bindless_macros::BufferStruct;
 use static_assertions::const_assert_eq;
 
-pub const ID_INDEX_BITS: u32 = 18;
-pub const ID_TYPE_BITS: u32 = 2;
-pub const ID_VERSION_BITS: u32 = 12;
+// The bit layout of DescriptorId is selected by cargo features. The shaders are compiled in a separate cargo invocation
+// by the shader builder, which does not see the features of the CPU side, so the layout has to be passed to it with
+// `ShaderSymbolsBuilder::id_layout`. Pipeline creation checks that both sides use the same `ID_LAYOUT`.
+//
+// The layout is global to the entire build: cargo unifies features, so if two crates in the dependency graph select
+// different layouts, both features are enabled and compilation fails below. Libraries must not select a layout, only the
+// final binary crate should.
+#[cfg(any(
+	all(feature = "id-layout-20-2-10", feature = "id-layout-17-3-12"),
+	all(feature = "id-layout-20-2-10", feature = "id-layout-16-2-14"),
+	all(feature = "id-layout-17-3-12", feature = "id-layout-16-2-14"),
+))]
+compile_error!(
+	"At most one `id-layout-*` feature may be enabled. Features are unified across the dependency graph, so only the \
+	 final binary crate should select a layout."
+);
+
+/// 20 index bits, 2 type bits, 10 version bits
+#[cfg(feature = "id-layout-20-2-10")]
+mod layout {
+	pub const ID_INDEX_BITS: u32 = 20;
+	pub const ID_TYPE_BITS: u32 = 2;
+	pub const ID_VERSION_BITS: u32 = 10;
+}
+
+/// 17 index bits, 3 type bits, 12 version bits
+#[cfg(feature = "id-layout-17-3-12")]
+mod layout {
+	pub const ID_INDEX_BITS: u32 = 17;
+	pub const ID_TYPE_BITS: u32 = 3;
+	pub const ID_VERSION_BITS: u32 = 12;
+}
+
+/// 16 index bits, 2 type bits, 14 version bits
+#[cfg(feature = "id-layout-16-2-14")]
+mod layout {
+	pub const ID_INDEX_BITS: u32 = 16;
+	pub const ID_TYPE_BITS: u32 = 2;
+	pub const ID_VERSION_BITS: u32 = 14;
+}
+
+/// default: 18 index bits, 2 type bits, 12 version bits
+#[cfg(not(any(
+	feature = "id-layout-20-2-10",
+	feature = "id-layout-17-3-12",
+	feature = "id-layout-16-2-14"
+)))]
+mod layout {
+	pub const ID_INDEX_BITS: u32 = 18;
+	pub const ID_TYPE_BITS: u32 = 2;
+	pub const ID_VERSION_BITS: u32 = 12;
+}
+
+/// The amount of bits used for the [`DescriptorIndex`], limiting the amount of slots per table
+pub const ID_INDEX_BITS: u32 = layout::ID_INDEX_BITS;
+/// The amount of bits used for the [`DescriptorType`], limiting the amount of tables
+pub const ID_TYPE_BITS: u32 = layout::ID_TYPE_BITS;
+/// The amount of bits used for the [`DescriptorVersion`], limiting how often a slot can be reused before it is retired
+pub const ID_VERSION_BITS: u32 = layout::ID_VERSION_BITS;
+/// The selected layout as `[ID_INDEX_BITS, ID_TYPE_BITS, ID_VERSION_BITS]`, embedded in every
+/// [`SpirvBinary`](crate::shader::SpirvBinary) to detect shaders compiled with a different layout
+pub const ID_LAYOUT: [u32; 3] = [ID_INDEX_BITS, ID_TYPE_BITS, ID_VERSION_BITS];
 
 const ID_INDEX_MASK: u32 = (1 << ID_INDEX_BITS) - 1;
 const ID_TYPE_MASK: u32 = (1 << ID_TYPE_BITS) - 1;
@@ -225,3 +284,64 @@ unsafe impl<C: DescContent> DescStructRef<UnsafeDesc<C>> for DescriptorId {
 		unsafe { UnsafeDesc::new(DescriptorId::read(from, meta)) }
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn round_trip(desc_type: u32, index: u32, version: u32) -> DescriptorId {
+		unsafe {
+			let id = DescriptorId::new(
+				DescriptorType::new(desc_type).unwrap(),
+				DescriptorIndex::new(index).unwrap(),
+				DescriptorVersion::new(version).unwrap(),
+			);
+			assert_eq!(id.desc_type().to_u32(), desc_type);
+			assert_eq!(id.index().to_u32(), index);
+			assert_eq!(id.version().to_u32(), version);
+			id
+		}
+	}
+
+	/// Run with every `id-layout-*` feature to test all layouts
+	#[test]
+	fn test_id_round_trip() {
+		let max_type = ID_TYPE_MASK;
+		let max_index = ID_INDEX_MASK;
+		let max_version = ID_VERSION_MASK;
+		assert_eq!(round_trip(0, 0, 0).0, 0);
+		assert_eq!(round_trip(max_type, max_index, max_version).0, !0);
+		round_trip(1, max_index, 0);
+		round_trip(max_type, 0, max_version);
+		round_trip(0, max_index / 3, max_version / 3);
+		unsafe {
+			assert_eq!(DescriptorType::new(max_type + 1), None);
+			assert_eq!(DescriptorIndex::new(max_index + 1), None);
+			assert_eq!(DescriptorVersion::new(max_version + 1), None);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "id-layout-20-2-10")]
+	fn test_id_layout_20_2_10() {
+		assert_eq!(ID_LAYOUT, [20, 2, 10]);
+		assert_eq!(round_trip(2, (1 << 20) - 1, 1023).0, 0xFFEF_FFFF);
+		unsafe { assert_eq!(DescriptorVersion::new(1024), None) };
+	}
+
+	#[test]
+	#[cfg(feature = "id-layout-17-3-12")]
+	fn test_id_layout_17_3_12() {
+		assert_eq!(ID_LAYOUT, [17, 3, 12]);
+		assert_eq!(round_trip(7, 1 << 16, 1).0, 0x001F_0000);
+		unsafe { assert_eq!(DescriptorIndex::new(1 << 17), None) };
+	}
+
+	#[test]
+	#[cfg(feature = "id-layout-16-2-14")]
+	fn test_id_layout_16_2_14() {
+		assert_eq!(ID_LAYOUT, [16, 2, 14]);
+		assert_eq!(round_trip(1, 0xFFFF, (1 << 14) - 1).0, 0xFFFD_FFFF);
+		unsafe { assert_eq!(DescriptorIndex::new(1 << 16), None) };
+	}
+}
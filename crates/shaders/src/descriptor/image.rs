@@ -1,5 +1,7 @@
 use crate::descriptor::descriptor_content::DescContent;
 use core::marker::PhantomData;
+use glam::{Vec2, Vec3, Vec4};
+use spirv_std::Sampler;
 pub use spirv_std::image::SampleType;
 use spirv_std::image::{Arrayed, Dimensionality, Image as SpvImage, ImageDepth, ImageFormat, Multisampled, Sampled};
 
@@ -98,3 +100,55 @@ unsafe impl<
 		4,
 	>;
 }
+
+/// Sampling depth images with a comparison sampler, i.e. a sampler created with a `compare_op`. The sampler compares
+/// `depth_reference` against the depth of each texel and filters the results, returning a value between 0 and 1. Used
+/// for hardware PCF shadow sampling.
+pub trait SampleCompare {
+	/// The coordinate to sample the image at
+	type Coordinate;
+
+	fn sample_compare(&self, sampler: Sampler, coordinate: Self::Coordinate, depth_reference: f32) -> f32;
+
+	/// Like [`Self::sample_compare`], but samples mip `lod` instead of computing it from derivatives, so it can be
+	/// used outside of fragment shaders.
+	fn sample_compare_by_lod(
+		&self,
+		sampler: Sampler,
+		coordinate: Self::Coordinate,
+		depth_reference: f32,
+		lod: f32,
+	) -> f32;
+}
+
+macro_rules! impl_sample_compare {
+	($($image:ident: $coordinate:ty,)*) => {
+		$(
+			impl SampleCompare for <crate::descriptor::$image as ImageType>::SampledSpvImage {
+				type Coordinate = $coordinate;
+
+				fn sample_compare(&self, sampler: Sampler, coordinate: $coordinate, depth_reference: f32) -> f32 {
+					self.sample_depth_reference(sampler, coordinate, depth_reference)
+				}
+
+				fn sample_compare_by_lod(
+					&self,
+					sampler: Sampler,
+					coordinate: $coordinate,
+					depth_reference: f32,
+					lod: f32,
+				) -> f32 {
+					self.sample_depth_reference_by_lod(sampler, coordinate, depth_reference, lod)
+				}
+			}
+		)*
+	};
+}
+
+impl_sample_compare! {
+	Image1d: f32,
+	Image2d: Vec2,
+	Image2dArray: Vec3,
+	Cubemap: Vec3,
+	CubemapArray: Vec4,
+}
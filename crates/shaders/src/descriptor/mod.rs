@@ -1,4 +1,5 @@
 mod buffer;
+mod custom;
 mod descriptor_content;
 mod descriptors;
 mod id;
@@ -15,6 +16,7 @@ mod weak;
 mod image_types;
 
 pub use buffer::*;
+pub use custom::*;
 pub use descriptor_content::*;
 pub use descriptors::*;
 pub use id::*;
@@ -30,3 +32,5 @@ pub const BINDING_BUFFER: u32 = 0;
 pub const BINDING_STORAGE_IMAGE: u32 = 1;
 pub const BINDING_SAMPLED_IMAGE: u32 = 2;
 pub const BINDING_SAMPLER: u32 = 3;
+/// The first binding available to custom tables
+pub const BINDING_CUSTOM_BASE: u32 = 4;
@@ -1,5 +1,5 @@
 use crate::descriptor::ImageTypeImpl;
-use spirv_std::image::SampleType;
+use spirv_std::image::{Arrayed, Dimensionality, Multisampled, SampleType};
 use spirv_std::image::{Image as SpvImage, ImageFormat};
 
 pub trait SpvImageToBindlessImage {
@@ -20,6 +20,8 @@ impl<
 	type Image = ImageTypeImpl<SampledType, DIM, ARRAYED, MULTISAMPLED>;
 }
 
+/// A 1d image used with a sampler. Not accessible as a storage image.
+pub type Image1d = <spirv_std::image::Image1d as SpvImageToBindlessImage>::Image;
 /// A 2d image used with a sampler. This is pretty typical and probably what you want.
 pub type Image2d = <spirv_std::image::Image2d as SpvImageToBindlessImage>::Image;
 /// A 3d image used with a sampler.
@@ -32,5 +34,13 @@ pub type Image3dU = <spirv_std::image::Image3dU as SpvImageToBindlessImage>::Ima
 pub type Image2dI = <spirv_std::image::Image2dI as SpvImageToBindlessImage>::Image;
 /// A 3d image used with a sampler, containing signed integer data.
 pub type Image3dI = <spirv_std::image::Image3dI as SpvImageToBindlessImage>::Image;
+/// An array of 2d images used with a sampler, like shadow cascades or texture atlases.
+pub type Image2dArray = <spirv_std::image::Image2dArray as SpvImageToBindlessImage>::Image;
+/// A multisampled 2d image, whose samples can only be fetched individually. Not accessible as a storage image.
+pub type Image2dMs =
+	ImageTypeImpl<f32, { Dimensionality::TwoD as u32 }, { Arrayed::False as u32 }, { Multisampled::True as u32 }>;
 /// A cubemap, i.e. a cube of 6 textures, sampled using a direction rather than image coordinates.
 pub type Cubemap = <spirv_std::image::Cubemap as SpvImageToBindlessImage>::Image;
+/// An array of cubemaps, with 6 array layers per cubemap.
+pub type CubemapArray =
+	ImageTypeImpl<f32, { Dimensionality::Cube as u32 }, { Arrayed::True as u32 }, { Multisampled::False as u32 }>;
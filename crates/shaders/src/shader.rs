@@ -15,4 +15,6 @@ pub trait BindlessShader {
 pub struct SpirvBinary<'a> {
 	pub binary: &'a [u32],
 	pub entry_point_name: &'a CStr,
+	/// The [`ID_LAYOUT`](crate::descriptor::ID_LAYOUT) the shader was compiled with, checked at pipeline creation
+	pub id_layout: [u32; 3],
 }
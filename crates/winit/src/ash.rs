@@ -18,9 +18,11 @@ use rust_gpu_bindless_core::pipeline::{AccessLock, AccessLockError, ImageAccess}
 use rust_gpu_bindless_core::platform::ash::{
 	Ash, AshAllocationError, AshImage, AshMemoryAllocation, AshPendingExecution,
 };
+use std::any::TypeId;
 use std::ffi::CStr;
 use std::fmt::Display;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 use winit::event::{Event, WindowEvent};
@@ -82,7 +84,7 @@ impl AshSwapchainParams {
 		SwapchainCreateInfoKHR::default()
 			.surface(surface)
 			.min_image_count(self.image_count)
-			.image_format(self.format)
+			.image_format(self.format.to_ash_format())
 			.image_color_space(self.colorspace)
 			.image_extent(extent.into())
 			.image_array_layers(1)
@@ -108,8 +110,8 @@ impl AshSwapchainParams {
 			let (format, colorspace) = surface_ext
 				.get_physical_device_surface_formats(phy, *surface)?
 				.into_iter()
-				.map(|e| (e.format, e.color_space))
-				.filter(|(_, c)| *c == ColorSpaceKHR::SRGB_NONLINEAR)
+				.filter(|e| e.color_space == ColorSpaceKHR::SRGB_NONLINEAR)
+				.filter_map(|e| Some((Format::from_ash_format(e.format)?, e.color_space)))
 				.max_by_key(|(format, _)| format_preference.value_format(*format))
 				.context("No SRGB_NONLINEAR surface format available")?;
 
@@ -317,7 +319,12 @@ impl AshSwapchain {
 				extent,
 				mip_levels: 1,
 				array_layers: 1,
-				access_lock: AccessLock::new_locked(),
+				samples: SampleCount::Sample1,
+				image_type: TypeId::of::<Image2d>(),
+				base_mip: 0,
+				base_layer: 0,
+				parent: None,
+				access_lock: Arc::new(AccessLock::new_locked()),
 				debug_name,
 				swapchain_image_id: SwapchainImageId::new(id),
 			})?;
@@ -0,0 +1,42 @@
+use glam::{IVec2, Vec3, Vec4};
+use rust_gpu_bindless_macros::{BufferStruct, bindless};
+use rust_gpu_bindless_shaders::descriptor::{
+	CubemapArray, Descriptors, Image, Image1d, Image2dArray, Image2dMs, MutBuffer, Sampler, TransientDesc,
+};
+use spirv_std::image::sample_with;
+
+#[derive(Copy, Clone, BufferStruct)]
+pub struct Param<'a> {
+	pub image_1d: TransientDesc<'a, Image<Image1d>>,
+	pub image_2d_array: TransientDesc<'a, Image<Image2dArray>>,
+	pub cubemap_array: TransientDesc<'a, Image<CubemapArray>>,
+	pub image_2d_ms: TransientDesc<'a, Image<Image2dMs>>,
+	pub sampler: TransientDesc<'a, Sampler>,
+	pub out: TransientDesc<'a, MutBuffer<[Vec4]>>,
+}
+
+/// Reads a texel from each of the image types requiring additional capabilities
+#[bindless(compute(threads(1)))]
+pub fn image_types(
+	#[bindless(descriptors)] mut descriptors: Descriptors<'_>,
+	#[bindless(param)] param: &Param<'static>,
+) {
+	let sampler = param.sampler.access(&descriptors);
+	let image_1d = param.image_1d.access(&descriptors);
+	let image_2d_array = param.image_2d_array.access(&descriptors);
+	let cubemap_array = param.cubemap_array.access(&descriptors);
+	let image_2d_ms = param.image_2d_ms.access(&descriptors);
+
+	let image_1d: Vec4 = image_1d.sample_by_lod(sampler, 0.5, 0.);
+	let image_2d_array: Vec4 = image_2d_array.sample_by_lod(sampler, Vec3::new(0.5, 0.5, 1.), 0.);
+	let cubemap_array: Vec4 = cubemap_array.sample_by_lod(sampler, Vec4::new(1., 0., 0., 1.), 0.);
+	let image_2d_ms: Vec4 = image_2d_ms.fetch_with(IVec2::ZERO, sample_with::sample_index(3));
+
+	let mut out = param.out.access(&mut descriptors);
+	unsafe {
+		out.store(0, image_1d);
+		out.store(1, image_2d_array);
+		out.store(2, cubemap_array);
+		out.store(3, image_2d_ms);
+	}
+}
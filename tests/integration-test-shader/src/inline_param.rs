@@ -0,0 +1,55 @@
+use rust_gpu_bindless_macros::{BufferStruct, bindless};
+use rust_gpu_bindless_shaders::buffer_content::BufferStruct;
+use rust_gpu_bindless_shaders::descriptor::{
+	Descriptors, MutBuffer, PUSH_CONSTANT_INLINE_SIZE, PUSH_CONSTANT_INLINE_WORDS, TransientDesc, is_param_inline,
+};
+use static_assertions::{const_assert, const_assert_eq};
+
+/// The amount of words of [`InlineParam::data`], so that together with `out` it fills the inline push constant exactly
+pub const INLINE_PARAM_LEN: usize = PUSH_CONSTANT_INLINE_WORDS - 1;
+
+/// The largest param that is still embedded inline
+#[derive(Copy, Clone, BufferStruct)]
+pub struct InlineParam<'a> {
+	pub out: TransientDesc<'a, MutBuffer<[u32]>>,
+	pub data: [u32; INLINE_PARAM_LEN],
+}
+const_assert_eq!(
+	size_of::<<InlineParam<'static> as BufferStruct>::Transfer>(),
+	PUSH_CONSTANT_INLINE_SIZE
+);
+const_assert!(is_param_inline::<InlineParam<'static>>());
+
+/// The smallest param that is loaded from a buffer
+#[derive(Copy, Clone, BufferStruct)]
+pub struct BufferParam<'a> {
+	pub out: TransientDesc<'a, MutBuffer<[u32]>>,
+	pub data: [u32; INLINE_PARAM_LEN + 1],
+}
+const_assert!(!is_param_inline::<BufferParam<'static>>());
+
+#[bindless(compute(threads(1)))]
+pub fn inline_param(
+	#[bindless(descriptors)] mut descriptors: Descriptors<'_>,
+	#[bindless(param)] param: &InlineParam<'static>,
+) {
+	let mut out = param.out.access(&mut descriptors);
+	for i in 0..INLINE_PARAM_LEN {
+		unsafe {
+			out.store(i, param.data[i]);
+		}
+	}
+}
+
+#[bindless(compute(threads(1)))]
+pub fn buffer_param(
+	#[bindless(descriptors)] mut descriptors: Descriptors<'_>,
+	#[bindless(param)] param: &BufferParam<'static>,
+) {
+	let mut out = param.out.access(&mut descriptors);
+	for i in 0..INLINE_PARAM_LEN + 1 {
+		unsafe {
+			out.store(i, param.data[i]);
+		}
+	}
+}
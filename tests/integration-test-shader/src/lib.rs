@@ -12,5 +12,8 @@ extern crate std;
 
 pub mod buffer_barriers;
 pub mod color;
+pub mod image_types;
+pub mod inline_param;
+pub mod sample_compare;
 pub mod simple_compute;
 pub mod triangle;
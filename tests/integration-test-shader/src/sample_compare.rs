@@ -0,0 +1,32 @@
+use glam::{UVec3, Vec2};
+use rust_gpu_bindless_macros::{BufferStruct, bindless};
+use rust_gpu_bindless_shaders::descriptor::{
+	Buffer, Descriptors, Image, Image2d, MutBuffer, SampleCompare, Sampler, TransientDesc,
+};
+
+#[derive(Copy, Clone, BufferStruct)]
+pub struct Param<'a> {
+	pub depth: TransientDesc<'a, Image<Image2d>>,
+	pub sampler: TransientDesc<'a, Sampler>,
+	pub depth_references: TransientDesc<'a, Buffer<[f32]>>,
+	pub out: TransientDesc<'a, MutBuffer<[f32]>>,
+}
+
+/// Compares each depth reference against the center of the depth image
+#[bindless(compute(threads(1)))]
+pub fn sample_compare(
+	#[bindless(descriptors)] mut descriptors: Descriptors<'_>,
+	#[bindless(param)] param: &Param<'static>,
+	#[spirv(workgroup_id)] wg_id: UVec3,
+) {
+	let index = wg_id.x as usize;
+	let depth_reference = param.depth_references.access(&descriptors).load(index);
+	let sampler = param.sampler.access(&descriptors);
+	let result = param
+		.depth
+		.access(&descriptors)
+		.sample_compare_by_lod(sampler, Vec2::splat(0.5), depth_reference, 0.);
+	unsafe {
+		param.out.access(&mut descriptors).store(index, result);
+	}
+}
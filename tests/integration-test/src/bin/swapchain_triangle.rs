@@ -1,20 +1,16 @@
-use ash::vk::{
-	ColorComponentFlags, CullModeFlags, FrontFace, PipelineColorBlendAttachmentState,
-	PipelineColorBlendStateCreateInfo, PolygonMode, PrimitiveTopology,
-};
 use glam::{Vec2, Vec4};
 use integration_test::debugger;
 use integration_test_shader::color::ColorEnum;
 use integration_test_shader::triangle::{Param, Vertex};
 use rust_gpu_bindless_core::descriptor::{
 	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessImageUsage,
-	BindlessInstance, DescriptorCounts, Format, Image2d, MutDesc, MutImage, RCDescExt,
+	BindlessInstance, DescriptorCounts, Format, Image2d, MutDesc, MutImage, RCDescExt, SampleCount,
 };
 use rust_gpu_bindless_core::pipeline::DrawIndirectCommand;
 use rust_gpu_bindless_core::pipeline::{
-	BindlessGraphicsPipeline, ClearValue, ColorAttachment, GraphicsPipelineCreateInfo, LoadOp, MutImageAccessExt,
-	PipelineDepthStencilStateCreateInfo, PipelineInputAssemblyStateCreateInfo, PipelineRasterizationStateCreateInfo,
-	Present, RenderPassFormat, RenderingAttachment, StoreOp,
+	BindlessGraphicsPipeline, ClearValue, ColorAttachment, ColorBlendAttachment, ColorBlendState, CullMode,
+	DepthStencilState, FrontFace, GraphicsPipelineCreateInfo, InputAssemblyState, LoadOp, MutImageAccessExt,
+	PolygonMode, Present, PrimitiveTopology, RasterizationState, RenderPassFormat, RenderingAttachment, StoreOp,
 };
 use rust_gpu_bindless_core::platform::BindlessPipelinePlatform;
 use rust_gpu_bindless_core::platform::ash::Debuggers;
@@ -113,6 +109,7 @@ impl TriangleRendererRTFormat {
 		RenderPassFormat {
 			color_attachments: SmallVec::from_slice(&[self.rt_format]),
 			depth_attachment: None,
+			samples: SampleCount::Sample1,
 		}
 	}
 }
@@ -133,16 +130,21 @@ impl<P: BindlessPipelinePlatform> TriangleRenderer<P> {
 		let pipeline = bindless.create_graphics_pipeline(
 			&rt_format.to_render_pass_format(),
 			&GraphicsPipelineCreateInfo {
-				input_assembly_state: PipelineInputAssemblyStateCreateInfo::default()
-					.topology(PrimitiveTopology::TRIANGLE_LIST),
-				rasterization_state: PipelineRasterizationStateCreateInfo::default()
-					.polygon_mode(PolygonMode::FILL)
-					.front_face(FrontFace::COUNTER_CLOCKWISE)
-					.cull_mode(CullModeFlags::BACK),
-				depth_stencil_state: PipelineDepthStencilStateCreateInfo::default(),
-				color_blend_state: PipelineColorBlendStateCreateInfo::default().attachments(&[
-					PipelineColorBlendAttachmentState::default().color_write_mask(ColorComponentFlags::RGBA),
-				]),
+				input_assembly_state: InputAssemblyState {
+					topology: PrimitiveTopology::TriangleList,
+					..InputAssemblyState::default()
+				},
+				rasterization_state: RasterizationState {
+					polygon_mode: PolygonMode::Fill,
+					front_face: FrontFace::CounterClockwise,
+					cull_mode: CullMode::Back,
+					..RasterizationState::default()
+				},
+				depth_stencil_state: DepthStencilState::default(),
+				color_blend_state: ColorBlendState {
+					attachments: &[ColorBlendAttachment::default()],
+					..ColorBlendState::default()
+				},
 			},
 			integration_test::shader::triangle::triangle_vertex::new(),
 			integration_test::shader::triangle::triangle_fragment::new(),
@@ -186,6 +188,7 @@ impl<P: BindlessPipelinePlatform> TriangleRenderer<P> {
 					image: &mut rt,
 					load_op: LoadOp::Clear(ClearValue::ColorF(ColorEnum::Black.color().to_array())),
 					store_op: StoreOp::Store,
+					resolve: None,
 				}],
 				None,
 				|rp| {
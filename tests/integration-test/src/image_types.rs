@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use crate::debugger;
+use glam::Vec4;
+use integration_test_shader::image_types::Param;
+use pollster::block_on;
+use rust_gpu_bindless_core::descriptor::{
+	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessImageCreateInfo,
+	BindlessImageUsage, BindlessInstance, BindlessSamplerCreateInfo, CubemapArray, DescriptorCounts, Format, Image1d,
+	Image2dArray, Image2dMs, MutDescBufferExt, RCDescExt, SampleCount,
+};
+use rust_gpu_bindless_core::pipeline::{
+	ClearValue, HostAccess, MutBufferAccessExt, MutImageAccessExt, ShaderReadWrite, TransferWrite,
+};
+use rust_gpu_bindless_core::platform::BindlessPipelinePlatform;
+use rust_gpu_bindless_core::platform::ash::{Ash, AshSingleGraphicsQueueCreateInfo, ash_init_single_graphics_queue};
+
+#[test]
+fn test_image_types_ash() -> anyhow::Result<()> {
+	unsafe {
+		let bindless = BindlessInstance::<Ash>::new(
+			ash_init_single_graphics_queue(AshSingleGraphicsQueueCreateInfo {
+				debug: debugger(),
+				..AshSingleGraphicsQueueCreateInfo::default()
+			})?,
+			DescriptorCounts::REASONABLE_DEFAULTS,
+		);
+		block_on(test_image_types(&bindless))?;
+		Ok(())
+	}
+}
+
+/// Samples every image type that requires additional shader capabilities or device features
+async fn test_image_types<P: BindlessPipelinePlatform>(bindless: &Bindless<P>) -> anyhow::Result<()> {
+	let pipeline = bindless.create_compute_pipeline(crate::shader::image_types::image_types::new())?;
+
+	let format = Format::R8G8B8A8_UNORM;
+	let white = [0xFF; 4 * 4 * 4 * 12];
+	let image_1d = bindless.image().alloc_from_data(
+		&BindlessImageCreateInfo::<Image1d> {
+			format,
+			extent: [4].into(),
+			usage: BindlessImageUsage::SAMPLED,
+			name: "image_1d",
+			..BindlessImageCreateInfo::default()
+		},
+		&white[..4 * 4],
+	)?;
+	let image_2d_array = bindless.image().alloc_from_data(
+		&BindlessImageCreateInfo::<Image2dArray> {
+			format,
+			extent: [4, 4].into(),
+			array_layers: 2,
+			usage: BindlessImageUsage::SAMPLED,
+			name: "image_2d_array",
+			..BindlessImageCreateInfo::default()
+		},
+		&white[..4 * 4 * 4 * 2],
+	)?;
+	let cubemap_array = bindless.image().alloc_from_data(
+		&BindlessImageCreateInfo::<CubemapArray> {
+			format,
+			extent: [4, 4].into(),
+			array_layers: 12,
+			usage: BindlessImageUsage::SAMPLED,
+			name: "cubemap_array",
+			..BindlessImageCreateInfo::default()
+		},
+		&white,
+	)?;
+	// multisampled images can't be uploaded to, only cleared
+	let image_2d_ms = bindless.image().alloc::<Image2dMs>(&BindlessImageCreateInfo {
+		format,
+		extent: [4, 4].into(),
+		samples: SampleCount::Sample4,
+		usage: BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_DST,
+		name: "image_2d_ms",
+		..BindlessImageCreateInfo::default()
+	})?;
+	let image_2d_ms = bindless.execute(|cmd| {
+		let image = image_2d_ms.access_dont_care::<TransferWrite>(cmd)?;
+		cmd.clear_color_image(&image, ClearValue::ColorF([1.; 4]))?;
+		Ok(image.into_shared())
+	})?;
+	let (image_1d, image_2d_array, cubemap_array, image_2d_ms) = (
+		image_1d.await,
+		image_2d_array.await,
+		cubemap_array.await,
+		image_2d_ms.await,
+	);
+	let sampler = bindless.sampler().alloc(&BindlessSamplerCreateInfo::default())?;
+
+	let out = bindless.buffer().alloc_slice::<Vec4>(
+		&BindlessBufferCreateInfo {
+			name: "out",
+			usage: BindlessBufferUsage::MAP_READ | BindlessBufferUsage::STORAGE_BUFFER,
+			allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+		},
+		4,
+	)?;
+	let out = bindless.execute(|cmd| {
+		let out = out.access::<ShaderReadWrite>(cmd)?;
+		cmd.dispatch(
+			&pipeline,
+			[1, 1, 1],
+			Param {
+				image_1d: image_1d.to_transient(cmd),
+				image_2d_array: image_2d_array.to_transient(cmd),
+				cubemap_array: cubemap_array.to_transient(cmd),
+				image_2d_ms: image_2d_ms.to_transient(cmd),
+				sampler: sampler.to_transient(cmd),
+				out: out.to_mut_transient()?,
+			},
+		)?;
+		Ok(out.transition::<HostAccess>()?.into_desc())
+	})?;
+
+	let result = out.mapped().await?.read_iter().collect::<Vec<_>>();
+	assert!(
+		result.iter().all(|texel| texel.abs_diff_eq(Vec4::ONE, 0.01)),
+		"result: {result:?}"
+	);
+	Ok(())
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use crate::debugger;
+use integration_test_shader::inline_param::{BufferParam, INLINE_PARAM_LEN, InlineParam};
+use pollster::block_on;
+use rust_gpu_bindless_core::descriptor::{
+	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessInstance,
+	DescriptorCounts, MutDescBufferExt,
+};
+use rust_gpu_bindless_core::pipeline::{HostAccess, MutBufferAccessExt, ShaderReadWrite};
+use rust_gpu_bindless_core::platform::BindlessPipelinePlatform;
+use rust_gpu_bindless_core::platform::ash::{Ash, AshSingleGraphicsQueueCreateInfo, ash_init_single_graphics_queue};
+
+#[test]
+fn test_inline_param_ash() -> anyhow::Result<()> {
+	unsafe {
+		let bindless = BindlessInstance::<Ash>::new(
+			ash_init_single_graphics_queue(AshSingleGraphicsQueueCreateInfo {
+				debug: debugger(),
+				..AshSingleGraphicsQueueCreateInfo::default()
+			})?,
+			DescriptorCounts::REASONABLE_DEFAULTS,
+		);
+		block_on(test_inline_param(&bindless))?;
+		Ok(())
+	}
+}
+
+/// Loads a param that exactly fills the inline push constant and one that is a single word larger, which is loaded
+/// from a param buffer instead
+async fn test_inline_param<P: BindlessPipelinePlatform>(bindless: &Bindless<P>) -> anyhow::Result<()> {
+	let inline_pipeline = bindless.create_compute_pipeline(crate::shader::inline_param::inline_param::new())?;
+	let buffer_pipeline = bindless.create_compute_pipeline(crate::shader::inline_param::buffer_param::new())?;
+
+	let data: [u32; INLINE_PARAM_LEN + 1] = core::array::from_fn(|i| i as u32 * 3 + 1);
+	let out_ci = |name| BindlessBufferCreateInfo {
+		name,
+		usage: BindlessBufferUsage::MAP_READ | BindlessBufferUsage::STORAGE_BUFFER,
+		allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+	};
+	let inline_out = bindless
+		.buffer()
+		.alloc_slice::<u32>(&out_ci("inline out"), INLINE_PARAM_LEN)?;
+	let buffer_out = bindless
+		.buffer()
+		.alloc_slice::<u32>(&out_ci("buffer out"), INLINE_PARAM_LEN + 1)?;
+	let (inline_out, buffer_out) = bindless.execute(|cmd| {
+		let inline_out = inline_out.access::<ShaderReadWrite>(cmd)?;
+		let buffer_out = buffer_out.access::<ShaderReadWrite>(cmd)?;
+		cmd.dispatch(
+			&inline_pipeline,
+			[1, 1, 1],
+			InlineParam {
+				out: inline_out.to_mut_transient()?,
+				data: core::array::from_fn(|i| data[i]),
+			},
+		)?;
+		cmd.dispatch(
+			&buffer_pipeline,
+			[1, 1, 1],
+			BufferParam {
+				out: buffer_out.to_mut_transient()?,
+				data,
+			},
+		)?;
+		Ok((
+			inline_out.transition::<HostAccess>()?.into_desc(),
+			buffer_out.transition::<HostAccess>()?.into_desc(),
+		))
+	})?;
+
+	let inline_result = inline_out.mapped().await?.read_iter().collect::<Vec<_>>();
+	assert_eq!(inline_result, data[..INLINE_PARAM_LEN]);
+	let buffer_result = buffer_out.mapped().await?.read_iter().collect::<Vec<_>>();
+	assert_eq!(buffer_result, data);
+	Ok(())
+}
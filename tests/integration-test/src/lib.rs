@@ -2,6 +2,10 @@ use rust_gpu_bindless_core::platform::ash::Debuggers;
 
 pub mod buffer_barrier;
 pub mod image_copy;
+pub mod image_types;
+pub mod inline_param;
+pub mod param_arena;
+pub mod sample_compare;
 pub mod semaphore;
 pub mod shader;
 pub mod simple_compute;
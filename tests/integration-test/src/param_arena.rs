@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use crate::debugger;
+use rust_gpu_bindless_core::descriptor::{
+	BindlessInstance, BindlessPushConstant, DescriptorCounts, DescriptorId, MutDescExt, PUSH_CONSTANT_INLINE_WORDS,
+};
+use rust_gpu_bindless_core::platform::ash::{
+	ASH_PARAM_BLOCK_POOL_SIZE, ASH_PARAM_BLOCK_SIZE, Ash, AshParamArena, AshSingleGraphicsQueueCreateInfo,
+	ash_init_single_graphics_queue,
+};
+
+fn ash_bindless() -> anyhow::Result<BindlessInstance<Ash>> {
+	unsafe {
+		Ok(BindlessInstance::new(
+			ash_init_single_graphics_queue(AshSingleGraphicsQueueCreateInfo {
+				debug: debugger(),
+				..AshSingleGraphicsQueueCreateInfo::default()
+			})?,
+			DescriptorCounts::REASONABLE_DEFAULTS,
+		))
+	}
+}
+
+fn assert_push_constant(push_constant: BindlessPushConstant, block: DescriptorId, offset: u32) {
+	let expected = unsafe { BindlessPushConstant::new(block, offset) };
+	assert_eq!(bytemuck::bytes_of(&push_constant), bytemuck::bytes_of(&expected));
+}
+
+#[test]
+fn test_param_arena_packing() -> anyhow::Result<()> {
+	let bindless = ash_bindless()?;
+	let mut arena = AshParamArena::default();
+	unsafe {
+		// inline params don't allocate any blocks
+		arena.push(&bindless, [0u32; PUSH_CONSTANT_INLINE_WORDS]);
+		assert!(arena.blocks().is_empty());
+
+		let a = arena.push(&bindless, [1u32; PUSH_CONSTANT_INLINE_WORDS + 1]);
+		let b = arena.push(&bindless, [2u8; 125]);
+		let c = arena.push(&bindless, [3u32; 64]);
+		let d = arena.push(&bindless, [4u32; 64]);
+		assert_eq!(arena.blocks().len(), 1);
+		let block = &arena.blocks()[0];
+		assert_eq!(block.inner_slot().size, ASH_PARAM_BLOCK_SIZE);
+		assert_push_constant(a, block.id(), 0);
+		assert_push_constant(b, block.id(), 124);
+		// aligned to 4 bytes, as shaders load params as u32s
+		assert_push_constant(c, block.id(), 252);
+		assert_push_constant(d, block.id(), 252 + 256);
+	}
+	Ok(())
+}
+
+#[test]
+fn test_param_arena_new_block() -> anyhow::Result<()> {
+	let bindless = ash_bindless()?;
+	let mut arena = AshParamArena::default();
+	unsafe {
+		const QUARTER: usize = ASH_PARAM_BLOCK_SIZE as usize / 16;
+		for i in 0..4 {
+			let push_constant = arena.push(&bindless, [0u32; QUARTER]);
+			assert_push_constant(push_constant, arena.blocks()[0].id(), (i * QUARTER * 4) as u32);
+		}
+		assert_eq!(arena.blocks().len(), 1);
+
+		// the first block is full
+		let push_constant = arena.push(&bindless, [0u32; 64]);
+		assert_eq!(arena.blocks().len(), 2);
+		assert_ne!(arena.blocks()[0].id(), arena.blocks()[1].id());
+		assert_push_constant(push_constant, arena.blocks()[1].id(), 0);
+	}
+	Ok(())
+}
+
+#[test]
+fn test_param_arena_reuse() -> anyhow::Result<()> {
+	let bindless = ash_bindless()?;
+	let manager = &bindless.execution_manager;
+	unsafe {
+		let mut arena = AshParamArena::default();
+		arena.push(&bindless, [0u32; 64]);
+		// params larger than a block get a dedicated block
+		arena.push(&bindless, [0u32; ASH_PARAM_BLOCK_SIZE as usize / 4 + 1]);
+		assert_eq!(arena.blocks().len(), 2);
+		assert_eq!(arena.blocks()[1].inner_slot().size, ASH_PARAM_BLOCK_SIZE + 4);
+		let shared = arena.blocks()[0].id();
+		let dedicated = arena.blocks()[1].id();
+		manager.recycle_params(arena);
+
+		// only the shared block is reused
+		let mut arena = AshParamArena::default();
+		arena.push(&bindless, [0u32; 64]);
+		arena.push(&bindless, [0u32; ASH_PARAM_BLOCK_SIZE as usize / 4 + 1]);
+		assert_eq!(arena.blocks()[0].id(), shared);
+		assert_ne!(arena.blocks()[1].id(), dedicated);
+	}
+	Ok(())
+}
+
+#[test]
+fn test_param_arena_pool_size() -> anyhow::Result<()> {
+	let bindless = ash_bindless()?;
+	let manager = &bindless.execution_manager;
+	unsafe {
+		// every param fills an entire block
+		let fill_blocks = |count: usize| {
+			let mut arena = AshParamArena::default();
+			for _ in 0..count {
+				arena.push(&bindless, [0u32; ASH_PARAM_BLOCK_SIZE as usize / 4]);
+			}
+			arena
+		};
+		let arena = fill_blocks(ASH_PARAM_BLOCK_POOL_SIZE + 1);
+		let old = arena.blocks().iter().map(|block| block.id()).collect::<Vec<_>>();
+		manager.recycle_params(arena);
+
+		// blocks beyond the pool size are freed instead of reused
+		let arena = fill_blocks(ASH_PARAM_BLOCK_POOL_SIZE + 1);
+		let reused = arena.blocks().iter().filter(|block| old.contains(&block.id())).count();
+		assert_eq!(reused, ASH_PARAM_BLOCK_POOL_SIZE);
+	}
+	Ok(())
+}
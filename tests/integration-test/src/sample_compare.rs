@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use crate::debugger;
+use approx::assert_relative_eq;
+use integration_test_shader::sample_compare::Param;
+use pollster::block_on;
+use rust_gpu_bindless_core::descriptor::{
+	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessImageCreateInfo,
+	BindlessImageUsage, BindlessInstance, BindlessSamplerCreateInfo, DescriptorCounts, Format, Image2d,
+	MutDescBufferExt, RCDescExt,
+};
+use rust_gpu_bindless_core::pipeline::{
+	CompareOp, HostAccess, MutBufferAccessExt, MutImageAccessExt, ShaderReadWrite, TransferWrite,
+};
+use rust_gpu_bindless_core::platform::BindlessPipelinePlatform;
+use rust_gpu_bindless_core::platform::ash::{Ash, AshSingleGraphicsQueueCreateInfo, ash_init_single_graphics_queue};
+
+#[test]
+fn test_sample_compare_ash() -> anyhow::Result<()> {
+	unsafe {
+		let bindless = BindlessInstance::<Ash>::new(
+			ash_init_single_graphics_queue(AshSingleGraphicsQueueCreateInfo {
+				debug: debugger(),
+				..AshSingleGraphicsQueueCreateInfo::default()
+			})?,
+			DescriptorCounts::REASONABLE_DEFAULTS,
+		);
+		block_on(test_sample_compare(&bindless))?;
+		Ok(())
+	}
+}
+
+/// Samples a depth image cleared to 0.5 with a comparison sampler
+async fn test_sample_compare<P: BindlessPipelinePlatform>(bindless: &Bindless<P>) -> anyhow::Result<()> {
+	let pipeline = bindless.create_compute_pipeline(crate::shader::sample_compare::sample_compare::new())?;
+
+	let depth = bindless.image().alloc::<Image2d>(&BindlessImageCreateInfo {
+		format: Format::D32_SFLOAT,
+		extent: [4, 4].into(),
+		usage: BindlessImageUsage::SAMPLED | BindlessImageUsage::TRANSFER_DST,
+		name: "depth",
+		..BindlessImageCreateInfo::default()
+	})?;
+	let depth = bindless
+		.execute(|cmd| {
+			let depth = depth.access_dont_care::<TransferWrite>(cmd)?;
+			cmd.clear_depth_stencil_image(&depth, 0.5, 0)?;
+			Ok(depth.into_shared())
+		})?
+		.await;
+	let sampler = bindless.sampler().alloc(&BindlessSamplerCreateInfo {
+		compare_op: Some(CompareOp::Less),
+		..BindlessSamplerCreateInfo::default()
+	})?;
+
+	let depth_references = [0.25, 0.75, 0.4, 0.6];
+	let depth_references_buffer = bindless.buffer().alloc_shared_from_iter(
+		&BindlessBufferCreateInfo {
+			name: "depth_references",
+			usage: BindlessBufferUsage::MAP_WRITE | BindlessBufferUsage::STORAGE_BUFFER,
+			allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+		},
+		depth_references,
+	)?;
+	let out = bindless.buffer().alloc_slice::<f32>(
+		&BindlessBufferCreateInfo {
+			name: "out",
+			usage: BindlessBufferUsage::MAP_READ | BindlessBufferUsage::STORAGE_BUFFER,
+			allocation_scheme: BindlessAllocationScheme::AllocatorManaged,
+		},
+		depth_references.len(),
+	)?;
+	let out = bindless.execute(|cmd| {
+		let out = out.access::<ShaderReadWrite>(cmd)?;
+		cmd.dispatch(
+			&pipeline,
+			[depth_references.len() as u32, 1, 1],
+			Param {
+				depth: depth.to_transient(cmd),
+				sampler: sampler.to_transient(cmd),
+				depth_references: depth_references_buffer.to_transient(cmd),
+				out: out.to_mut_transient()?,
+			},
+		)?;
+		Ok(out.transition::<HostAccess>()?.into_desc())
+	})?;
+
+	// CompareOp::Less passes if the reference is less than the stored depth of 0.5
+	let result = out.mapped().await?.read_iter().collect::<Vec<_>>();
+	assert_relative_eq!(&*result, &[1., 0., 1., 0.][..], epsilon = 0.01);
+	Ok(())
+}
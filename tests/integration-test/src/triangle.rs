@@ -1,10 +1,6 @@
 #![cfg(test)]
 
 use crate::debugger;
-use ash::vk::{
-	ColorComponentFlags, CullModeFlags, FrontFace, PipelineColorBlendAttachmentState,
-	PipelineColorBlendStateCreateInfo, PolygonMode, PrimitiveTopology,
-};
 use glam::{UVec2, Vec2, Vec4};
 use integration_test_shader::color::ColorEnum;
 use integration_test_shader::triangle::{Param, Vertex};
@@ -12,12 +8,13 @@ use pollster::block_on;
 use rust_gpu_bindless_core::descriptor::{
 	Bindless, BindlessAllocationScheme, BindlessBufferCreateInfo, BindlessBufferUsage, BindlessImageCreateInfo,
 	BindlessImageUsage, BindlessInstance, DescBufferLenExt, DescriptorCounts, Extent, Format, Image2d,
-	MutDescBufferExt, RCDescExt,
+	MutDescBufferExt, RCDescExt, SampleCount,
 };
 use rust_gpu_bindless_core::pipeline::{
-	ClearValue, ColorAttachment, DrawIndirectCommand, GraphicsPipelineCreateInfo, HostAccess, LoadOp,
-	MutBufferAccessExt, MutImageAccessExt, PipelineDepthStencilStateCreateInfo, PipelineInputAssemblyStateCreateInfo,
-	PipelineRasterizationStateCreateInfo, RenderPassFormat, RenderingAttachment, StoreOp, TransferRead, TransferWrite,
+	ClearValue, ColorAttachment, ColorBlendAttachment, ColorBlendState, CullMode, DepthStencilState,
+	DrawIndirectCommand, FrontFace, GraphicsPipelineCreateInfo, HostAccess, InputAssemblyState, LoadOp,
+	MutBufferAccessExt, MutImageAccessExt, PolygonMode, PrimitiveTopology, RasterizationState, RenderPassFormat,
+	RenderingAttachment, StoreOp, TransferRead, TransferWrite,
 };
 use rust_gpu_bindless_core::platform::BindlessPipelinePlatform;
 use rust_gpu_bindless_core::platform::ash::{Ash, AshSingleGraphicsQueueCreateInfo, ash_init_single_graphics_queue};
@@ -68,21 +65,27 @@ async fn test_triangle<P: BindlessPipelinePlatform>(bindless: &Bindless<P>) -> a
 	let render_pass_format = RenderPassFormat {
 		color_attachments: SmallVec::from_slice(&[rt_format]),
 		depth_attachment: None,
+		samples: SampleCount::Sample1,
 	};
 
 	let pipeline = bindless.create_graphics_pipeline(
 		&render_pass_format,
 		&GraphicsPipelineCreateInfo {
-			input_assembly_state: PipelineInputAssemblyStateCreateInfo::default()
-				.topology(PrimitiveTopology::TRIANGLE_LIST),
-			rasterization_state: PipelineRasterizationStateCreateInfo::default()
-				.polygon_mode(PolygonMode::FILL)
-				.front_face(FrontFace::CLOCKWISE)
-				.cull_mode(CullModeFlags::BACK),
-			depth_stencil_state: PipelineDepthStencilStateCreateInfo::default(),
-			color_blend_state: PipelineColorBlendStateCreateInfo::default().attachments(&[
-				PipelineColorBlendAttachmentState::default().color_write_mask(ColorComponentFlags::RGBA),
-			]),
+			input_assembly_state: InputAssemblyState {
+				topology: PrimitiveTopology::TriangleList,
+				..InputAssemblyState::default()
+			},
+			rasterization_state: RasterizationState {
+				polygon_mode: PolygonMode::Fill,
+				front_face: FrontFace::Clockwise,
+				cull_mode: CullMode::Back,
+				..RasterizationState::default()
+			},
+			depth_stencil_state: DepthStencilState::default(),
+			color_blend_state: ColorBlendState {
+				attachments: &[ColorBlendAttachment::default()],
+				..ColorBlendState::default()
+			},
 		},
 		crate::shader::triangle::triangle_vertex::new(),
 		crate::shader::triangle::triangle_fragment::new(),
@@ -116,6 +119,7 @@ async fn test_triangle<P: BindlessPipelinePlatform>(bindless: &Bindless<P>) -> a
 				image: &mut image,
 				load_op: LoadOp::Clear(ClearValue::ColorF(B.color().to_array())),
 				store_op: StoreOp::Store,
+				resolve: None,
 			}],
 			None,
 			|rp| {